@@ -14,3 +14,4 @@ linkchecker target/doc/embedded_graphics/index.html
 linkchecker target/doc/tinybmp/index.html
 linkchecker target/doc/tinytga/index.html
 linkchecker target/doc/embedded_graphics_simulator/index.html
+linkchecker target/doc/embedded_graphics_fontconv/index.html
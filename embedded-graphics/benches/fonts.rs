@@ -7,8 +7,8 @@ use embedded_graphics::{
 
 fn font_6x8(c: &mut Criterion) {
     c.bench_function("font 6x8 Hello world!", |b| {
-        let object: Font6x8<Gray8> =
-            Font6x8::render_str("Hello world!").stroke_color(Some(Gray8::new(10)));
+        let object: Styled<Font6x8, TextStyle<Gray8>> = Font6x8::render_str("Hello world!")
+            .into_styled(TextStyle::with_text_color(Gray8::new(10)));
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
@@ -16,8 +16,8 @@ fn font_6x8(c: &mut Criterion) {
 
 fn font_12x16(c: &mut Criterion) {
     c.bench_function("font 12x16 Hello world!", |b| {
-        let object: Font12x16<Gray8> =
-            Font12x16::render_str("Hello world!").stroke_color(Some(Gray8::new(10)));
+        let object: Styled<Font12x16, TextStyle<Gray8>> = Font12x16::render_str("Hello world!")
+            .into_styled(TextStyle::with_text_color(Gray8::new(10)));
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
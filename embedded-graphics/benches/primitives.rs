@@ -3,15 +3,16 @@ use embedded_graphics::{
     drawable::Pixel,
     geometry::Point,
     pixelcolor::Gray8,
-    primitives::{Circle, Line, Rectangle, Triangle},
-    style::WithStyle,
+    primitives::{Circle, Line, Primitive, Rectangle, Triangle},
+    style::{PrimitiveStyle, Styled},
 };
 
 fn filled_circle(c: &mut Criterion) {
     c.bench_function("filled circle", |b| {
-        let object: Circle<Gray8> = Circle::new(Point::new(100, 100), 100)
-            .fill_color(Some(Gray8::new(1)))
-            .stroke_color(Some(Gray8::new(10)));
+        let object: Styled<Circle, PrimitiveStyle<Gray8>> = Circle::new(Point::new(100, 100), 100)
+            .into_styled(
+                PrimitiveStyle::with_fill(Gray8::new(1)).stroke_color(Some(Gray8::new(10))),
+            );
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
@@ -19,9 +20,10 @@ fn filled_circle(c: &mut Criterion) {
 
 fn filled_rect(c: &mut Criterion) {
     c.bench_function("filled rectangle", |b| {
-        let object: Rectangle<Gray8> = Rectangle::new(Point::new(100, 100), Point::new(200, 200))
-            .fill_color(Some(Gray8::new(1)))
-            .stroke_color(Some(Gray8::new(10)));
+        let object: Styled<Rectangle, PrimitiveStyle<Gray8>> =
+            Rectangle::new(Point::new(100, 100), Point::new(200, 200)).into_styled(
+                PrimitiveStyle::with_fill(Gray8::new(1)).stroke_color(Some(Gray8::new(10))),
+            );
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
@@ -29,8 +31,9 @@ fn filled_rect(c: &mut Criterion) {
 
 fn empty_rect(c: &mut Criterion) {
     c.bench_function("unfilled rectangle", |b| {
-        let object: Rectangle<Gray8> = Rectangle::new(Point::new(100, 100), Point::new(200, 200))
-            .stroke_color(Some(Gray8::new(10)));
+        let object: Styled<Rectangle, PrimitiveStyle<Gray8>> =
+            Rectangle::new(Point::new(100, 100), Point::new(200, 200))
+                .into_styled(PrimitiveStyle::with_stroke(Gray8::new(10), 1));
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
@@ -38,8 +41,9 @@ fn empty_rect(c: &mut Criterion) {
 
 fn line(c: &mut Criterion) {
     c.bench_function("line", |b| {
-        let object: Line<Gray8> = Line::new(Point::new(100, 100), Point::new(200, 200))
-            .stroke_color(Some(Gray8::new(10)));
+        let object: Styled<Line, PrimitiveStyle<Gray8>> =
+            Line::new(Point::new(100, 100), Point::new(200, 200))
+                .into_styled(PrimitiveStyle::with_stroke(Gray8::new(10), 1));
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
@@ -47,8 +51,9 @@ fn line(c: &mut Criterion) {
 
 fn triangle(c: &mut Criterion) {
     c.bench_function("triangle", |b| {
-        let object: Triangle<Gray8> =
-            Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(5, 20));
+        let object: Styled<Triangle, PrimitiveStyle<Gray8>> =
+            Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(5, 20))
+                .into_styled(PrimitiveStyle::with_stroke(Gray8::new(10), 1));
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
@@ -56,9 +61,9 @@ fn triangle(c: &mut Criterion) {
 
 fn filled_triangle(c: &mut Criterion) {
     c.bench_function("filled_triangle", |b| {
-        let object: Triangle<Gray8> =
+        let object: Styled<Triangle, PrimitiveStyle<Gray8>> =
             Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(5, 20))
-                .fill_color(Some(Gray8::new(1)));
+                .into_styled(PrimitiveStyle::with_fill(Gray8::new(1)));
 
         b.iter(|| object.into_iter().collect::<Vec<Pixel<Gray8>>>())
     });
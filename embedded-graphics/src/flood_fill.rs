@@ -0,0 +1,427 @@
+//! Flood fill.
+//!
+//! A [`FloodFill`] replaces the color of a connected area of pixels with the same color, similar to
+//! the paint bucket tool in image editors. The fill reads back the current display content and
+//! therefore requires a display which implements [`GetPixel`].
+//!
+//! The fill doesn't allocate. Pending parts of the filled area are instead stored in a stack of
+//! [`SeedSpan`]s supplied by the caller. If the stack isn't large enough for the area being filled
+//! the fill stops and [`FloodFillError::StackOverflow`] is returned. Simple convex shapes only need
+//! a few entries, but areas with many holes or a jagged outline need more.
+//!
+//! # Examples
+//!
+//! ## Fill the inside of a circle
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::flood_fill::{FloodFill, SeedSpan};
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! use embedded_graphics::primitives::Circle;
+//! use embedded_graphics::style::PrimitiveStyle;
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display = MockDisplay::default();
+//! # Circle::new(Point::new(32, 32), 31).into_styled(PrimitiveStyle::with_fill(BinaryColor::Off)).draw(&mut display);
+//!
+//! Circle::new(Point::new(32, 32), 20)
+//!     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+//!     .draw(&mut display);
+//!
+//! let mut stack = [SeedSpan::default(); 32];
+//!
+//! FloodFill::new(Point::new(32, 32), BinaryColor::On)
+//!     .fill(&mut display, &mut stack)
+//!     .expect("stack overflow");
+//! ```
+//!
+//! [`FloodFill`]: struct.FloodFill.html
+//! [`GetPixel`]: ../trait.GetPixel.html
+//! [`SeedSpan`]: struct.SeedSpan.html
+//! [`FloodFillError::StackOverflow`]: enum.FloodFillError.html#variant.StackOverflow
+
+use crate::drawable::Pixel;
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::GetPixel;
+
+/// Pixel neighborhood used to decide which pixels are connected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Pixels are connected to the pixels above, below, left and right of them.
+    Four,
+
+    /// Pixels are additionally connected to their diagonal neighbors.
+    ///
+    /// An eight connected fill leaks through diagonal gaps, for example between the pixels of a
+    /// one pixel wide diagonal line.
+    Eight,
+}
+
+/// Error returned by [`FloodFill::fill`].
+///
+/// [`FloodFill::fill`]: struct.FloodFill.html#method.fill
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FloodFillError {
+    /// The span stack was too small to fill the area.
+    ///
+    /// The area was only partially filled when this error is returned.
+    StackOverflow,
+}
+
+/// Entry in the stack used by [`FloodFill`].
+///
+/// This type is only used to reserve storage for the fill, its contents are private.
+///
+/// [`FloodFill`]: struct.FloodFill.html
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SeedSpan {
+    /// Start of the filled span in the parent row.
+    x_start: i32,
+
+    /// End of the filled span in the parent row (inclusive).
+    x_end: i32,
+
+    /// Row to scan for unfilled pixels.
+    y: i32,
+
+    /// Direction from the parent row to `y`.
+    dy: i32,
+}
+
+/// Flood fill.
+///
+/// See the [module documentation] for more information.
+///
+/// [module documentation]: index.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FloodFill<C> {
+    seed: Point,
+    color: C,
+    connectivity: Connectivity,
+}
+
+impl<C> FloodFill<C>
+where
+    C: PixelColor,
+{
+    /// Creates a four connected flood fill starting at `seed`.
+    pub fn new(seed: Point, color: C) -> Self {
+        Self {
+            seed,
+            color,
+            connectivity: Connectivity::Four,
+        }
+    }
+
+    /// Sets the connectivity of the fill.
+    pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
+        self.connectivity = connectivity;
+
+        self
+    }
+
+    /// Fills the area around the seed point.
+    ///
+    /// All pixels which are connected to the seed point and have the same color as the seed point
+    /// are set to the fill color. Nothing is drawn if the seed point is outside the display or
+    /// already has the fill color.
+    pub fn fill<D>(&self, display: &mut D, stack: &mut [SeedSpan]) -> Result<(), FloodFillError>
+    where
+        D: GetPixel<C>,
+    {
+        let target = match display.pixel(self.seed) {
+            Some(color) if color != self.color => color,
+            _ => return Ok(()),
+        };
+
+        let mut filler = Filler {
+            display,
+            target,
+            color: self.color,
+            stack,
+            len: 0,
+        };
+
+        let Point { x, y } = self.seed;
+        let (x_start, x_end) = filler.fill_run(x, y);
+        filler.push(x_start, x_end, y + 1, 1)?;
+        filler.push(x_start, x_end, y - 1, -1)?;
+
+        // Diagonal neighbors are included by widening the scanned range by one pixel on each side.
+        let extend = match self.connectivity {
+            Connectivity::Four => 0,
+            Connectivity::Eight => 1,
+        };
+
+        while let Some(span) = filler.pop() {
+            let SeedSpan {
+                x_start: parent_start,
+                x_end: parent_end,
+                y,
+                dy,
+            } = span;
+
+            let mut x = parent_start - extend;
+            while x <= parent_end + extend {
+                if !filler.is_inside(x, y) {
+                    x += 1;
+                    continue;
+                }
+
+                let (x_start, x_end) = filler.fill_run(x, y);
+                filler.push(x_start, x_end, y + dy, dy)?;
+
+                // The parent row only needs to be scanned again if the new run reaches beyond the
+                // already filled parent span.
+                if x_start - extend < parent_start || x_end + extend > parent_end {
+                    filler.push(x_start, x_end, y - dy, -dy)?;
+                }
+
+                // The pixel after the run isn't inside and can be skipped.
+                x = x_end + 2;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// State of a running flood fill.
+struct Filler<'a, C, D> {
+    display: &'a mut D,
+    target: C,
+    color: C,
+    stack: &'a mut [SeedSpan],
+    len: usize,
+}
+
+impl<C, D> Filler<'_, C, D>
+where
+    C: PixelColor,
+    D: GetPixel<C>,
+{
+    fn is_inside(&self, x: i32, y: i32) -> bool {
+        self.display.pixel(Point::new(x, y)) == Some(self.target)
+    }
+
+    /// Fills the horizontal run of inside pixels containing `(x, y)`.
+    ///
+    /// Returns the first and last `x` coordinate of the run.
+    fn fill_run(&mut self, x: i32, y: i32) -> (i32, i32) {
+        let mut x_start = x;
+        while self.is_inside(x_start - 1, y) {
+            x_start -= 1;
+        }
+
+        let mut x_end = x;
+        while self.is_inside(x_end + 1, y) {
+            x_end += 1;
+        }
+
+        let color = self.color;
+        self.display
+            .draw_iter((x_start..=x_end).map(move |x| Pixel(Point::new(x, y), color)));
+
+        (x_start, x_end)
+    }
+
+    fn push(&mut self, x_start: i32, x_end: i32, y: i32, dy: i32) -> Result<(), FloodFillError> {
+        let entry = self
+            .stack
+            .get_mut(self.len)
+            .ok_or(FloodFillError::StackOverflow)?;
+
+        *entry = SeedSpan {
+            x_start,
+            x_end,
+            y,
+            dy,
+        };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<SeedSpan> {
+        self.len = self.len.checked_sub(1)?;
+
+        Some(self.stack[self.len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawable::Drawable;
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Primitive;
+    use crate::primitives::Rectangle;
+    use crate::style::PrimitiveStyle;
+
+    #[test]
+    fn fills_enclosed_area() {
+        let mut display = MockDisplay::from_pattern(&[
+            "........", //
+            ".######.", //
+            ".#....#.", //
+            ".#.##.#.", //
+            ".#....#.", //
+            ".######.", //
+            "........", //
+        ]);
+
+        let mut stack = [SeedSpan::default(); 8];
+        FloodFill::new(Point::new(2, 2), BinaryColor::On)
+            .fill(&mut display, &mut stack)
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "........", //
+                ".######.", //
+                ".######.", //
+                ".######.", //
+                ".######.", //
+                ".######.", //
+                "........", //
+            ])
+        );
+    }
+
+    #[test]
+    fn four_connected_stops_at_diagonal_gaps() {
+        let mut display = MockDisplay::from_pattern(&[
+            "...#", //
+            "..#.", //
+            ".#..", //
+            "#...", //
+        ]);
+
+        let mut stack = [SeedSpan::default(); 8];
+        FloodFill::new(Point::new(0, 0), BinaryColor::On)
+            .fill(&mut display, &mut stack)
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "####", //
+                "###.", //
+                "##..", //
+                "#...", //
+            ])
+        );
+    }
+
+    #[test]
+    fn eight_connected_leaks_through_diagonal_gaps() {
+        let mut display = MockDisplay::from_pattern(&[
+            "...#", //
+            "..#.", //
+            ".#..", //
+            "#...", //
+        ]);
+
+        let mut stack = [SeedSpan::default(); 8];
+        FloodFill::new(Point::new(0, 0), BinaryColor::On)
+            .connectivity(Connectivity::Eight)
+            .fill(&mut display, &mut stack)
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "####", //
+                "####", //
+                "####", //
+                "####", //
+            ])
+        );
+    }
+
+    #[test]
+    fn fills_around_obstacles() {
+        // A spiral requires scanning back into already visited rows.
+        let mut display = MockDisplay::from_pattern(&[
+            "#########", //
+            "#.......#", //
+            "#.#####.#", //
+            "#.#...#.#", //
+            "#.#.#.#.#", //
+            "#.#.###.#", //
+            "#.#.....#", //
+            "#.#######", //
+            "#........", //
+        ]);
+
+        let mut stack = [SeedSpan::default(); 16];
+        FloodFill::new(Point::new(4, 3), BinaryColor::On)
+            .fill(&mut display, &mut stack)
+            .unwrap();
+
+        assert_eq!(display, MockDisplay::from_pattern(&["#########"; 9]));
+    }
+
+    #[test]
+    fn seed_with_fill_color_is_noop() {
+        let mut display = MockDisplay::from_pattern(&[
+            "##", //
+            "..", //
+        ]);
+        let expected = display.clone();
+
+        let mut stack: [SeedSpan; 0] = [];
+        FloodFill::new(Point::new(0, 0), BinaryColor::On)
+            .fill(&mut display, &mut stack)
+            .unwrap();
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn seed_outside_display_is_noop() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        let mut stack: [SeedSpan; 0] = [];
+        assert_eq!(
+            FloodFill::new(Point::new(-1, 0), BinaryColor::On).fill(&mut display, &mut stack),
+            Ok(())
+        );
+        assert_eq!(display, MockDisplay::new());
+    }
+
+    #[test]
+    fn stack_overflow() {
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Point::new(9, 9))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off))
+            .draw(&mut display);
+
+        let mut stack = [SeedSpan::default(); 1];
+        assert_eq!(
+            FloodFill::new(Point::new(5, 5), BinaryColor::On).fill(&mut display, &mut stack),
+            Err(FloodFillError::StackOverflow)
+        );
+    }
+
+    #[test]
+    fn small_stack_is_sufficient_for_rectangle() {
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Point::new(9, 9))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off))
+            .draw(&mut display);
+
+        let mut stack = [SeedSpan::default(); 4];
+        FloodFill::new(Point::new(5, 5), BinaryColor::On)
+            .fill(&mut display, &mut stack)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Point::new(9, 9))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected);
+        assert_eq!(display, expected);
+    }
+}
@@ -1,4 +1,8 @@
 use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+use crate::fonts::glyph_map::{GlyphMap, GlyphRange};
+
+/// Maps printable ASCII characters to glyphs, other characters are drawn as `?`.
+const GLYPH_MAP: GlyphMap = GlyphMap::new(&[GlyphRange::new(' ', '~', 0)], '?' as u32 - ' ' as u32);
 
 #[derive(Debug, Copy, Clone)]
 /// Config for 12x16 font
@@ -8,15 +12,9 @@ impl FontBuilderConf for Font12x16Conf {
     const CHAR_HEIGHT: u32 = 16;
     const CHAR_WIDTH: u32 = 12;
     const FONT_IMAGE_WIDTH: u32 = 480;
+    const BASELINE: u32 = 13;
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        fallback
+        GLYPH_MAP.index(c)
     }
 }
 
@@ -31,7 +29,7 @@ impl FontBuilderConf for Font12x16Conf {
 /// See the [module-level documentation](./index.html) for examples.
 ///
 /// [`text_12x16`]: ../macro.text_12x16.html
-pub type Font12x16<'a, C> = FontBuilder<'a, C, Font12x16Conf>;
+pub type Font12x16<'a> = FontBuilder<'a, Font12x16Conf>;
 
 #[cfg(test)]
 mod tests {
@@ -41,7 +39,7 @@ mod tests {
     use crate::geometry::{Dimensions, Point, Size};
     use crate::mock_display::MockDisplay;
     use crate::pixelcolor::BinaryColor;
-    use crate::style::WithStyle;
+    use crate::style::TextStyle;
     use crate::transform::Transform;
 
     const WIDTH: usize = Font12x16Conf::CHAR_WIDTH as usize;
@@ -50,8 +48,8 @@ mod tests {
 
     #[test]
     fn text_dimensions() {
-        let hello: Font12x16<BinaryColor> = Font12x16::render_str(HELLO_WORLD);
-        let empty: Font12x16<BinaryColor> = Font12x16::render_str("");
+        let hello: Font12x16 = Font12x16::render_str(HELLO_WORLD);
+        let empty: Font12x16 = Font12x16::render_str("");
 
         assert_eq!(
             hello.size(),
@@ -62,9 +60,8 @@ mod tests {
 
     #[test]
     fn text_corners() {
-        let hello: Font12x16<BinaryColor> =
-            Font12x16::render_str(HELLO_WORLD).translate(Point::new(5, -20));
-        let empty: Font12x16<BinaryColor> = Font12x16::render_str("").translate(Point::new(10, 20));
+        let hello: Font12x16 = Font12x16::render_str(HELLO_WORLD).translate(Point::new(5, -20));
+        let empty: Font12x16 = Font12x16::render_str("").translate(Point::new(10, 20));
 
         assert_eq!(hello.top_left(), Point::new(5, -20));
         assert_eq!(
@@ -82,7 +79,7 @@ mod tests {
     fn correct_m() {
         let mut display = MockDisplay::new();
         Font12x16::render_str("Mm")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -112,7 +109,7 @@ mod tests {
     fn correct_ascii_borders() {
         let mut display = MockDisplay::new();
         Font12x16::render_str(" ~")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -142,7 +139,7 @@ mod tests {
     fn correct_dollar_y() {
         let mut display = MockDisplay::new();
         Font12x16::render_str("$y")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -190,26 +187,26 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        Font12x16::render_str("\0\n")
-            .stroke_color(Some(BinaryColor::On))
+        Font12x16::render_str("\0\r")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font12x16::render_str("\x7F\u{A0}")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font12x16::render_str("¡ÿ")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font12x16::render_str("Ā💣")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
     }
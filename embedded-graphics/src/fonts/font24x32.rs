@@ -1,4 +1,8 @@
 use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+use crate::fonts::glyph_map::{GlyphMap, GlyphRange};
+
+/// Maps printable ASCII characters to glyphs, other characters are drawn as `?`.
+const GLYPH_MAP: GlyphMap = GlyphMap::new(&[GlyphRange::new(' ', '~', 0)], '?' as u32 - ' ' as u32);
 
 #[derive(Debug, Copy, Clone)]
 /// Config for 24x32 font
@@ -8,15 +12,9 @@ impl FontBuilderConf for Font24x32Conf {
     const CHAR_HEIGHT: u32 = 32;
     const CHAR_WIDTH: u32 = 24;
     const FONT_IMAGE_WIDTH: u32 = 960;
+    const BASELINE: u32 = 27;
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        fallback
+        GLYPH_MAP.index(c)
     }
 }
 
@@ -35,7 +33,7 @@ impl FontBuilderConf for Font24x32Conf {
 /// See the [module-level documentation](./index.html) for examples.
 ///
 /// [`text_24x32`]: ../macro.text_24x32.html
-pub type Font24x32<'a, C> = FontBuilder<'a, C, Font24x32Conf>;
+pub type Font24x32<'a> = FontBuilder<'a, Font24x32Conf>;
 
 #[cfg(test)]
 mod tests {
@@ -45,7 +43,7 @@ mod tests {
     use crate::geometry::{Dimensions, Point, Size};
     use crate::mock_display::MockDisplay;
     use crate::pixelcolor::BinaryColor;
-    use crate::style::WithStyle;
+    use crate::style::TextStyle;
     use crate::transform::Transform;
 
     const WIDTH: usize = Font24x32Conf::CHAR_WIDTH as usize;
@@ -54,8 +52,8 @@ mod tests {
 
     #[test]
     fn text_dimensions() {
-        let hello: Font24x32<BinaryColor> = Font24x32::render_str(HELLO_WORLD);
-        let empty: Font24x32<BinaryColor> = Font24x32::render_str("");
+        let hello: Font24x32 = Font24x32::render_str(HELLO_WORLD);
+        let empty: Font24x32 = Font24x32::render_str("");
 
         assert_eq!(
             hello.size(),
@@ -66,9 +64,8 @@ mod tests {
 
     #[test]
     fn text_corners() {
-        let hello: Font24x32<BinaryColor> =
-            Font24x32::render_str(HELLO_WORLD).translate(Point::new(5, -20));
-        let empty: Font24x32<BinaryColor> = Font24x32::render_str("").translate(Point::new(10, 20));
+        let hello: Font24x32 = Font24x32::render_str(HELLO_WORLD).translate(Point::new(5, -20));
+        let empty: Font24x32 = Font24x32::render_str("").translate(Point::new(10, 20));
 
         assert_eq!(hello.top_left(), Point::new(5, -20));
         assert_eq!(
@@ -86,7 +83,7 @@ mod tests {
     fn correct_m() {
         let mut display = MockDisplay::new();
         Font24x32::render_str("Mm")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -128,7 +125,7 @@ mod tests {
     fn correct_ascii_borders() {
         let mut display = MockDisplay::new();
         Font24x32::render_str(" ~")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -150,7 +147,7 @@ mod tests {
     fn correct_dollar_y() {
         let mut display = MockDisplay::new();
         Font24x32::render_str("$y")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -226,26 +223,26 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        Font24x32::render_str("\0\n")
-            .stroke_color(Some(BinaryColor::On))
+        Font24x32::render_str("\0\r")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font24x32::render_str("\x7F\u{A0}")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font24x32::render_str("¡ÿ")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font24x32::render_str("Ā💣")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
     }
@@ -1,4 +1,8 @@
 use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+use crate::fonts::glyph_map::{GlyphMap, GlyphRange};
+
+/// Maps printable ASCII characters to glyphs, other characters are drawn as `?`.
+const GLYPH_MAP: GlyphMap = GlyphMap::new(&[GlyphRange::new(' ', '~', 0)], '?' as u32 - ' ' as u32);
 
 #[derive(Debug, Copy, Clone)]
 /// Config for 6x12 font
@@ -8,15 +12,9 @@ impl FontBuilderConf for Font6x12Conf {
     const CHAR_HEIGHT: u32 = 12;
     const CHAR_WIDTH: u32 = 6;
     const FONT_IMAGE_WIDTH: u32 = 96;
+    const BASELINE: u32 = 9;
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        fallback
+        GLYPH_MAP.index(c)
     }
 }
 
@@ -31,7 +29,7 @@ impl FontBuilderConf for Font6x12Conf {
 /// See the [module-level documentation](./index.html) for examples.
 ///
 /// [`text_6x12`]: ../macro.text_6x12.html
-pub type Font6x12<'a, C> = FontBuilder<'a, C, Font6x12Conf>;
+pub type Font6x12<'a> = FontBuilder<'a, Font6x12Conf>;
 
 #[cfg(test)]
 mod tests {
@@ -41,7 +39,7 @@ mod tests {
     use crate::geometry::{Dimensions, Point, Size};
     use crate::mock_display::MockDisplay;
     use crate::pixelcolor::BinaryColor;
-    use crate::style::WithStyle;
+    use crate::style::{Styled, TextStyle};
     use crate::transform::Transform;
 
     const WIDTH: usize = Font6x12Conf::CHAR_WIDTH as usize;
@@ -50,8 +48,8 @@ mod tests {
 
     #[test]
     fn text_dimensions() {
-        let hello: Font6x12<BinaryColor> = Font6x12::render_str(HELLO_WORLD);
-        let empty: Font6x12<BinaryColor> = Font6x12::render_str("");
+        let hello: Font6x12 = Font6x12::render_str(HELLO_WORLD);
+        let empty: Font6x12 = Font6x12::render_str("");
 
         assert_eq!(
             hello.size(),
@@ -62,9 +60,8 @@ mod tests {
 
     #[test]
     fn text_corners() {
-        let hello: Font6x12<BinaryColor> =
-            Font6x12::render_str(HELLO_WORLD).translate(Point::new(5, -20));
-        let empty: Font6x12<BinaryColor> = Font6x12::render_str("").translate(Point::new(10, 20));
+        let hello: Font6x12 = Font6x12::render_str(HELLO_WORLD).translate(Point::new(5, -20));
+        let empty: Font6x12 = Font6x12::render_str("").translate(Point::new(10, 20));
 
         assert_eq!(hello.top_left(), Point::new(5, -20));
         assert_eq!(
@@ -82,7 +79,7 @@ mod tests {
     fn correct_m() {
         let mut display = MockDisplay::new();
         Font6x12::render_str("Mm")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -108,7 +105,7 @@ mod tests {
     fn correct_ascii_borders() {
         let mut display = MockDisplay::new();
         Font6x12::render_str(" ~")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -134,7 +131,7 @@ mod tests {
     fn correct_dollar_y() {
         let mut display = MockDisplay::new();
         Font6x12::render_str("$y")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -174,35 +171,37 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        Font6x12::render_str("\0\n")
-            .stroke_color(Some(BinaryColor::On))
+        Font6x12::render_str("\0\r")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font6x12::render_str("\x7F\u{A0}")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font6x12::render_str("¡ÿ")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font6x12::render_str("Ā💣")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
     }
 
     #[test]
     fn negative_y_no_infinite_loop() {
-        let text: Font6x12<BinaryColor> = Font6x12::render_str("Testing string")
-            .stroke_color(Some(BinaryColor::On))
-            .fill_color(Some(BinaryColor::Off))
+        let text: Styled<Font6x12, TextStyle<BinaryColor>> = Font6x12::render_str("Testing string")
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
             .translate(Point::new(0, -12));
 
         assert_eq!(text.into_iter().count(), 6 * 12 * "Testing string".len());
@@ -210,9 +209,11 @@ mod tests {
 
     #[test]
     fn negative_x_no_infinite_loop() {
-        let text: Font6x12<BinaryColor> = Font6x12::render_str("A")
-            .stroke_color(Some(BinaryColor::On))
-            .fill_color(Some(BinaryColor::Off))
+        let text: Styled<Font6x12, TextStyle<BinaryColor>> = Font6x12::render_str("A")
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
             .translate(Point::new(-6, 0));
 
         assert_eq!(text.into_iter().count(), 6 * 12);
@@ -1,4 +1,11 @@
 use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+use crate::fonts::glyph_map::{GlyphMap, GlyphRange};
+
+/// Maps printable ASCII and Latin-1 characters to glyphs, other characters are drawn as `?`.
+const GLYPH_MAP: GlyphMap = GlyphMap::new(
+    &[GlyphRange::new(' ', '~', 0), GlyphRange::new('¡', 'ÿ', 95)],
+    '?' as u32 - ' ' as u32,
+);
 
 #[derive(Debug, Copy, Clone)]
 /// Config for 6x8 font
@@ -8,18 +15,9 @@ impl FontBuilderConf for Font6x8Conf {
     const CHAR_HEIGHT: u32 = 8;
     const CHAR_WIDTH: u32 = 6;
     const FONT_IMAGE_WIDTH: u32 = 240;
+    const BASELINE: u32 = 6;
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c < '¡' || c > 'ÿ' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 34
+        GLYPH_MAP.index(c)
     }
 }
 
@@ -35,17 +33,17 @@ impl FontBuilderConf for Font6x8Conf {
 ///
 /// [`text_6x8`]: ../macro.text_6x8.html
 
-pub type Font6x8<'a, C> = FontBuilder<'a, C, Font6x8Conf>;
+pub type Font6x8<'a> = FontBuilder<'a, Font6x8Conf>;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::drawable::Drawable;
     use crate::fonts::Font;
-    use crate::geometry::{Dimensions, Point, Size};
-    use crate::mock_display::MockDisplay;
+    use crate::geometry::{ContainsPoint, Dimensions, Point, Size};
+    use crate::mock_display::{assert_contains_drawn_points, MockDisplay};
     use crate::pixelcolor::BinaryColor;
-    use crate::style::WithStyle;
+    use crate::style::{Styled, TextStyle};
     use crate::transform::Transform;
 
     const WIDTH: usize = Font6x8Conf::CHAR_WIDTH as usize;
@@ -54,8 +52,8 @@ mod tests {
 
     #[test]
     fn text_dimensions() {
-        let hello: Font6x8<BinaryColor> = Font6x8::render_str(HELLO_WORLD);
-        let empty: Font6x8<BinaryColor> = Font6x8::render_str("");
+        let hello: Font6x8 = Font6x8::render_str(HELLO_WORLD);
+        let empty: Font6x8 = Font6x8::render_str("");
 
         assert_eq!(
             hello.size(),
@@ -66,9 +64,8 @@ mod tests {
 
     #[test]
     fn text_corners() {
-        let hello: Font6x8<BinaryColor> =
-            Font6x8::render_str(HELLO_WORLD).translate(Point::new(5, -20));
-        let empty: Font6x8<BinaryColor> = Font6x8::render_str("").translate(Point::new(10, 20));
+        let hello: Font6x8 = Font6x8::render_str(HELLO_WORLD).translate(Point::new(5, -20));
+        let empty: Font6x8 = Font6x8::render_str("").translate(Point::new(10, 20));
 
         assert_eq!(hello.top_left(), Point::new(5, -20));
         assert_eq!(
@@ -86,7 +83,7 @@ mod tests {
     fn correct_m() {
         let mut display = MockDisplay::new();
         Font6x8::render_str("Mm")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -108,8 +105,10 @@ mod tests {
     fn correct_inverse_coloured_m() {
         let mut display = MockDisplay::new();
         Font6x8::render_str("Mm")
-            .stroke_color(Some(BinaryColor::Off))
-            .fill_color(Some(BinaryColor::On))
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::Off)
+                    .background_color(Some(BinaryColor::On)),
+            )
             .draw(&mut display);
 
         assert_eq!(
@@ -132,14 +131,18 @@ mod tests {
     fn compare_inverse_coloured_m() {
         let mut display_inverse = MockDisplay::new();
         Font6x8::render_str("Mm")
-            .stroke_color(Some(BinaryColor::Off))
-            .fill_color(Some(BinaryColor::On))
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::Off)
+                    .background_color(Some(BinaryColor::On)),
+            )
             .draw(&mut display_inverse);
 
         let mut display_normal = MockDisplay::new();
         Font6x8::render_str("Mm")
-            .stroke_color(Some(BinaryColor::On))
-            .fill_color(Some(BinaryColor::Off))
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
             .draw(&mut display_normal);
 
         for y in 0..display_inverse.height() {
@@ -158,7 +161,7 @@ mod tests {
     fn correct_ascii_borders() {
         let mut display = MockDisplay::new();
         Font6x8::render_str(" ~")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -180,7 +183,7 @@ mod tests {
     fn no_fill_doesnt_hang() {
         let mut display = MockDisplay::new();
         Font6x8::render_str(" ")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(display, MockDisplay::new());
@@ -190,7 +193,7 @@ mod tests {
     fn correct_dollar_y() {
         let mut display = MockDisplay::new();
         Font6x8::render_str("$y")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -212,7 +215,7 @@ mod tests {
     fn correct_latin1() {
         let mut display = MockDisplay::new();
         Font6x8::render_str("¡ÿ")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -244,21 +247,36 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        Font6x8::render_str("\0\n")
-            .stroke_color(Some(BinaryColor::On))
+        Font6x8::render_str("\0\r")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font6x8::render_str("\x7F\u{A0}")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font6x8::render_str("Ā💣")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
     }
+
+    #[test]
+    fn contains() {
+        let text: Styled<Font6x8, TextStyle<BinaryColor>> = Font6x8::render_str("Hi!")
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
+            .translate(Point::new(3, 5));
+
+        assert_contains_drawn_points(&text);
+
+        let empty: Font6x8 = Font6x8::render_str("");
+        assert!(!empty.contains(Point::zero()));
+    }
 }
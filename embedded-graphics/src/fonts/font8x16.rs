@@ -1,4 +1,11 @@
 use crate::fonts::font_builder::{FontBuilder, FontBuilderConf};
+use crate::fonts::glyph_map::{GlyphMap, GlyphRange};
+
+/// Maps printable ASCII and Latin-1 characters to glyphs, other characters are drawn as `?`.
+const GLYPH_MAP: GlyphMap = GlyphMap::new(
+    &[GlyphRange::new(' ', '~', 0), GlyphRange::new('¡', 'ÿ', 95)],
+    '?' as u32 - ' ' as u32,
+);
 
 #[derive(Debug, Copy, Clone)]
 /// Config for 8x16 font
@@ -8,18 +15,9 @@ impl FontBuilderConf for Font8x16Conf {
     const CHAR_HEIGHT: u32 = 16;
     const CHAR_WIDTH: u32 = 8;
     const FONT_IMAGE_WIDTH: u32 = 240;
+    const BASELINE: u32 = 11;
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c < '¡' || c > 'ÿ' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 34
+        GLYPH_MAP.index(c)
     }
 }
 
@@ -34,7 +32,7 @@ impl FontBuilderConf for Font8x16Conf {
 /// See the [module-level documentation](./index.html) for examples.
 ///
 /// [`text_8x16`]: ../macro.text_8x16.html
-pub type Font8x16<'a, C> = FontBuilder<'a, C, Font8x16Conf>;
+pub type Font8x16<'a> = FontBuilder<'a, Font8x16Conf>;
 
 #[cfg(test)]
 mod tests {
@@ -44,7 +42,7 @@ mod tests {
     use crate::geometry::{Dimensions, Point, Size};
     use crate::mock_display::MockDisplay;
     use crate::pixelcolor::BinaryColor;
-    use crate::style::WithStyle;
+    use crate::style::TextStyle;
     use crate::transform::Transform;
 
     const WIDTH: usize = Font8x16Conf::CHAR_WIDTH as usize;
@@ -53,8 +51,8 @@ mod tests {
 
     #[test]
     fn text_dimensions() {
-        let hello: Font8x16<BinaryColor> = Font8x16::render_str(HELLO_WORLD);
-        let empty: Font8x16<BinaryColor> = Font8x16::render_str("");
+        let hello: Font8x16 = Font8x16::render_str(HELLO_WORLD);
+        let empty: Font8x16 = Font8x16::render_str("");
 
         assert_eq!(
             hello.size(),
@@ -65,9 +63,8 @@ mod tests {
 
     #[test]
     fn text_corners() {
-        let hello: Font8x16<BinaryColor> =
-            Font8x16::render_str(HELLO_WORLD).translate(Point::new(5, -20));
-        let empty: Font8x16<BinaryColor> = Font8x16::render_str("").translate(Point::new(10, 20));
+        let hello: Font8x16 = Font8x16::render_str(HELLO_WORLD).translate(Point::new(5, -20));
+        let empty: Font8x16 = Font8x16::render_str("").translate(Point::new(10, 20));
 
         assert_eq!(hello.top_left(), Point::new(5, -20));
         assert_eq!(
@@ -85,7 +82,7 @@ mod tests {
     fn correct_m() {
         let mut display = MockDisplay::new();
         Font8x16::render_str("Mm")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -115,7 +112,7 @@ mod tests {
     fn correct_ascii_borders() {
         let mut display = MockDisplay::new();
         Font8x16::render_str(" ~")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -145,7 +142,7 @@ mod tests {
     fn correct_dollar_y() {
         let mut display = MockDisplay::new();
         Font8x16::render_str("$y")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -175,7 +172,7 @@ mod tests {
     fn correct_latin1() {
         let mut display = MockDisplay::new();
         Font8x16::render_str("¡ÿ")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
 
         assert_eq!(
@@ -223,20 +220,20 @@ mod tests {
         ]);
 
         let mut display = MockDisplay::new();
-        Font8x16::render_str("\0\n")
-            .stroke_color(Some(BinaryColor::On))
+        Font8x16::render_str("\0\r")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font8x16::render_str("\x7F\u{A0}")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
         Font8x16::render_str("Ā💣")
-            .stroke_color(Some(BinaryColor::On))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
             .draw(&mut display);
         assert_eq!(display, two_question_marks);
     }
@@ -1,17 +1,51 @@
-//! Common code used to define available monospace pixel fonts.
+//! Common code used to define available pixel fonts.
 //!
 //! See the [module level type definitions](../index.html#types) for a list of usable fonts.
-use crate::drawable::{Drawable, Pixel};
-use crate::fonts::Font;
-use crate::geometry::{Dimensions, Point, Size};
-use crate::pixelcolor::{BinaryColor, PixelColor};
-use crate::style::Style;
-use crate::style::WithStyle;
-use crate::transform::Transform;
-use crate::DrawTarget;
+use crate::fonts::{Font, GlyphSource, Text, TextIterator, TextMetrics};
+use core::fmt;
 use core::marker::PhantomData;
 
 /// The configuration of the font
+///
+/// The glyphs are stored in `FONT_IMAGE` in cells of `CHAR_WIDTH` by `CHAR_HEIGHT` pixels. Fonts
+/// are monospace by default and advance by `CHAR_WIDTH` pixels after each glyph. Proportional fonts
+/// set `GLYPH_WIDTHS` to a table of per-glyph widths.
+///
+/// Each pixel in `FONT_IMAGE` is stored with `BITS_PER_PIXEL` bits. Monochrome fonts use one bit
+/// per pixel, anti-aliased fonts use 2, 4 or 8 bits to store the coverage of each pixel. Text with
+/// an anti-aliased font is blended over the background color, see [`PixelColor::blend`].
+///
+/// # Examples
+///
+/// ## Define a proportional font
+///
+/// ```rust
+/// use embedded_graphics::fonts::font_builder::{FontBuilder, FontBuilderConf, GlyphWidth};
+///
+/// #[derive(Debug, Copy, Clone)]
+/// pub enum NarrowConf {}
+///
+/// impl FontBuilderConf for NarrowConf {
+///     // Two 4x2 glyphs, "i" and "m"
+///     const FONT_IMAGE: &'static [u8] = &[0b1000_1110, 0b1000_1010];
+///     const CHAR_HEIGHT: u32 = 2;
+///     const CHAR_WIDTH: u32 = 4;
+///     const FONT_IMAGE_WIDTH: u32 = 8;
+///     const GLYPH_WIDTHS: &'static [GlyphWidth] = &[GlyphWidth::new(1, 2), GlyphWidth::new(3, 4)];
+///
+///     fn char_offset(c: char) -> u32 {
+///         if c == 'i' {
+///             0
+///         } else {
+///             1
+///         }
+///     }
+/// }
+///
+/// pub type Narrow<'a> = FontBuilder<'a, NarrowConf>;
+/// ```
+///
+/// [`PixelColor::blend`]: ../../pixelcolor/trait.PixelColor.html#method.blend
 pub trait FontBuilderConf {
     /// Raw image containing the font
     const FONT_IMAGE: &'static [u8];
@@ -19,305 +53,472 @@ pub trait FontBuilderConf {
     const CHAR_HEIGHT: u32;
 
     /// `char` width of the font
+    ///
+    /// For proportional fonts this is the width of the glyph cells in the font image, which must
+    /// be at least as large as the widest glyph.
     const CHAR_WIDTH: u32;
-    /// Font image width, must be divisible by `8` and `CHAR_WIDTH`.
+    /// Font image width, must be divisible by `CHAR_WIDTH`.
+    ///
+    /// Each row of the font image must fill whole bytes, which means that `FONT_IMAGE_WIDTH *
+    /// BITS_PER_PIXEL` must be divisible by `8`.
     const FONT_IMAGE_WIDTH: u32 = 240;
+    /// Number of bits per pixel in the font image, must be 1, 2, 4 or 8
+    ///
+    /// Pixels are stored from the most significant bits of each byte. The value of a pixel is the
+    /// coverage of the pixel, from `0` for transparent pixels to the maximum value for completely
+    /// covered pixels.
+    const BITS_PER_PIXEL: u32 = 1;
+    /// Row of the baseline, counted from the top of the glyph cell
+    ///
+    /// The baseline is the bottom row of capital letters. Descenders are drawn below it.
+    const BASELINE: u32 = Self::CHAR_HEIGHT - 1;
+    /// Widths of the glyphs, indexed by the value returned from `char_offset`
+    ///
+    /// Glyphs without an entry in this table use `CHAR_WIDTH` as their bitmap width and advance
+    /// width. Monospace fonts leave the table empty.
+    const GLYPH_WIDTHS: &'static [GlyphWidth] = &[];
+    /// Kerning adjustments for pairs of chars
+    ///
+    /// The table is searched linearly for each glyph and should only contain the pairs which need
+    /// an adjustment. Fonts without kerning leave the table empty.
+    const KERNING_PAIRS: &'static [KerningPair] = &[];
     /// Returns the index in the font of the correponding `char`
     fn char_offset(_: char) -> u32;
 }
 
-/// The font builder
-///
-/// This is a helper struct to reduce code duplication when implementing fonts. View the [module
-/// level type definitions](../index.html#types) for a list of usable fonts.
-#[derive(Debug)]
-pub struct FontBuilder<'a, C: PixelColor, Conf> {
-    /// Top left corner of the text
-    pub pos: Point,
+/// Horizontal metrics of a glyph in a proportional font
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GlyphWidth {
+    /// Number of pixel columns of the glyph cell that are drawn
+    pub width: u8,
 
-    /// Text to draw
-    text: &'a str,
+    /// Distance from the left edge of this glyph to the left edge of the next glyph
+    ///
+    /// Columns between `width` and `advance` are filled with the background color. If `advance`
+    /// is smaller than `width` the glyph overlaps the next glyph.
+    pub advance: u8,
+}
 
-    /// Style of the font
-    style: Style<C>,
+impl GlyphWidth {
+    /// Creates new glyph metrics.
+    pub const fn new(width: u8, advance: u8) -> Self {
+        Self { width, advance }
+    }
+}
 
-    _conf: PhantomData<Conf>,
+/// Kerning adjustment for a pair of chars
+///
+/// ```rust
+/// use embedded_graphics::fonts::font_builder::KerningPair;
+///
+/// // Move "V" one pixel closer to a preceding "A"
+/// const AV: KerningPair = KerningPair::new('A', 'V', -1);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KerningPair {
+    /// First char of the pair
+    pub left: char,
+
+    /// Second char of the pair
+    pub right: char,
+
+    /// Adjustment which is added to the advance width of `left` if it is followed by `right`
+    pub adjustment: i8,
 }
 
-impl<'a, C: PixelColor + Copy, Conf> Copy for FontBuilder<'a, C, Conf> {}
-impl<'a, C: PixelColor + Clone, Conf> Clone for FontBuilder<'a, C, Conf> {
-    fn clone(&self) -> Self {
+impl KerningPair {
+    /// Creates a new kerning pair.
+    pub const fn new(left: char, right: char, adjustment: i8) -> Self {
         Self {
-            pos: self.pos,
-            text: self.text,
-            style: self.style,
-            _conf: Default::default(),
+            left,
+            right,
+            adjustment,
         }
     }
 }
 
-impl<'a, C, Conf> Dimensions for FontBuilder<'a, C, Conf>
-where
-    C: PixelColor,
-    Conf: FontBuilderConf,
-{
-    fn top_left(&self) -> Point {
-        self.pos
+/// Glyph source for fonts defined by a `FontBuilderConf`
+///
+/// This is a zero sized type which reads the glyphs from the constants of the configuration.
+pub struct StaticFont<Conf>(PhantomData<Conf>);
+
+impl<Conf> StaticFont<Conf> {
+    /// Creates a new glyph source.
+    pub const fn new() -> Self {
+        Self(PhantomData)
     }
+}
 
-    fn bottom_right(&self) -> Point {
-        self.top_left() + self.size()
+impl<Conf> Default for StaticFont<Conf> {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// Get the bounding box of a piece of text
-    ///
-    /// Currently does not handle newlines (but neither does the rasteriser). It will give `(0, 0)`
-    /// if the string to render is empty.
-    fn size(&self) -> Size {
-        // TODO: Handle height of text with newlines in it
-        let width = Conf::CHAR_WIDTH * self.text.len() as u32;
-        let height = if width > 0 { Conf::CHAR_HEIGHT } else { 0 };
-
-        Size::new(width, height)
+impl<Conf> Copy for StaticFont<Conf> {}
+impl<Conf> Clone for StaticFont<Conf> {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
-impl<'a, C, Conf> Font<'a, C> for FontBuilder<'a, C, Conf>
-where
-    C: PixelColor,
-    Conf: FontBuilderConf,
-{
-    fn render_str(text: &'a str) -> Self {
-        Self {
-            pos: Point::zero(),
-            text,
-            style: Style::default(),
-            _conf: Default::default(),
-        }
+impl<Conf> fmt::Debug for StaticFont<Conf> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("StaticFont")
     }
 }
 
-impl<'a, C, Conf> WithStyle<C> for FontBuilder<'a, C, Conf>
+impl<Conf> GlyphSource for StaticFont<Conf>
 where
-    C: PixelColor,
+    Conf: FontBuilderConf,
 {
-    fn style(mut self, style: Style<C>) -> Self {
-        self.style = style;
+    fn char_height(&self) -> u32 {
+        Conf::CHAR_HEIGHT
+    }
 
-        self
+    fn baseline(&self) -> u32 {
+        Conf::BASELINE
     }
 
-    fn stroke_color(mut self, color: Option<C>) -> Self {
-        self.style.stroke_color = color;
+    fn glyph(&self, c: char) -> u32 {
+        Conf::char_offset(c)
+    }
 
-        self
+    fn glyph_width(&self, glyph: u32) -> (u32, u32) {
+        Conf::GLYPH_WIDTHS
+            .get(glyph as usize)
+            .map(|glyph| (u32::from(glyph.width), u32::from(glyph.advance)))
+            .unwrap_or((Conf::CHAR_WIDTH, Conf::CHAR_WIDTH))
     }
 
-    fn stroke_width(self, _width: u32) -> Self {
-        // Noop
+    fn pixel(&self, glyph: u32, x: u32, y: u32) -> bool {
+        self.coverage(glyph, x, y) >= 128
+    }
 
-        self
+    fn kerning(&self, left: char, right: char) -> i32 {
+        Conf::KERNING_PAIRS
+            .iter()
+            .find(|pair| pair.left == left && pair.right == right)
+            .map_or(0, |pair| i32::from(pair.adjustment))
     }
 
-    fn fill_color(mut self, color: Option<C>) -> Self {
-        self.style.fill_color = color;
+    fn coverage(&self, glyph: u32, x: u32, y: u32) -> u8 {
+        let char_per_row = Conf::FONT_IMAGE_WIDTH / Conf::CHAR_WIDTH;
+        let row = glyph / char_per_row;
+
+        // Top left corner of character, in pixels
+        let char_x = (glyph - (row * char_per_row)) * Conf::CHAR_WIDTH;
+        let char_y = row * Conf::CHAR_HEIGHT;
+
+        // Pixel index
+        // = X pixel offset for char
+        // + Character row offset (row 0 = 0, row 1 = (192 * 8) = 1536)
+        // + X offset for the pixel block that comprises this char
+        // + Y offset for pixel block
+        let pixel_index =
+            char_x + (Conf::FONT_IMAGE_WIDTH * char_y) + x + (y * Conf::FONT_IMAGE_WIDTH);
+
+        let bit_index = pixel_index * Conf::BITS_PER_PIXEL;
+        let bitmap_byte = bit_index / 8;
+        let shift = 8 - Conf::BITS_PER_PIXEL - (bit_index % 8);
+        let max_value = (1 << Conf::BITS_PER_PIXEL) - 1;
+
+        let value = (u32::from(Conf::FONT_IMAGE[bitmap_byte as usize]) >> shift) & max_value;
 
-        self
+        (value * 255 / max_value) as u8
     }
 }
 
+/// The font builder
+///
+/// This is a helper type to reduce code duplication when implementing fonts. View the [module
+/// level type definitions](../index.html#types) for a list of usable fonts.
+pub type FontBuilder<'a, Conf> = Text<'a, StaticFont<Conf>>;
+
 /// Pixel iterator for the `FontBuilder` object
-#[derive(Debug, Clone, Copy)]
-pub struct FontBuilderIterator<'a, C, Conf>
-where
-    C: PixelColor,
-{
-    char_walk_x: u32,
-    char_walk_y: u32,
-    current_char: Option<char>,
-    idx: usize,
-    pos: Point,
-    text: &'a str,
-    style: Style<C>,
-    _conf: PhantomData<Conf>,
-}
+pub type FontBuilderIterator<'a, C, Conf> = TextIterator<'a, C, StaticFont<Conf>>;
 
-impl<'a, C: 'a, Conf: 'a> IntoIterator for FontBuilder<'a, C, Conf>
+impl<'a, Conf> Font<'a> for FontBuilder<'a, Conf>
 where
-    C: PixelColor + From<BinaryColor>,
     Conf: FontBuilderConf,
 {
-    type Item = Pixel<C>;
-    type IntoIter = FontBuilderIterator<'a, C, Conf>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            current_char: self.text.chars().next(),
-            idx: 0,
-            text: self.text,
-            char_walk_x: 0,
-            char_walk_y: 0,
-            pos: self.pos,
-            style: self.style,
-            _conf: Default::default(),
-        }
+    fn render_str(text: &'a str) -> Self {
+        Text::new(text, StaticFont::new())
+    }
+
+    fn measure(text: &str) -> TextMetrics {
+        Text::new(text, StaticFont::<Conf>::new()).metrics()
+    }
+
+    fn measure_prefix(text: &str, max_width: u32) -> (&str, TextMetrics) {
+        Text::new(text, StaticFont::<Conf>::new()).prefix_metrics(max_width)
     }
 }
 
-impl<'a, C, Conf> IntoIterator for &FontBuilder<'a, C, Conf>
-where
-    C: PixelColor + From<BinaryColor>,
-    Conf: FontBuilderConf,
-{
-    type IntoIter = FontBuilderIterator<'a, C, Conf>;
-    type Item = Pixel<C>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            current_char: self.text.chars().next(),
-            idx: 0,
-            text: self.text,
-            char_walk_x: 0,
-            char_walk_y: 0,
-            pos: self.pos,
-            style: self.style,
-            _conf: Default::default(),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{Dimensions, Point, Rect, Size};
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::{BinaryColor, Gray8, GrayColor};
+    use crate::style::TextStyle;
+    use crate::Drawable;
+
+    /// Proportional test font with the glyphs "i", "m", "-" and a fallback glyph
+    #[derive(Debug, Copy, Clone)]
+    enum ProportionalConf {}
+
+    impl FontBuilderConf for ProportionalConf {
+        const FONT_IMAGE: &'static [u8] = &[
+            0b1000_1110,
+            0b0000_1111, //
+            0b1000_1010,
+            0b1100_1111, //
+        ];
+        const CHAR_HEIGHT: u32 = 2;
+        const CHAR_WIDTH: u32 = 4;
+        const FONT_IMAGE_WIDTH: u32 = 16;
+        const GLYPH_WIDTHS: &'static [GlyphWidth] = &[
+            GlyphWidth::new(1, 2),
+            GlyphWidth::new(3, 4),
+            GlyphWidth::new(2, 3),
+        ];
+
+        fn char_offset(c: char) -> u32 {
+            match c {
+                'i' => 0,
+                'm' => 1,
+                '-' => 2,
+                _ => 3,
+            }
         }
     }
-}
 
-impl<'a, C, Conf> Iterator for FontBuilderIterator<'a, C, Conf>
-where
-    C: PixelColor + From<BinaryColor>,
-    Conf: FontBuilderConf,
-{
-    type Item = Pixel<C>;
+    type ProportionalFont<'a> = FontBuilder<'a, ProportionalConf>;
+
+    /// Proportional test font with kerning between "m" and "i" and between "i" and "-"
+    #[derive(Debug, Copy, Clone)]
+    enum KernedConf {}
+
+    impl FontBuilderConf for KernedConf {
+        const FONT_IMAGE: &'static [u8] = ProportionalConf::FONT_IMAGE;
+        const CHAR_HEIGHT: u32 = ProportionalConf::CHAR_HEIGHT;
+        const CHAR_WIDTH: u32 = ProportionalConf::CHAR_WIDTH;
+        const FONT_IMAGE_WIDTH: u32 = ProportionalConf::FONT_IMAGE_WIDTH;
+        const GLYPH_WIDTHS: &'static [GlyphWidth] = ProportionalConf::GLYPH_WIDTHS;
+        const KERNING_PAIRS: &'static [KerningPair] = &[
+            KerningPair::new('m', 'i', -1),
+            KerningPair::new('i', '-', 2),
+        ];
+
+        fn char_offset(c: char) -> u32 {
+            ProportionalConf::char_offset(c)
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let char_per_row = Conf::FONT_IMAGE_WIDTH / Conf::CHAR_WIDTH;
+    type KernedFont<'a> = FontBuilder<'a, KernedConf>;
 
-        loop {
-            if let Some(current_char) = self.current_char {
-                // Char _code_ offset from first char, most often a space
-                // E.g. first char = ' ' (32), target char = '!' (33), offset = 33 - 32 = 1
-                let char_offset = Conf::char_offset(current_char);
-                let row = char_offset / char_per_row;
-
-                // Top left corner of character, in pixels
-                let char_x = (char_offset - (row * char_per_row)) * Conf::CHAR_WIDTH;
-                let char_y = row * Conf::CHAR_HEIGHT;
-
-                // Bit index
-                // = X pixel offset for char
-                // + Character row offset (row 0 = 0, row 1 = (192 * 8) = 1536)
-                // + X offset for the pixel block that comprises this char
-                // + Y offset for pixel block
-                let bitmap_bit_index = char_x
-                    + (Conf::FONT_IMAGE_WIDTH * char_y)
-                    + self.char_walk_x
-                    + (self.char_walk_y * Conf::FONT_IMAGE_WIDTH);
-
-                let bitmap_byte = bitmap_bit_index / 8;
-                let bitmap_bit = 7 - (bitmap_bit_index % 8);
-
-                let color = if Conf::FONT_IMAGE[bitmap_byte as usize] & (1 << bitmap_bit) != 0 {
-                    Some(
-                        self.style
-                            .stroke_color
-                            .unwrap_or_else(|| BinaryColor::On.into()),
-                    )
-                } else {
-                    self.style.fill_color
-                };
-
-                let x = self.pos.x
-                    + (Conf::CHAR_WIDTH * self.idx as u32) as i32
-                    + self.char_walk_x as i32;
-                let y = self.pos.y + self.char_walk_y as i32;
-
-                self.char_walk_x += 1;
-
-                if self.char_walk_x >= Conf::CHAR_WIDTH {
-                    self.char_walk_x = 0;
-                    self.char_walk_y += 1;
-
-                    // Done with this char, move on to the next one
-                    if self.char_walk_y >= Conf::CHAR_HEIGHT {
-                        self.char_walk_y = 0;
-                        self.idx += 1;
-                        self.current_char = self.text.chars().nth(self.idx);
-                    }
-                }
-
-                // Skip to next point if pixel is transparent
-                if let Some(color) = color {
-                    break Some(Pixel(Point::new(x, y), color));
-                }
-            } else {
-                break None;
-            }
+    /// Anti-aliased test font with a single 4x1 glyph with decreasing coverage
+    #[derive(Debug, Copy, Clone)]
+    enum AntialiasedConf {}
+
+    impl FontBuilderConf for AntialiasedConf {
+        const FONT_IMAGE: &'static [u8] = &[0b11_10_01_00];
+        const CHAR_HEIGHT: u32 = 1;
+        const CHAR_WIDTH: u32 = 4;
+        const FONT_IMAGE_WIDTH: u32 = 4;
+        const BITS_PER_PIXEL: u32 = 2;
+
+        fn char_offset(_: char) -> u32 {
+            0
         }
     }
-}
 
-impl<'a, C, Conf> Drawable<C> for &FontBuilder<'a, C, Conf>
-where
-    C: PixelColor + From<BinaryColor>,
-    Conf: FontBuilderConf,
-{
-    fn draw<D: DrawTarget<C>>(self, display: &mut D) {
-        display.draw_iter(self.into_iter());
+    type AntialiasedFont<'a> = FontBuilder<'a, AntialiasedConf>;
+
+    #[test]
+    fn proportional_size() {
+        assert_eq!(ProportionalFont::render_str("mi-").size(), Size::new(9, 2));
+        assert_eq!(ProportionalFont::render_str("ii").size(), Size::new(4, 2));
+        // The fallback glyph has no entry in the table and uses the cell width
+        assert_eq!(ProportionalFont::render_str("?i").size(), Size::new(6, 2));
+        assert_eq!(ProportionalFont::render_str("").size(), Size::zero());
     }
-}
 
-impl<'a, C, Conf> Transform for FontBuilder<'a, C, Conf>
-where
-    C: PixelColor,
-{
-    /// Translate the image from its current position to a new position by (x, y) pixels, returning
-    /// a new `Font8x16`. For a mutating transform, see `translate_mut`.
-    ///
-    /// ```
-    /// # use embedded_graphics::fonts::{ Font, Font8x16 };
-    /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::BinaryColor;
-    /// #
-    /// # let style = Style::stroke_color(BinaryColor::On);
-    /// #
-    /// // 8px x 1px test image
-    /// let text = Font8x16::render_str("Hello world")
-    /// #    .style(style);
-    /// let moved = text.translate(Point::new(25, 30));
-    ///
-    /// assert_eq!(text.pos, Point::new(0, 0));
-    /// assert_eq!(moved.pos, Point::new(25, 30));
-    /// ```
-    fn translate(&self, by: Point) -> Self {
-        Self {
-            pos: self.pos + by,
-            ..*self
-        }
+    #[test]
+    fn proportional_layout() {
+        let mut display = MockDisplay::new();
+        ProportionalFont::render_str("mi-")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "### #    ", //
+                "# # # ## ", //
+            ])
+        );
+
+        let mut display = MockDisplay::new();
+        ProportionalFont::render_str("mi-?")
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "###.#....####", //
+                "#.#.#.##.####", //
+            ])
+        );
     }
 
-    /// Translate the font origin from its current position to a new position by (x, y) pixels.
-    ///
-    /// ```
-    /// # use embedded_graphics::fonts::{ Font, Font8x16 };
-    /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::BinaryColor;
-    /// #
-    /// # let style = Style::stroke_color(BinaryColor::On);
-    /// #
-    /// // 8px x 1px test image
-    /// let mut text = Font8x16::render_str("Hello world")
-    /// #    .style(style);
-    /// text.translate_mut(Point::new(25, 30));
-    ///
-    /// assert_eq!(text.pos, Point::new(25, 30));
-    /// ```
-    fn translate_mut(&mut self, by: Point) -> &mut Self {
-        self.pos += by;
+    #[test]
+    fn antialiased_coverage() {
+        let font = StaticFont::<AntialiasedConf>::new();
+
+        assert_eq!(font.coverage(0, 0, 0), 255);
+        assert_eq!(font.coverage(0, 1, 0), 170);
+        assert_eq!(font.coverage(0, 2, 0), 85);
+        assert_eq!(font.coverage(0, 3, 0), 0);
+        assert!(font.pixel(0, 1, 0));
+        assert!(!font.pixel(0, 2, 0));
+    }
+
+    #[test]
+    fn antialiased_blending() {
+        let luma =
+            |display: &MockDisplay<Gray8>, x| display.get_pixel(Point::new(x, 0)).map(|c| c.luma());
+
+        let mut display = MockDisplay::new();
+        AntialiasedFont::render_str("a")
+            .into_styled(
+                TextStyle::with_text_color(Gray8::new(200)).background_color(Some(Gray8::new(50))),
+            )
+            .draw(&mut display);
+        assert_eq!(luma(&display, 0), Some(200));
+        assert_eq!(luma(&display, 1), Some(150));
+        assert_eq!(luma(&display, 2), Some(100));
+        assert_eq!(luma(&display, 3), Some(50));
+
+        // Without a background color the coverage is thresholded
+        let mut display = MockDisplay::new();
+        AntialiasedFont::render_str("a")
+            .into_styled(TextStyle::with_text_color(Gray8::new(200)))
+            .draw(&mut display);
+        assert_eq!(luma(&display, 0), Some(200));
+        assert_eq!(luma(&display, 1), Some(200));
+        assert_eq!(luma(&display, 2), None);
+        assert_eq!(luma(&display, 3), None);
+
+        let mut display = MockDisplay::new();
+        AntialiasedFont::render_str("a")
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+        assert_eq!(display, MockDisplay::from_pattern(&["##.."]));
+    }
+
+    #[test]
+    fn proportional_bold() {
+        let style = TextStyle::with_text_color(BinaryColor::On).bold(true);
+        let text = ProportionalFont::render_str("?");
+        assert_eq!(text.size(), Size::new(4, 2));
+        assert_eq!(text.into_styled(style).size(), Size::new(5, 2));
+        assert_eq!(
+            text.into_styled(style).bounding_box(),
+            Rect::new(Point::zero(), Size::new(5, 2))
+        );
+
+        let mut display = MockDisplay::new();
+        ProportionalFont::render_str("mi-")
+            .into_styled(style)
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "######   ", //
+                "#########", //
+            ])
+        );
+    }
+
+    #[test]
+    fn kerning() {
+        assert_eq!(KernedFont::render_str("mi-").size(), Size::new(10, 2));
+        assert_eq!(KernedFont::render_str("im").size(), Size::new(6, 2));
+
+        let metrics = KernedFont::measure("mi-");
+        assert_eq!(metrics.bounding_box.size, Size::new(10, 2));
+        assert_eq!(metrics.next_position, Point::new(10, 0));
+
+        let mut display = MockDisplay::new();
+        KernedFont::render_str("mi-")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "####      ", //
+                "# ##   ## ", //
+            ])
+        );
+    }
 
-        self
+    #[test]
+    fn letter_spacing() {
+        let text = ProportionalFont::render_str("mi-");
+        assert_eq!(text.letter_spacing(1).size(), Size::new(12, 2));
+        assert_eq!(text.letter_spacing(-1).size(), Size::new(6, 2));
+        assert_eq!(
+            KernedFont::render_str("mi-").letter_spacing(-1).size(),
+            Size::new(7, 2)
+        );
+
+        let metrics = KernedFont::render_str("mi-").letter_spacing(-1).metrics();
+        assert_eq!(metrics.bounding_box.size, Size::new(7, 2));
+        assert_eq!(metrics.next_position, Point::new(7, 0));
+        assert_eq!(text.letter_spacing(1).prefix_metrics(8).0, "mi");
+
+        let mut display = MockDisplay::new();
+        text.letter_spacing(-1)
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "####..", //
+                "#.####", //
+            ])
+        );
+
+        // The background of overlapping glyphs doesn't hide the previous glyphs
+        let mut display = MockDisplay::new();
+        text.letter_spacing(-2)
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "### ", //
+                "#.##", //
+            ])
+        );
     }
 }
@@ -0,0 +1,193 @@
+//! Mapping from characters to glyph indices.
+//!
+//! A [`GlyphMap`] is a table of character ranges, sorted by code point. Each range maps a
+//! continuous block of code points to a continuous block of glyphs in the font image. Characters
+//! which aren't covered by any range are mapped to a fallback glyph.
+//!
+//! # Examples
+//!
+//! ## Map ASCII, Greek and a few symbols
+//!
+//! ```rust
+//! use embedded_graphics::fonts::glyph_map::{GlyphMap, GlyphRange};
+//!
+//! const GLYPH_MAP: GlyphMap = GlyphMap::new(
+//!     &[
+//!         // Printable ASCII characters are glyphs 0 to 94
+//!         GlyphRange::new(' ', '~', 0),
+//!         // Single symbols are stored after the Greek letters
+//!         GlyphRange::new('°', '°', 120),
+//!         // Greek capital letters are glyphs 95 to 119
+//!         GlyphRange::new('Α', 'Ω', 95),
+//!         GlyphRange::new('€', '€', 121),
+//!     ],
+//!     // Use the question mark for all other characters
+//!     '?' as u32 - ' ' as u32,
+//! );
+//!
+//! assert_eq!(GLYPH_MAP.index('A'), 33);
+//! assert_eq!(GLYPH_MAP.index('Β'), 96);
+//! assert_eq!(GLYPH_MAP.index('€'), 121);
+//! assert_eq!(GLYPH_MAP.index('ÿ'), 31);
+//! ```
+//!
+//! The glyph map is used to implement [`FontBuilderConf::char_offset`]:
+//!
+//! ```rust
+//! use embedded_graphics::fonts::font_builder::FontBuilderConf;
+//! use embedded_graphics::fonts::glyph_map::{GlyphMap, GlyphRange};
+//!
+//! const GLYPH_MAP: GlyphMap = GlyphMap::new(&[GlyphRange::new(' ', '~', 0)], 31);
+//!
+//! #[derive(Debug, Copy, Clone)]
+//! pub enum MyFontConf {}
+//!
+//! impl FontBuilderConf for MyFontConf {
+//!     const FONT_IMAGE: &'static [u8] = &[0; 6 * 96];
+//!     const CHAR_HEIGHT: u32 = 8;
+//!     const CHAR_WIDTH: u32 = 6;
+//!     const FONT_IMAGE_WIDTH: u32 = 96;
+//!
+//!     fn char_offset(c: char) -> u32 {
+//!         GLYPH_MAP.index(c)
+//!     }
+//! }
+//! ```
+//!
+//! [`GlyphMap`]: struct.GlyphMap.html
+//! [`FontBuilderConf::char_offset`]: ../font_builder/trait.FontBuilderConf.html#tymethod.char_offset
+
+use core::cmp::Ordering;
+
+/// A continuous range of characters which is mapped to continuous glyphs
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GlyphRange {
+    /// First character in the range
+    pub start: char,
+
+    /// Last character in the range
+    pub end: char,
+
+    /// Glyph index of the first character
+    pub index: u32,
+}
+
+impl GlyphRange {
+    /// Creates a new range which maps the characters `start` to `end` (both inclusive) to the
+    /// glyphs starting at `index`.
+    pub const fn new(start: char, end: char, index: u32) -> Self {
+        Self { start, end, index }
+    }
+}
+
+/// Mapping from characters to glyph indices
+///
+/// See the [module level documentation](index.html) for examples.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GlyphMap<'a> {
+    ranges: &'a [GlyphRange],
+    fallback: u32,
+}
+
+impl<'a> GlyphMap<'a> {
+    /// Creates a new glyph map.
+    ///
+    /// The ranges must be sorted by their start character and must not overlap. Characters which
+    /// aren't part of any range are mapped to the `fallback` glyph.
+    pub const fn new(ranges: &'a [GlyphRange], fallback: u32) -> Self {
+        Self { ranges, fallback }
+    }
+
+    /// Returns the glyph index of a character, or `None` if the character isn't part of the map.
+    pub fn get(&self, c: char) -> Option<u32> {
+        self.ranges
+            .binary_search_by(|range| {
+                if range.end < c {
+                    Ordering::Less
+                } else if range.start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|i| {
+                let range = &self.ranges[i];
+
+                range.index + (c as u32 - range.start as u32)
+            })
+    }
+
+    /// Returns the glyph index of a character, or the fallback glyph if the character isn't part
+    /// of the map.
+    pub fn index(&self, c: char) -> u32 {
+        self.get(c).unwrap_or(self.fallback)
+    }
+
+    /// Returns the glyph index which is used for unmapped characters.
+    pub fn fallback(&self) -> u32 {
+        self.fallback
+    }
+
+    /// Returns a copy of this map with a different fallback glyph.
+    pub const fn with_fallback(self, fallback: u32) -> Self {
+        Self {
+            ranges: self.ranges,
+            fallback,
+        }
+    }
+
+    /// Returns the ranges of the map.
+    pub fn ranges(&self) -> &'a [GlyphRange] {
+        self.ranges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: GlyphMap = GlyphMap::new(
+        &[
+            GlyphRange::new('!', '!', 7),
+            GlyphRange::new('0', '9', 10),
+            GlyphRange::new('Α', 'Ω', 20),
+            GlyphRange::new('А', 'я', 50),
+            GlyphRange::new('💣', '💣', 2),
+        ],
+        0,
+    );
+
+    #[test]
+    fn lookup() {
+        assert_eq!(MAP.get('!'), Some(7));
+        assert_eq!(MAP.get('0'), Some(10));
+        assert_eq!(MAP.get('9'), Some(19));
+        assert_eq!(MAP.get('Α'), Some(20));
+        assert_eq!(MAP.get('Ω'), Some(20 + ('Ω' as u32 - 'Α' as u32)));
+        assert_eq!(MAP.get('Ж'), Some(50 + ('Ж' as u32 - 'А' as u32)));
+        assert_eq!(MAP.get('💣'), Some(2));
+    }
+
+    #[test]
+    fn fallback() {
+        for &c in ['\0', ' ', '"', '/', ':', 'A', 'ÿ', 'ω', '€', '\u{10FFFF}'].iter() {
+            assert_eq!(MAP.get(c), None, "{:?}", c);
+            assert_eq!(MAP.index(c), 0, "{:?}", c);
+        }
+
+        let map = MAP.with_fallback(3);
+        assert_eq!(map.fallback(), 3);
+        assert_eq!(map.index('A'), 3);
+        assert_eq!(map.index('5'), 15);
+    }
+
+    #[test]
+    fn empty() {
+        let map = GlyphMap::new(&[], 5);
+
+        assert_eq!(map.get('a'), None);
+        assert_eq!(map.index('a'), 5);
+        assert!(map.ranges().is_empty());
+    }
+}
@@ -11,12 +11,15 @@
 //! use embedded_graphics::prelude::*;
 //! use embedded_graphics::fonts::Font6x8;
 //! use embedded_graphics::text_6x8;
+//! use embedded_graphics::style::TextStyle;
 //! # use embedded_graphics::mock_display::MockDisplay;
 //! # use embedded_graphics::pixelcolor::BinaryColor;
 //! # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
 //!
 //! // Use struct methods directly
-//! Font6x8::render_str("Hello Rust!").draw(&mut display);
+//! Font6x8::render_str("Hello Rust!")
+//!     .into_styled(TextStyle::with_text_color(BinaryColor::On))
+//!     .draw(&mut display);
 //!
 //! // Use a macro instead
 //! text_6x8!("Hello Rust!").draw(&mut display);
@@ -27,37 +30,86 @@
 //! ```rust
 //! use embedded_graphics::prelude::*;
 //! use embedded_graphics::fonts::Font6x8;
+//! use embedded_graphics::style::TextStyle;
 //! # use embedded_graphics::mock_display::MockDisplay;
 //! # use embedded_graphics::pixelcolor::BinaryColor;
 //! # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
 //!
-//! Font6x8::render_str("Hello Rust!").translate(Point::new(20, 30)).draw(&mut display)
+//! Font6x8::render_str("Hello Rust!")
+//!     .translate(Point::new(20, 30))
+//!     .into_styled(TextStyle::with_text_color(BinaryColor::On))
+//!     .draw(&mut display)
 //! ```
 //!
 //! ## Add some styling to the text
 //!
-//! Use [any method provided by the `WithStyle` trait](../style/trait.WithStyle.html#required-methods).
-//! Properties like `fill_color` or `stroke_color` passed to the `text_6x8` macro are converted into method
-//! calls verbatim.
+//! Text is styled with a [`TextStyle`]. Properties like `text_color` or `background_color` passed
+//! to the `text_6x8` macro are converted into calls to the corresponding `TextStyle` methods.
 //!
 //! ```rust
 //! use embedded_graphics::prelude::*;
 //! use embedded_graphics::text_6x8;
 //! use embedded_graphics::fonts::Font6x8;
 //! use embedded_graphics::pixelcolor::Rgb565;
+//! use embedded_graphics::style::TextStyle;
 //! # use embedded_graphics::mock_display::MockDisplay;
 //! # let mut display = MockDisplay::default();
 //!
 //! text_6x8!(
 //!     "Hello Rust!",
-//!     fill_color = Some(Rgb565::BLUE),
-//!     stroke_color = Some(Rgb565::YELLOW)
+//!     background_color = Some(Rgb565::BLUE),
+//!     text_color = Some(Rgb565::YELLOW)
 //! ).draw(&mut display);
 //!
+//! let style = TextStyle::with_text_color(Rgb565::YELLOW).background_color(Some(Rgb565::BLUE));
+//!
 //! Font6x8::render_str("Hello Rust!")
 //!     .translate(Point::new(20, 30))
-//!     .fill_color(Some(Rgb565::BLUE))
-//!     .stroke_color(Some(Rgb565::YELLOW))
+//!     .into_styled(style)
+//!     .draw(&mut display);
+//! ```
+//!
+//! ## Draw multiple lines of text
+//!
+//! Text is split into lines at each `\n` character. The space between the lines can be increased
+//! with `line_spacing`.
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::fonts::Font6x8;
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! use embedded_graphics::style::TextStyle;
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+//!
+//! let text = Font6x8::render_str("Temperature\n21.5 C").line_spacing(2);
+//! assert_eq!(text.size(), Size::new(66, 18));
+//!
+//! text.into_styled(TextStyle::with_text_color(BinaryColor::On))
+//!     .draw(&mut display);
+//! ```
+//!
+//! ## Center a label on a button
+//!
+//! The text position is the top left corner of the text by default. The horizontal and vertical
+//! alignment move the text relative to its position, which makes it easy to center text.
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::fonts::{Font6x8, HorizontalAlignment, VerticalAlignment};
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! use embedded_graphics::style::TextStyle;
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+//!
+//! let text = Font6x8::render_str("OK")
+//!     .horizontal_alignment(HorizontalAlignment::Center)
+//!     .vertical_alignment(VerticalAlignment::Middle)
+//!     .translate(Point::new(32, 16));
+//!
+//! assert_eq!(text.top_left(), Point::new(26, 12));
+//!
+//! text.into_styled(TextStyle::with_text_color(BinaryColor::On))
 //!     .draw(&mut display);
 //! ```
 //!
@@ -87,13 +139,14 @@
 //!
 //! text_6x8!(
 //!     &buf,
-//!     fill_color = Some(Rgb565::BLUE),
-//!     stroke_color = Some(Rgb565::YELLOW)
+//!     background_color = Some(Rgb565::BLUE),
+//!     text_color = Some(Rgb565::YELLOW)
 //! ).draw(&mut display);
 //! ```
 //!
 //! [`text_6x8`]: ../macro.text_6x8.html
 //! [`Font6x8`]: ./type.Font6x8.html
+//! [`TextStyle`]: ../style/struct.TextStyle.html
 //! [`ArrayString`]: https://docs.rs/arrayvec/0.4.11/arrayvec/struct.ArrayString.html
 //! [`write!()`]: https://doc.rust-lang.org/nightly/std/macro.write.html
 
@@ -103,52 +156,101 @@ mod font6x12;
 mod font6x8;
 mod font8x16;
 pub mod font_builder;
+pub mod glyph_map;
+pub mod psf;
+mod text;
+mod text_box;
 
 pub use self::font12x16::Font12x16;
 pub use self::font24x32::Font24x32;
 pub use self::font6x12::Font6x12;
 pub use self::font6x8::Font6x8;
 pub use self::font8x16::Font8x16;
+pub use self::text::{
+    GlyphSource, HorizontalAlignment, Text, TextIterator, TextMetrics, TextSpans, VerticalAlignment,
+};
+pub use self::text_box::{Overflow, TextBox, TextBoxIterator};
 use crate::geometry::Dimensions;
 use crate::pixelcolor::PixelColor;
-use crate::style::WithStyle;
+use crate::style::{Styled, TextStyle};
 
 /// Common methods for all fonts
-pub trait Font<'a, C>: WithStyle<C> + Dimensions
-where
-    C: PixelColor,
-{
+pub trait Font<'a>: Dimensions {
     /// Render a string in the implementing font's typeface.
     ///
-    /// Defaults to 1u8 for stroke_color and 0u8 for fill_color
+    /// The returned object only describes the position and content of the text. It needs to be
+    /// styled with [`into_styled`](#method.into_styled) before it can be drawn.
     ///
     /// ```rust
     /// use embedded_graphics::prelude::*;
     /// use embedded_graphics::fonts::Font6x8;
     /// use embedded_graphics::pixelcolor::Rgb565;
+    /// use embedded_graphics::style::TextStyle;
     /// # use embedded_graphics::mock_display::MockDisplay as Display;
     ///
     /// fn main() {
     ///     let mut disp = Display::default();
-    ///     // Render a string with a red stroke
+    ///     // Render a string in red
     ///     let text = Font6x8::render_str("Hello world")
-    ///         .style(Style::stroke_color(Rgb565::RED));
+    ///         .into_styled(TextStyle::with_text_color(Rgb565::RED));
     ///
     ///     text.draw(&mut disp);
     /// }
     /// ```
     fn render_str(chars: &'a str) -> Self;
+
+    /// Measures a string in the implementing font's typeface without drawing it.
+    ///
+    /// This is the same as measuring the result of [`render_str`](#tymethod.render_str) with
+    /// [`Text::metrics`](struct.Text.html#method.metrics), see there for details.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    ///
+    /// let metrics = Font6x8::measure("Hello\nworld!");
+    /// assert_eq!(metrics.bounding_box.size, Size::new(36, 16));
+    /// assert_eq!(metrics.next_position, Point::new(36, 8));
+    /// assert_eq!(metrics.line_count, 2);
+    /// ```
+    fn measure(text: &str) -> TextMetrics;
+
+    /// Measures the longest prefix of a string which fits into the given width.
+    ///
+    /// This is the same as measuring the result of [`render_str`](#tymethod.render_str) with
+    /// [`Text::prefix_metrics`](struct.Text.html#method.prefix_metrics), see there for details.
+    ///
+    /// ```rust
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    ///
+    /// let (prefix, metrics) = Font6x8::measure_prefix("Hello world!", 40);
+    /// assert_eq!(prefix, "Hello ");
+    /// assert_eq!(metrics.bounding_box.size.width, 36);
+    /// ```
+    fn measure_prefix(text: &str, max_width: u32) -> (&str, TextMetrics);
+
+    /// Converts this text into a styled text with the given style.
+    fn into_styled<C>(self, style: TextStyle<C>) -> Styled<Self, TextStyle<C>>
+    where
+        C: PixelColor,
+        Self: Sized,
+    {
+        Styled::new(self, style)
+    }
 }
 
 /// Internal macro used to implement `text_*` on fonts. Do not use directly!
 #[doc(hidden)]
 #[macro_export]
 macro_rules! impl_text {
+    ($Font:ident, $text:expr, style = $style:expr $(,)?) => {{
+        use $crate::fonts::Font;
+        $crate::fonts::$Font::render_str($text).into_styled($style)
+    }};
     ($Font:ident, $text:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
-        #[allow(unused_imports)]
-        use $crate::style::WithStyle;
+        use $crate::fonts::Font;
         $crate::fonts::$Font::render_str($text)
-            $( .$style_key($style_value) )*
+            .into_styled($crate::style::TextStyle::default() $( .$style_key($style_value) )*)
     }};
 }
 
@@ -157,16 +259,16 @@ macro_rules! impl_text {
 /// ```rust
 /// use embedded_graphics::{text_6x8, prelude::*, fonts::Font6x8, pixelcolor::Rgb565};
 ///
-/// let text: Font6x8<Rgb565> = text_6x8!("Hello world!");
-/// let styled_text: Font6x8<Rgb565> = text_6x8!(
+/// let text: Styled<Font6x8, TextStyle<Rgb565>> = text_6x8!("Hello world!");
+/// let styled_text: Styled<Font6x8, TextStyle<Rgb565>> = text_6x8!(
 ///     "Hello world!",
-///     stroke_color = Some(Rgb565::RED),
-///     fill_color = Some(Rgb565::GREEN)
+///     text_color = Some(Rgb565::RED),
+///     background_color = Some(Rgb565::GREEN)
 /// );
 /// ```
 ///
-/// Style properties like `stroke` map to the method calls on the
-/// [`WithStyle`](./style/trait.WithStyle.html) trait.
+/// Style properties like `text_color` map to the method calls on the
+/// [`TextStyle`](./style/struct.TextStyle.html) struct.
 #[macro_export]
 macro_rules! text_6x8 {
     ($text:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
@@ -179,16 +281,16 @@ macro_rules! text_6x8 {
 /// ```rust
 /// use embedded_graphics::{text_6x12, prelude::*, fonts::Font6x12, pixelcolor::Rgb565};
 ///
-/// let text: Font6x12<Rgb565> = text_6x12!("Hello world!");
-/// let styled_text: Font6x12<Rgb565> = text_6x12!(
+/// let text: Styled<Font6x12, TextStyle<Rgb565>> = text_6x12!("Hello world!");
+/// let styled_text: Styled<Font6x12, TextStyle<Rgb565>> = text_6x12!(
 ///     "Hello world!",
-///     stroke_color = Some(Rgb565::RED),
-///     fill_color = Some(Rgb565::GREEN)
+///     text_color = Some(Rgb565::RED),
+///     background_color = Some(Rgb565::GREEN)
 /// );
 /// ```
 ///
-/// Style properties like `stroke` map to the method calls on the
-/// [`WithStyle`](./style/trait.WithStyle.html) trait.
+/// Style properties like `text_color` map to the method calls on the
+/// [`TextStyle`](./style/struct.TextStyle.html) struct.
 #[macro_export]
 macro_rules! text_6x12 {
     ($text:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
@@ -201,16 +303,16 @@ macro_rules! text_6x12 {
 /// ```rust
 /// use embedded_graphics::{text_8x16, prelude::*, fonts::Font8x16, pixelcolor::Rgb565};
 ///
-/// let text: Font8x16<Rgb565> = text_8x16!("Hello world!");
-/// let styled_text: Font8x16<Rgb565> = text_8x16!(
+/// let text: Styled<Font8x16, TextStyle<Rgb565>> = text_8x16!("Hello world!");
+/// let styled_text: Styled<Font8x16, TextStyle<Rgb565>> = text_8x16!(
 ///     "Hello world!",
-///     stroke_color = Some(Rgb565::RED),
-///     fill_color = Some(Rgb565::GREEN)
+///     text_color = Some(Rgb565::RED),
+///     background_color = Some(Rgb565::GREEN)
 /// );
 /// ```
 ///
-/// Style properties like `stroke` map to the method calls on the
-/// [`WithStyle`](./style/trait.WithStyle.html) trait.
+/// Style properties like `text_color` map to the method calls on the
+/// [`TextStyle`](./style/struct.TextStyle.html) struct.
 #[macro_export]
 macro_rules! text_8x16 {
     ($text:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
@@ -223,16 +325,16 @@ macro_rules! text_8x16 {
 /// ```rust
 /// use embedded_graphics::{text_12x16, prelude::*, fonts::Font12x16, pixelcolor::Rgb565};
 ///
-/// let text: Font12x16<Rgb565> = text_12x16!("Hello world!");
-/// let styled_text: Font12x16<Rgb565> = text_12x16!(
+/// let text: Styled<Font12x16, TextStyle<Rgb565>> = text_12x16!("Hello world!");
+/// let styled_text: Styled<Font12x16, TextStyle<Rgb565>> = text_12x16!(
 ///     "Hello world!",
-///     stroke_color = Some(Rgb565::RED),
-///     fill_color = Some(Rgb565::GREEN)
+///     text_color = Some(Rgb565::RED),
+///     background_color = Some(Rgb565::GREEN)
 /// );
 /// ```
 ///
-/// Style properties like `stroke` map to the method calls on the
-/// [`WithStyle`](./style/trait.WithStyle.html) trait.
+/// Style properties like `text_color` map to the method calls on the
+/// [`TextStyle`](./style/struct.TextStyle.html) struct.
 #[macro_export]
 macro_rules! text_12x16 {
     ($text:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
@@ -245,16 +347,16 @@ macro_rules! text_12x16 {
 /// ```rust
 /// use embedded_graphics::{text_24x32, prelude::*, fonts::Font24x32, pixelcolor::Rgb565};
 ///
-/// let text: Font24x32<Rgb565> = text_24x32!("Hello world!");
-/// let styled_text: Font24x32<Rgb565> = text_24x32!(
+/// let text: Styled<Font24x32, TextStyle<Rgb565>> = text_24x32!("Hello world!");
+/// let styled_text: Styled<Font24x32, TextStyle<Rgb565>> = text_24x32!(
 ///     "Hello world!",
-///     stroke_color = Some(Rgb565::RED),
-///     fill_color = Some(Rgb565::GREEN)
+///     text_color = Some(Rgb565::RED),
+///     background_color = Some(Rgb565::GREEN)
 /// );
 /// ```
 ///
-/// Style properties like `stroke` map to the method calls on the
-/// [`WithStyle`](./style/trait.WithStyle.html) trait.
+/// Style properties like `text_color` map to the method calls on the
+/// [`TextStyle`](./style/struct.TextStyle.html) struct.
 #[macro_export]
 macro_rules! text_24x32 {
     ($text:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
@@ -269,19 +371,24 @@ mod tests {
 
     #[test]
     fn font_macros() {
-        let _text: Font6x8<BinaryColor> = text_6x8!("Hello!");
-        let _text: Font6x12<BinaryColor> = text_6x12!("Hello!");
-        let _text: Font8x16<BinaryColor> = text_8x16!("Hello!");
-        let _text: Font12x16<BinaryColor> = text_12x16!("Hello!");
-        let _text: Font24x32<BinaryColor> = text_24x32!("Hello!");
+        let _text: Styled<Font6x8, TextStyle<BinaryColor>> = text_6x8!("Hello!");
+        let _text: Styled<Font6x12, TextStyle<BinaryColor>> = text_6x12!("Hello!");
+        let _text: Styled<Font8x16, TextStyle<BinaryColor>> = text_8x16!("Hello!");
+        let _text: Styled<Font12x16, TextStyle<BinaryColor>> = text_12x16!("Hello!");
+        let _text: Styled<Font24x32, TextStyle<BinaryColor>> = text_24x32!("Hello!");
     }
 
     #[test]
     fn styled_text() {
-        let _text: Font6x8<Rgb565> = text_6x8!("Hello!", stroke_color = Some(Rgb565::RED));
-        let _text: Font6x12<Rgb565> = text_6x12!("Hello!", stroke_color = Some(Rgb565::GREEN));
-        let _text: Font8x16<Rgb565> = text_8x16!("Hello!", stroke_color = Some(Rgb565::BLUE));
-        let _text: Font12x16<Rgb565> = text_12x16!("Hello!", stroke_color = Some(Rgb565::YELLOW));
-        let _text: Font24x32<Rgb565> = text_24x32!("Hello!", stroke_color = Some(Rgb565::MAGENTA));
+        let _text: Styled<Font6x8, TextStyle<Rgb565>> =
+            text_6x8!("Hello!", text_color = Some(Rgb565::RED));
+        let _text: Styled<Font6x12, TextStyle<Rgb565>> =
+            text_6x12!("Hello!", text_color = Some(Rgb565::GREEN));
+        let _text: Styled<Font8x16, TextStyle<Rgb565>> =
+            text_8x16!("Hello!", text_color = Some(Rgb565::BLUE));
+        let _text: Styled<Font12x16, TextStyle<Rgb565>> =
+            text_12x16!("Hello!", text_color = Some(Rgb565::YELLOW));
+        let _text: Styled<Font24x32, TextStyle<Rgb565>> =
+            text_24x32!("Hello!", text_color = Some(Rgb565::MAGENTA));
     }
 }
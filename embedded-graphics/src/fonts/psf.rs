@@ -0,0 +1,566 @@
+//! Linux console fonts in the PC Screen Font format
+//!
+//! [`PsfFont`] parses PSF1 and PSF2 fonts, like the fonts in `/usr/share/consolefonts`, from a
+//! byte slice. The font data is borrowed and not copied, which makes it possible to load fonts at
+//! runtime, e.g. from a filesystem or a flash partition.
+//!
+//! Characters are mapped to glyphs using the Unicode table of the font. Fonts without a Unicode
+//! table map each code point to the glyph with the same index. Characters which aren't included in
+//! the font are drawn with the glyph for `U+FFFD REPLACEMENT CHARACTER`, or `?` if the font
+//! doesn't contain this glyph.
+//!
+//! # Examples
+//!
+//! ## Draw text with a PSF font
+//!
+//! ```rust
+//! use embedded_graphics::fonts::psf::PsfFont;
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::style::TextStyle;
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+//!
+//! // A PSF1 font with 256 glyphs of 8x1 pixels and no Unicode table
+//! let mut data = [0u8; 4 + 256];
+//! data[0..4].copy_from_slice(&[0x36, 0x04, 0x00, 1]);
+//! data[4 + 'A' as usize] = 0b1010_0000;
+//!
+//! let font = PsfFont::parse(&data).expect("invalid PSF font");
+//! assert_eq!(font.glyph_count(), 256);
+//!
+//! font.render_str("AA")
+//!     .translate(Point::new(10, 20))
+//!     .into_styled(TextStyle::with_text_color(BinaryColor::On))
+//!     .draw(&mut display);
+//! ```
+//!
+//! [`PsfFont`]: ./struct.PsfFont.html
+
+use crate::fonts::{GlyphSource, Text};
+use core::str;
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_MODE_512: u8 = 0x01;
+const PSF1_MODE_HAS_TAB: u8 = 0x02;
+const PSF1_MODE_HAS_SEQ: u8 = 0x04;
+const PSF1_SEPARATOR: u16 = 0xFFFF;
+const PSF1_START_SEQ: u16 = 0xFFFE;
+
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+const PSF2_HEADER_SIZE: usize = 32;
+const PSF2_SEPARATOR: u8 = 0xFF;
+const PSF2_START_SEQ: u8 = 0xFE;
+
+/// Errors which can occur while parsing a PSF font
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PsfError {
+    /// The data doesn't start with the PSF1 or PSF2 magic bytes.
+    InvalidMagic,
+
+    /// The PSF2 version isn't supported. Holds the version number.
+    UnsupportedVersion(u32),
+
+    /// The header contains invalid glyph dimensions or a glyph count of zero.
+    InvalidHeader,
+
+    /// The data is shorter than the size given in the header.
+    Truncated,
+}
+
+/// Format of the Unicode table
+#[derive(Debug, Copy, Clone)]
+enum UnicodeTable<'a> {
+    /// No Unicode table, code points are used as glyph indices
+    None,
+
+    /// Little endian UCS-2 values, used by PSF1
+    Ucs2(&'a [u8]),
+
+    /// UTF-8 encoded strings, used by PSF2
+    Utf8(&'a [u8]),
+}
+
+/// A PSF1 or PSF2 font
+///
+/// See the [module level documentation](./index.html) for more information.
+#[derive(Debug, Copy, Clone)]
+pub struct PsfFont<'a> {
+    glyphs: &'a [u8],
+    unicode_table: UnicodeTable<'a>,
+    glyph_count: u32,
+    width: u32,
+    height: u32,
+    bytes_per_row: u32,
+    bytes_per_glyph: u32,
+    baseline: u32,
+    fallback: u32,
+}
+
+impl<'a> PsfFont<'a> {
+    /// Parses a PSF1 or PSF2 font.
+    pub fn parse(data: &'a [u8]) -> Result<Self, PsfError> {
+        let mut font = if data.starts_with(&PSF2_MAGIC) {
+            Self::parse_psf2(data)?
+        } else if data.starts_with(&PSF1_MAGIC) {
+            Self::parse_psf1(data)?
+        } else {
+            return Err(PsfError::InvalidMagic);
+        };
+
+        font.fallback = font
+            .lookup('\u{FFFD}')
+            .or_else(|| font.lookup('?'))
+            .unwrap_or(0);
+
+        Ok(font)
+    }
+
+    fn parse_psf1(data: &'a [u8]) -> Result<Self, PsfError> {
+        if data.len() < 4 {
+            return Err(PsfError::Truncated);
+        }
+
+        let mode = data[2];
+        let height = u32::from(data[3]);
+        let glyph_count = if mode & PSF1_MODE_512 != 0 { 512 } else { 256 };
+
+        if height == 0 {
+            return Err(PsfError::InvalidHeader);
+        }
+
+        let (glyphs, table) = split_glyphs(data, 4, glyph_count, height)?;
+
+        let unicode_table = if mode & (PSF1_MODE_HAS_TAB | PSF1_MODE_HAS_SEQ) != 0 {
+            UnicodeTable::Ucs2(table)
+        } else {
+            UnicodeTable::None
+        };
+
+        Ok(Self::new(
+            glyphs,
+            unicode_table,
+            glyph_count,
+            8,
+            height,
+            height,
+        ))
+    }
+
+    fn parse_psf2(data: &'a [u8]) -> Result<Self, PsfError> {
+        if data.len() < PSF2_HEADER_SIZE {
+            return Err(PsfError::Truncated);
+        }
+
+        let field = |index: usize| {
+            let offset = 4 + index * 4;
+            u32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ])
+        };
+
+        let version = field(0);
+        let header_size = field(1) as usize;
+        let flags = field(2);
+        let glyph_count = field(3);
+        let bytes_per_glyph = field(4);
+        let height = field(5);
+        let width = field(6);
+
+        if version != 0 {
+            return Err(PsfError::UnsupportedVersion(version));
+        }
+
+        let bytes_per_row = width.div_ceil(8);
+
+        if header_size < PSF2_HEADER_SIZE
+            || glyph_count == 0
+            || width == 0
+            || height == 0
+            || height
+                .checked_mul(bytes_per_row)
+                .is_none_or(|size| size > bytes_per_glyph)
+        {
+            return Err(PsfError::InvalidHeader);
+        }
+
+        let (glyphs, table) = split_glyphs(data, header_size, glyph_count, bytes_per_glyph)?;
+
+        let unicode_table = if flags & PSF2_HAS_UNICODE_TABLE != 0 {
+            UnicodeTable::Utf8(table)
+        } else {
+            UnicodeTable::None
+        };
+
+        Ok(Self::new(
+            glyphs,
+            unicode_table,
+            glyph_count,
+            width,
+            height,
+            bytes_per_glyph,
+        ))
+    }
+
+    fn new(
+        glyphs: &'a [u8],
+        unicode_table: UnicodeTable<'a>,
+        glyph_count: u32,
+        width: u32,
+        height: u32,
+        bytes_per_glyph: u32,
+    ) -> Self {
+        Self {
+            glyphs,
+            unicode_table,
+            glyph_count,
+            width,
+            height,
+            bytes_per_row: width.div_ceil(8),
+            bytes_per_glyph,
+            // PSF fonts don't store the baseline, a quarter of the height is reserved for
+            // descenders.
+            baseline: height - height / 4 - 1,
+            fallback: 0,
+        }
+    }
+
+    /// Returns the width of the glyphs in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the glyphs in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the number of glyphs in the font.
+    pub fn glyph_count(&self) -> u32 {
+        self.glyph_count
+    }
+
+    /// Returns `true` if the font contains a Unicode table.
+    pub fn has_unicode_table(&self) -> bool {
+        !matches!(self.unicode_table, UnicodeTable::None)
+    }
+
+    /// Sets the row of the baseline, counted from the top of the glyphs.
+    ///
+    /// PSF fonts don't include the position of the baseline. By default the bottom quarter of
+    /// the glyphs is used for descenders.
+    pub fn with_baseline(self, baseline: u32) -> Self {
+        Self { baseline, ..self }
+    }
+
+    /// Render a string in this font.
+    ///
+    /// The returned object only describes the position and content of the text. It needs to be
+    /// styled with [`into_styled`] before it can be drawn.
+    ///
+    /// [`into_styled`]: ../struct.Text.html#method.into_styled
+    pub fn render_str<'b>(&self, text: &'b str) -> Text<'b, Self> {
+        Text::new(text, *self)
+    }
+
+    /// Returns the index of the glyph for a `char`, or `None` if the font doesn't contain it.
+    ///
+    /// Sequences of multiple code points in the Unicode table are ignored.
+    fn lookup(&self, c: char) -> Option<u32> {
+        match self.unicode_table {
+            UnicodeTable::None => Some(c as u32).filter(|&index| index < self.glyph_count),
+            UnicodeTable::Ucs2(table) => {
+                let mut glyph = 0;
+                let mut in_sequence = false;
+
+                for value in table.chunks_exact(2) {
+                    match u16::from_le_bytes([value[0], value[1]]) {
+                        PSF1_SEPARATOR => {
+                            glyph += 1;
+                            in_sequence = false;
+
+                            if glyph >= self.glyph_count {
+                                break;
+                            }
+                        }
+                        PSF1_START_SEQ => in_sequence = true,
+                        value if !in_sequence && u32::from(value) == c as u32 => {
+                            return Some(glyph)
+                        }
+                        _ => {}
+                    }
+                }
+
+                None
+            }
+            UnicodeTable::Utf8(table) => table
+                .split(|&byte| byte == PSF2_SEPARATOR)
+                .take(self.glyph_count as usize)
+                .position(|entry| {
+                    let chars = entry
+                        .split(|&byte| byte == PSF2_START_SEQ)
+                        .next()
+                        .unwrap_or_default();
+
+                    str::from_utf8(chars).is_ok_and(|chars| chars.chars().any(|ch| ch == c))
+                })
+                .map(|glyph| glyph as u32),
+        }
+    }
+}
+
+/// Splits the font data after the header into the glyph bitmaps and the Unicode table.
+fn split_glyphs(
+    data: &[u8],
+    header_size: usize,
+    glyph_count: u32,
+    bytes_per_glyph: u32,
+) -> Result<(&[u8], &[u8]), PsfError> {
+    let glyphs_size = (glyph_count as usize)
+        .checked_mul(bytes_per_glyph as usize)
+        .ok_or(PsfError::InvalidHeader)?;
+
+    let glyphs_end = header_size
+        .checked_add(glyphs_size)
+        .filter(|&end| end <= data.len())
+        .ok_or(PsfError::Truncated)?;
+
+    Ok((&data[header_size..glyphs_end], &data[glyphs_end..]))
+}
+
+impl GlyphSource for PsfFont<'_> {
+    fn char_height(&self) -> u32 {
+        self.height
+    }
+
+    fn baseline(&self) -> u32 {
+        self.baseline
+    }
+
+    fn glyph(&self, c: char) -> u32 {
+        self.lookup(c).unwrap_or(self.fallback)
+    }
+
+    fn glyph_width(&self, _glyph: u32) -> (u32, u32) {
+        (self.width, self.width)
+    }
+
+    fn pixel(&self, glyph: u32, x: u32, y: u32) -> bool {
+        let index = glyph * self.bytes_per_glyph + y * self.bytes_per_row + x / 8;
+
+        self.glyphs[index as usize] & (0x80 >> (x % 8)) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawable::Drawable;
+    use crate::geometry::{Dimensions, Point, Size};
+    use crate::mock_display::MockDisplay;
+    use crate::pixelcolor::BinaryColor;
+    use crate::style::TextStyle;
+    use crate::transform::Transform;
+
+    /// PSF1 font with 256 glyphs of 8x2 pixels and no Unicode table
+    fn psf1_font() -> [u8; 4 + 256 * 2] {
+        let mut data = [0; 4 + 256 * 2];
+        data[0..4].copy_from_slice(&[0x36, 0x04, 0x00, 2]);
+        data[4 + 'A' as usize * 2..][..2].copy_from_slice(&[0b0100_0000, 0b1010_0000]);
+        data[4 + '?' as usize * 2..][..2].copy_from_slice(&[0b1100_0000, 0b0100_0000]);
+
+        data
+    }
+
+    /// PSF2 font with 3 glyphs of 3x3 pixels and a Unicode table
+    const PSF2_FONT: &[u8] = &[
+        // Header
+        0x72,
+        0xb5,
+        0x4a,
+        0x86, // magic
+        0,
+        0,
+        0,
+        0, // version
+        32,
+        0,
+        0,
+        0, // header size
+        1,
+        0,
+        0,
+        0, // flags
+        3,
+        0,
+        0,
+        0, // glyph count
+        3,
+        0,
+        0,
+        0, // bytes per glyph
+        3,
+        0,
+        0,
+        0, // height
+        3,
+        0,
+        0,
+        0, // width
+        // Glyph 0: replacement character
+        0b1110_0000,
+        0b1010_0000,
+        0b1110_0000,
+        // Glyph 1: "-"
+        0b0000_0000,
+        0b1110_0000,
+        0b0000_0000,
+        // Glyph 2: "é", "e" with a combining acute accent and "ë"
+        0b0100_0000,
+        0b1110_0000,
+        0b1100_0000,
+        // Unicode table
+        0xEF,
+        0xBF,
+        0xBD,
+        0xFF, // U+FFFD
+        b'-',
+        0xE2,
+        0x80,
+        0x90,
+        0xFF, // "-", U+2010 hyphen
+        0xC3,
+        0xA9,
+        0xC3,
+        0xAB,
+        0xFE,
+        b'e',
+        0xCC,
+        0x81,
+        0xFF, // "é", "ë", sequence
+    ];
+
+    #[test]
+    fn psf1_without_unicode_table() {
+        let data = psf1_font();
+        let font = PsfFont::parse(&data).unwrap();
+
+        assert_eq!(font.width(), 8);
+        assert_eq!(font.height(), 2);
+        assert_eq!(font.glyph_count(), 256);
+        assert!(!font.has_unicode_table());
+
+        assert_eq!(font.glyph('A'), 65);
+        // Code points outside of the font use the "?" glyph
+        assert_eq!(font.glyph('€'), '?' as u32);
+    }
+
+    #[test]
+    fn psf1_unicode_table() {
+        let mut data = [0; 4 + 512 * 2 + 14];
+        data[0..4].copy_from_slice(&[0x36, 0x04, 0x03, 2]);
+
+        // Glyph 0 maps "a" and the sequence "bc", glyph 1 maps "b"
+        data[4 + 512 * 2..].copy_from_slice(&[
+            b'a', 0, 0xFE, 0xFF, b'b', 0, b'c', 0, 0xFF, 0xFF, // glyph 0
+            b'b', 0, 0xFF, 0xFF, // glyph 1
+        ]);
+
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.glyph_count(), 512);
+        assert!(font.has_unicode_table());
+
+        assert_eq!(font.glyph('a'), 0);
+        assert_eq!(font.glyph('b'), 1);
+        // Unmapped characters use glyph 0 if the font doesn't contain "?" or U+FFFD
+        assert_eq!(font.glyph('c'), 0);
+    }
+
+    #[test]
+    fn psf2_unicode_table() {
+        let font = PsfFont::parse(PSF2_FONT).unwrap();
+
+        assert_eq!(font.width(), 3);
+        assert_eq!(font.height(), 3);
+        assert_eq!(font.glyph_count(), 3);
+        assert!(font.has_unicode_table());
+
+        assert_eq!(font.glyph('-'), 1);
+        assert_eq!(font.glyph('\u{2010}'), 1);
+        assert_eq!(font.glyph('é'), 2);
+        assert_eq!(font.glyph('ë'), 2);
+        // Sequences aren't matched and unmapped characters use U+FFFD
+        assert_eq!(font.glyph('e'), 0);
+        assert_eq!(font.glyph('A'), 0);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(PsfFont::parse(&[]).unwrap_err(), PsfError::InvalidMagic);
+        assert_eq!(
+            PsfFont::parse(&[0x36, 0x04, 0x00]).unwrap_err(),
+            PsfError::Truncated
+        );
+        assert_eq!(
+            PsfFont::parse(&[0x36, 0x04, 0x00, 8, 0, 0]).unwrap_err(),
+            PsfError::Truncated
+        );
+        assert_eq!(
+            PsfFont::parse(&PSF2_FONT[0..40]).unwrap_err(),
+            PsfError::Truncated
+        );
+
+        let mut data = [0; 32];
+        data.copy_from_slice(&PSF2_FONT[0..32]);
+        data[4] = 1;
+        assert_eq!(
+            PsfFont::parse(&data).unwrap_err(),
+            PsfError::UnsupportedVersion(1)
+        );
+
+        // A glyph size of 2 bytes is too small for 3 rows
+        data.copy_from_slice(&PSF2_FONT[0..32]);
+        data[20] = 2;
+        assert_eq!(PsfFont::parse(&data).unwrap_err(), PsfError::InvalidHeader);
+    }
+
+    #[test]
+    fn draw() {
+        let font = PsfFont::parse(PSF2_FONT).unwrap();
+        let text = font.render_str("é-x").translate(Point::new(1, 1));
+
+        assert_eq!(text.size(), Size::new(9, 3));
+        assert_eq!(text.top_left(), Point::new(1, 1));
+
+        let mut display = MockDisplay::new();
+        text.into_styled(
+            TextStyle::with_text_color(BinaryColor::On).background_color(Some(BinaryColor::Off)),
+        )
+        .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&["          ", " .#....###", " #######.#", " ##....###",])
+        );
+    }
+
+    #[test]
+    fn draw_psf1() {
+        let data = psf1_font();
+        let font = PsfFont::parse(&data).unwrap();
+
+        let mut display = MockDisplay::new();
+        font.render_str("A?")
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[" #      ##      ", "# #      #      ",])
+        );
+    }
+}
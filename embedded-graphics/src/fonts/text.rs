@@ -0,0 +1,1419 @@
+//! Text rendering shared by all fonts.
+//!
+//! [`Text`] lays out a string with the glyphs of any [`GlyphSource`]. The built-in fonts are
+//! glyph sources defined at compile time through [`FontBuilderConf`], while fonts like
+//! [`PsfFont`] are loaded at runtime.
+//!
+//! [`Text`]: ./struct.Text.html
+//! [`GlyphSource`]: ./trait.GlyphSource.html
+//! [`FontBuilderConf`]: ./font_builder/trait.FontBuilderConf.html
+//! [`PsfFont`]: ./psf/struct.PsfFont.html
+
+use crate::drawable::{Drawable, Pixel};
+use crate::geometry::{ContainsPoint, Dimensions, Point, Rect, Size};
+use crate::pixelcolor::{BinaryColor, PixelColor};
+use crate::span::Span;
+use crate::style::{Styled, TextStyle};
+use crate::transform::Transform;
+use crate::DrawTarget;
+
+/// A source of glyphs
+///
+/// All glyphs of a glyph source have the same height. Glyphs are identified by an index, which is
+/// returned by [`glyph`](#tymethod.glyph) for each `char`.
+///
+/// Glyphs are monochrome by default. Anti-aliased fonts also implement
+/// [`coverage`](#method.coverage) to return the coverage of partially covered pixels.
+pub trait GlyphSource: Copy {
+    /// Returns the height of the glyphs in pixels.
+    fn char_height(&self) -> u32;
+
+    /// Returns the row of the baseline, counted from the top of the glyphs.
+    fn baseline(&self) -> u32;
+
+    /// Returns the index of the glyph used to draw a `char`.
+    ///
+    /// Characters which aren't included in the font must be mapped to a fallback glyph.
+    fn glyph(&self, c: char) -> u32;
+
+    /// Returns the bitmap width and the advance width of a glyph.
+    ///
+    /// Columns between the bitmap width and the advance width are filled with the background
+    /// color.
+    fn glyph_width(&self, glyph: u32) -> (u32, u32);
+
+    /// Returns `true` if the pixel at `(x, y)` in a glyph is set.
+    ///
+    /// `x` is always less than the bitmap width of the glyph and `y` less than the char height.
+    fn pixel(&self, glyph: u32, x: u32, y: u32) -> bool;
+
+    /// Returns the coverage of the pixel at `(x, y)` in a glyph.
+    ///
+    /// The coverage ranges from `0` for transparent pixels to `255` for pixels which are
+    /// completely covered by the glyph. The default implementation returns `255` for pixels which
+    /// are set and `0` otherwise.
+    fn coverage(&self, glyph: u32, x: u32, y: u32) -> u8 {
+        if self.pixel(glyph, x, y) {
+            255
+        } else {
+            0
+        }
+    }
+
+    /// Returns the kerning adjustment between two chars in pixels.
+    ///
+    /// The adjustment is added to the advance width of `left` if it is followed by `right`. A
+    /// negative adjustment moves the glyphs closer together. The default implementation doesn't
+    /// use kerning and returns `0`.
+    fn kerning(&self, _left: char, _right: char) -> i32 {
+        0
+    }
+
+    /// Returns the row of the underline, counted from the top of the glyphs.
+    ///
+    /// The default implementation places the underline directly below the baseline, or on the
+    /// bottom row if the baseline is the bottom row of the glyphs.
+    fn underline_position(&self) -> u32 {
+        (self.baseline() + 1).min(self.char_height().saturating_sub(1))
+    }
+
+    /// Returns the row of the strikethrough line, counted from the top of the glyphs.
+    ///
+    /// The default implementation places the line a third of the baseline height above the
+    /// baseline, which is close to the middle of lowercase letters.
+    fn strikethrough_position(&self) -> u32 {
+        self.baseline() - self.baseline() / 3
+    }
+
+    /// Measures a string without drawing it.
+    ///
+    /// The text is split into lines at each `\n` character, without any space between the lines.
+    /// The glyphs are placed using the kerning of the font. This is the same as measuring a
+    /// [`Text`] with the default layout settings, see [`Text::metrics`] for details.
+    ///
+    /// ```rust
+    /// use embedded_graphics::fonts::psf::PsfFont;
+    /// use embedded_graphics::fonts::GlyphSource;
+    /// use embedded_graphics::geometry::{Point, Size};
+    ///
+    /// // A PSF1 font with 256 glyphs of 8x4 pixels
+    /// let mut data = [0u8; 4 + 256 * 4];
+    /// data[0..4].copy_from_slice(&[0x36, 0x04, 0x00, 4]);
+    /// let font = PsfFont::parse(&data).unwrap();
+    ///
+    /// let metrics = font.measure("Hello\nworld!");
+    /// assert_eq!(metrics.bounding_box.size, Size::new(48, 8));
+    /// assert_eq!(metrics.next_position, Point::new(48, 4));
+    /// assert_eq!(metrics.line_count, 2);
+    /// ```
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Text::metrics`]: struct.Text.html#method.metrics
+    fn measure(&self, text: &str) -> TextMetrics {
+        Text::new(text, *self).metrics()
+    }
+
+    /// Measures the longest prefix of a string that fits into the given width.
+    ///
+    /// This is the same as measuring a [`Text`] with the default layout settings, see
+    /// [`Text::prefix_metrics`] for details.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Text::prefix_metrics`]: struct.Text.html#method.prefix_metrics
+    fn measure_prefix<'t>(&self, text: &'t str, max_width: u32) -> (&'t str, TextMetrics) {
+        Text::new(text, *self).prefix_metrics(max_width)
+    }
+}
+
+/// Metrics of a measured string
+///
+/// Returned by [`Text::metrics`], [`GlyphSource::measure`] and [`Font::measure`].
+///
+/// [`Text::metrics`]: struct.Text.html#method.metrics
+/// [`GlyphSource::measure`]: trait.GlyphSource.html#method.measure
+/// [`Font::measure`]: trait.Font.html#tymethod.measure
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextMetrics {
+    /// Bounding box of all drawn pixels, including the background
+    ///
+    /// The bounding box is empty if the string doesn't contain any glyphs.
+    pub bounding_box: Rect,
+
+    /// Top left corner of the next glyph if the string is continued
+    pub next_position: Point,
+
+    /// Number of lines, which is the number of `\n` characters plus one
+    pub line_count: u32,
+}
+
+/// Horizontal alignment of the lines of a text relative to the text position
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HorizontalAlignment {
+    /// Lines start at the text position.
+    Left,
+
+    /// Lines are centered on the text position.
+    Center,
+
+    /// Lines end at the text position.
+    Right,
+}
+
+impl HorizontalAlignment {
+    /// Returns the distance from the start of a line to the text position.
+    fn offset(self, width: u32) -> i32 {
+        match self {
+            HorizontalAlignment::Left => 0,
+            HorizontalAlignment::Center => (width / 2) as i32,
+            HorizontalAlignment::Right => width as i32,
+        }
+    }
+}
+
+/// Vertical alignment of a text relative to the text position
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VerticalAlignment {
+    /// The top of the first line is at the text position.
+    Top,
+
+    /// The text is centered on the text position.
+    Middle,
+
+    /// The baseline of the first line is at the text position.
+    Baseline,
+
+    /// The bottom of the last line is at the text position.
+    Bottom,
+}
+
+/// A string laid out with the glyphs of a font
+///
+/// The built-in fonts are type aliases of `Text`, e.g. [`Font6x8`], and are created with
+/// [`Font::render_str`]. Text using a runtime font is created with [`Text::new`] or the
+/// `render_str` method of the font.
+///
+/// [`Font6x8`]: ./type.Font6x8.html
+/// [`Font::render_str`]: ./trait.Font.html#tymethod.render_str
+/// [`Text::new`]: #method.new
+#[derive(Debug, Copy, Clone)]
+pub struct Text<'a, F> {
+    /// Position of the text
+    ///
+    /// The position is the top left corner of the text, unless a different alignment is set.
+    pub pos: Point,
+
+    /// Text to draw
+    pub(super) text: &'a str,
+
+    /// Font used to draw the text
+    pub(super) font: F,
+
+    /// Number of empty pixel rows between two lines
+    pub(super) line_spacing: u32,
+
+    pub(super) horizontal_alignment: HorizontalAlignment,
+
+    pub(super) vertical_alignment: VerticalAlignment,
+
+    /// Horizontal scale factor of the glyphs
+    pub(super) scale_x: u32,
+
+    /// Vertical scale factor of the glyphs
+    pub(super) scale_y: u32,
+
+    /// Draw glyphs in faux bold
+    ///
+    /// This is copied from [`TextStyle::bold`] when the text is styled, because bold changes the
+    /// layout of the glyphs.
+    ///
+    /// [`TextStyle::bold`]: ../style/struct.TextStyle.html#structfield.bold
+    pub(super) bold: bool,
+
+    /// Additional horizontal space between two glyphs
+    pub(super) letter_spacing: i32,
+}
+
+impl<'a, F> Text<'a, F> {
+    /// Creates a new text at the origin.
+    pub const fn new(text: &'a str, font: F) -> Self {
+        Self {
+            pos: Point::zero(),
+            text,
+            font,
+            line_spacing: 0,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            scale_x: 1,
+            scale_y: 1,
+            bold: false,
+            letter_spacing: 0,
+        }
+    }
+
+    /// Sets the number of empty pixel rows between two lines of text.
+    ///
+    /// The text is split into lines at each `\n` character. The default line spacing is `0`.
+    pub fn line_spacing(self, line_spacing: u32) -> Self {
+        Self {
+            line_spacing,
+            ..self
+        }
+    }
+
+    /// Sets the number of pixels which are added to the advance width of each glyph.
+    ///
+    /// Negative values move the glyphs closer together, but the distance between the start of two
+    /// glyphs never becomes negative. The letter spacing isn't scaled and is added to the
+    /// kerning adjustments of the font. The default letter spacing is `0`.
+    ///
+    /// ```rust
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    /// use embedded_graphics::prelude::*;
+    ///
+    /// let text = Font6x8::render_str("1234");
+    /// assert_eq!(text.size(), Size::new(24, 8));
+    /// assert_eq!(text.letter_spacing(-1).size(), Size::new(21, 8));
+    /// ```
+    pub fn letter_spacing(self, letter_spacing: i32) -> Self {
+        Self {
+            letter_spacing,
+            ..self
+        }
+    }
+
+    /// Sets the horizontal alignment of each line relative to the text position.
+    ///
+    /// The default alignment is `HorizontalAlignment::Left`.
+    pub fn horizontal_alignment(self, horizontal_alignment: HorizontalAlignment) -> Self {
+        Self {
+            horizontal_alignment,
+            ..self
+        }
+    }
+
+    /// Sets the vertical alignment of the text relative to the text position.
+    ///
+    /// The default alignment is `VerticalAlignment::Top`.
+    pub fn vertical_alignment(self, vertical_alignment: VerticalAlignment) -> Self {
+        Self {
+            vertical_alignment,
+            ..self
+        }
+    }
+
+    /// Scales the glyphs by an integer factor in both directions.
+    ///
+    /// Each pixel of a glyph is drawn as a `factor` x `factor` block. The line spacing isn't
+    /// scaled. The default scale factor is `1`, a factor of `0` is treated like `1`.
+    ///
+    /// ```rust
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::style::TextStyle;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+    ///
+    /// let text = Font6x8::render_str("42").scale_factor(3);
+    /// assert_eq!(text.size(), Size::new(36, 24));
+    ///
+    /// text.into_styled(TextStyle::with_text_color(BinaryColor::On))
+    ///     .draw(&mut display);
+    /// ```
+    pub fn scale_factor(self, factor: u32) -> Self {
+        self.scale_factor_xy(factor, factor)
+    }
+
+    /// Scales the glyphs by independent integer factors in the horizontal and vertical direction.
+    ///
+    /// Each pixel of a glyph is drawn as a block which is `x` pixels wide and `y` pixels high. A
+    /// factor of `0` is treated like `1`.
+    pub fn scale_factor_xy(self, x: u32, y: u32) -> Self {
+        Self {
+            scale_x: x.max(1),
+            scale_y: y.max(1),
+            ..self
+        }
+    }
+
+    /// Converts this text into a styled text with the given style.
+    pub fn into_styled<C>(self, style: TextStyle<C>) -> Styled<Self, TextStyle<C>>
+    where
+        C: PixelColor,
+    {
+        Styled::new(self, style)
+    }
+}
+
+impl<'a, F> Dimensions for Text<'a, F>
+where
+    F: GlyphSource,
+{
+    fn top_left(&self) -> Point {
+        let x = -self.horizontal_alignment.offset(self.size().width);
+
+        self.pos + Point::new(x, self.top_offset())
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.top_left() + self.size()
+    }
+
+    /// Get the bounding box of a piece of text
+    ///
+    /// The width is the width of the widest line and includes all columns drawn by the glyphs,
+    /// which are the advance widths of the glyphs in proportional fonts, and includes the letter
+    /// spacing and kerning. The height includes all lines and the line spacing between them. Both
+    /// include the scale factor. It will give `(0, 0)` if the string to render doesn't contain
+    /// any glyphs. The size of styled text also includes the extra column of bold glyphs.
+    fn size(&self) -> Size {
+        let width = self
+            .text
+            .split('\n')
+            .map(|line| self.line_width(line))
+            .max()
+            .unwrap_or(0);
+
+        if width > 0 {
+            Size::new(width, self.height())
+        } else {
+            Size::zero()
+        }
+    }
+}
+
+impl<'a, F> Text<'a, F>
+where
+    F: GlyphSource,
+{
+    /// Measures the text without drawing it.
+    ///
+    /// The measurement uses the layout settings of the text, which are the letter spacing, line
+    /// spacing and scale factor, and the kerning of the font. All positions in the returned
+    /// metrics are relative to the top left corner of the first line and don't include the
+    /// position and alignment of the text. Bold glyphs are wider, so styled text should be measured
+    /// with [`Styled::metrics`] instead.
+    ///
+    /// [`Styled::metrics`]: ../style/struct.Styled.html#method.metrics
+    ///
+    /// ```rust
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    /// use embedded_graphics::prelude::*;
+    ///
+    /// let metrics = Font6x8::render_str("Hello\nworld!")
+    ///     .letter_spacing(1)
+    ///     .line_spacing(2)
+    ///     .metrics();
+    /// assert_eq!(metrics.bounding_box.size, Size::new(42, 18));
+    /// assert_eq!(metrics.next_position, Point::new(42, 10));
+    /// assert_eq!(metrics.line_count, 2);
+    /// ```
+    pub fn metrics(&self) -> TextMetrics {
+        let mut line_count = 0;
+        let mut x = 0;
+
+        for line in self.text.split('\n') {
+            x = self.advance_width(line);
+            line_count += 1;
+        }
+
+        let line_height = self.char_height() + self.line_spacing;
+
+        TextMetrics {
+            bounding_box: Rect::new(Point::zero(), self.size()),
+            next_position: Point::new(x as i32, ((line_count - 1) * line_height) as i32),
+            line_count,
+        }
+    }
+
+    /// Measures the longest prefix of the text that fits into the given width.
+    ///
+    /// The prefix ends at the first character which would extend the text beyond `max_width` or at
+    /// the first `\n` character. Returns the prefix and its metrics, which are measured like
+    /// [`metrics`](#method.metrics).
+    ///
+    /// ```rust
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    ///
+    /// let text = Font6x8::render_str("Hello world!").letter_spacing(2);
+    /// let (prefix, metrics) = text.prefix_metrics(40);
+    /// assert_eq!(prefix, "Hello");
+    /// assert_eq!(metrics.bounding_box.size.width, 40);
+    /// ```
+    pub fn prefix_metrics(&self, max_width: u32) -> (&'a str, TextMetrics) {
+        let mut end = self.text.find('\n').unwrap_or(self.text.len());
+
+        for glyph in self.line_glyphs(&self.text[..end]) {
+            if i64::from(glyph.x) + i64::from(glyph.extent()) > i64::from(max_width) {
+                end = glyph.index;
+                break;
+            }
+        }
+
+        let prefix = Self {
+            text: &self.text[..end],
+            ..*self
+        };
+
+        (prefix.text, prefix.metrics())
+    }
+
+    /// Returns the height of all lines, including the line spacing.
+    fn height(&self) -> u32 {
+        let lines = self.text.split('\n').count() as u32;
+
+        lines * self.char_height() + (lines - 1) * self.line_spacing
+    }
+
+    /// Returns the scaled height of a line.
+    pub(super) fn char_height(&self) -> u32 {
+        self.font.char_height() * self.scale_y
+    }
+
+    /// Returns the horizontal distance from the start of a line to the end of its last glyph.
+    ///
+    /// This is the position where a glyph which continues the line would be placed.
+    pub(super) fn advance_width(&self, line: &str) -> u32 {
+        let mut glyphs = self.line_glyphs(line);
+        while glyphs.next().is_some() {}
+
+        glyphs.x.max(0) as u32
+    }
+
+    /// Returns the width of a single line of text.
+    pub(super) fn line_width(&self, line: &str) -> u32 {
+        self.line_glyphs(line)
+            .map(|glyph| glyph.x + glyph.extent() as i32)
+            .max()
+            .unwrap_or(0)
+            .max(0) as u32
+    }
+
+    /// Returns an iterator over the positions of the glyphs in a single line of text.
+    pub(super) fn line_glyphs<'t>(&self, line: &'t str) -> LineGlyphs<'t, F> {
+        LineGlyphs {
+            font: self.font,
+            remaining: line,
+            index: 0,
+            previous: None,
+            x: 0,
+            scale_x: self.scale_x,
+            bold: self.bold,
+            letter_spacing: self.letter_spacing,
+        }
+    }
+
+    /// Returns the vertical offset of the top of the first line from the text position.
+    fn top_offset(&self) -> i32 {
+        match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Middle => -((self.height() / 2) as i32),
+            VerticalAlignment::Baseline => -((self.font.baseline() * self.scale_y) as i32),
+            VerticalAlignment::Bottom => -(self.height() as i32),
+        }
+    }
+}
+
+/// Position and size of a glyph in a line of text
+///
+/// All values are scaled by the horizontal scale factor.
+#[derive(Debug, Copy, Clone)]
+pub(super) struct LineGlyph {
+    /// Byte offset of the char in the line
+    pub index: usize,
+    /// Character which is drawn by the glyph
+    pub c: char,
+    /// Index of the glyph in the font
+    pub glyph: u32,
+    /// Horizontal offset of the glyph from the start of the line
+    pub x: i32,
+    /// Bitmap width, including the extra column of bold glyphs
+    pub width: u32,
+    /// Advance width, including the letter spacing
+    pub advance: u32,
+}
+
+impl LineGlyph {
+    /// Returns the number of columns which are drawn by the glyph.
+    pub fn extent(&self) -> u32 {
+        self.width.max(self.advance)
+    }
+}
+
+/// Iterator over the glyphs of a single line of text
+///
+/// The iterator places the glyphs using the advance widths, letter spacing and kerning. Bold
+/// glyphs are one pixel wider than their bitmap.
+#[derive(Debug, Copy, Clone)]
+pub(super) struct LineGlyphs<'a, F> {
+    font: F,
+    remaining: &'a str,
+    /// Byte offset of the next char in the line
+    index: usize,
+    previous: Option<char>,
+    /// Horizontal offset of the next glyph before kerning is applied
+    x: i32,
+    scale_x: u32,
+    bold: bool,
+    letter_spacing: i32,
+}
+
+impl<'a, F> Iterator for LineGlyphs<'a, F>
+where
+    F: GlyphSource,
+{
+    type Item = LineGlyph;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.remaining.chars().next()?;
+        let index = self.index;
+
+        self.remaining = &self.remaining[c.len_utf8()..];
+        self.index += c.len_utf8();
+
+        if let Some(previous) = self.previous {
+            self.x += self.font.kerning(previous, c) * self.scale_x as i32;
+        }
+        self.previous = Some(c);
+
+        let glyph = self.font.glyph(c);
+        let (width, advance) = self.font.glyph_width(glyph);
+        let width = (width + u32::from(self.bold)) * self.scale_x;
+        let advance = ((advance * self.scale_x) as i32 + self.letter_spacing).max(0) as u32;
+
+        let x = self.x;
+        self.x += advance as i32;
+
+        Some(LineGlyph {
+            index,
+            c,
+            glyph,
+            x,
+            width,
+            advance,
+        })
+    }
+}
+
+impl<'a, F> ContainsPoint for Text<'a, F>
+where
+    F: GlyphSource,
+{
+    /// Returns `true` if the point is inside the bounding box of the text.
+    fn contains(&self, point: Point) -> bool {
+        self.bounding_box().contains(point)
+    }
+}
+
+impl<'a, C, F> Styled<Text<'a, F>, TextStyle<C>>
+where
+    C: PixelColor,
+    F: GlyphSource,
+{
+    /// Returns the text with the layout settings of the style.
+    fn layout(&self) -> Text<'a, F> {
+        Text {
+            bold: self.style.bold,
+            ..self.primitive
+        }
+    }
+
+    /// Measures the styled text without drawing it.
+    ///
+    /// This is the same as [`Text::metrics`], but includes the extra column of bold glyphs.
+    ///
+    /// [`Text::metrics`]: ../fonts/struct.Text.html#method.metrics
+    pub fn metrics(&self) -> TextMetrics {
+        self.layout().metrics()
+    }
+
+    /// Measures the longest prefix of the styled text that fits into the given width.
+    ///
+    /// This is the same as [`Text::prefix_metrics`], but includes the extra column of bold glyphs.
+    ///
+    /// [`Text::prefix_metrics`]: ../fonts/struct.Text.html#method.prefix_metrics
+    pub fn prefix_metrics(&self, max_width: u32) -> (&'a str, TextMetrics) {
+        self.layout().prefix_metrics(max_width)
+    }
+}
+
+impl<'a, C, F> Dimensions for Styled<Text<'a, F>, TextStyle<C>>
+where
+    C: PixelColor,
+    F: GlyphSource,
+{
+    fn top_left(&self) -> Point {
+        self.layout().top_left()
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.layout().bottom_right()
+    }
+
+    /// Returns the size of the text, including the extra column of bold glyphs.
+    fn size(&self) -> Size {
+        self.layout().size()
+    }
+}
+
+impl<'a, C, F> ContainsPoint for Styled<Text<'a, F>, TextStyle<C>>
+where
+    C: PixelColor,
+    F: GlyphSource,
+{
+    /// Returns `true` if the point is inside the bounding box of the text.
+    fn contains(&self, point: Point) -> bool {
+        self.bounding_box().contains(point)
+    }
+}
+
+impl<'a, C, F> Styled<Text<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    /// Returns an iterator over the horizontal spans of the rendered text.
+    ///
+    /// Each span is a run of pixels with the same color in a single row of a character.
+    pub fn spans(&self) -> TextSpans<'a, C, F> {
+        TextSpans {
+            iter: self.into_iter(),
+        }
+    }
+}
+
+/// Pixel iterator for the `Text` object
+#[derive(Debug, Clone, Copy)]
+pub struct TextIterator<'a, C, F>
+where
+    C: PixelColor,
+{
+    char_walk_x: u32,
+    char_walk_y: u32,
+    /// Horizontal offset of the start of the current line from the text position
+    line_x: i32,
+    /// Vertical offset of the current line from the top of the text
+    line_y: u32,
+    /// Vertical offset of the top of the text from the text position
+    top: i32,
+    /// Horizontal offset of the end of the columns drawn by the previous glyphs in the line
+    ///
+    /// Glyphs can overlap because of negative letter spacing or kerning. The background isn't
+    /// drawn in columns before this offset to keep the pixels of the previous glyphs.
+    drawn_end: i32,
+    current_glyph: Option<LineGlyph>,
+    /// Remaining glyphs of the current line
+    line: LineGlyphs<'a, F>,
+    /// Lines after the current line
+    remaining_lines: Option<&'a str>,
+    /// Layout settings of the text
+    text: Text<'a, F>,
+    style: TextStyle<C>,
+}
+
+impl<'a, C, F> TextIterator<'a, C, F>
+where
+    C: PixelColor,
+    F: GlyphSource,
+{
+    pub(super) fn new(text: &Text<'a, F>, style: TextStyle<C>) -> Self {
+        let text = &Text {
+            bold: style.bold,
+            ..*text
+        };
+
+        let mut iter = Self {
+            char_walk_x: 0,
+            char_walk_y: 0,
+            line_x: 0,
+            line_y: 0,
+            top: text.top_offset(),
+            drawn_end: 0,
+            current_glyph: None,
+            line: text.line_glyphs(""),
+            remaining_lines: None,
+            text: *text,
+            style,
+        };
+        iter.start_line(text.text);
+        iter.current_glyph = iter.next_glyph();
+
+        iter
+    }
+
+    /// Starts drawing the first line of the given text.
+    fn start_line(&mut self, text: &'a str) {
+        let (line, remaining_lines) = match text.find('\n') {
+            Some(index) => (&text[..index], Some(&text[index + 1..])),
+            None => (text, None),
+        };
+
+        self.line_x = match self.text.horizontal_alignment {
+            HorizontalAlignment::Left => 0,
+            alignment => -alignment.offset(self.text.line_width(line)),
+        };
+        self.line = self.text.line_glyphs(line);
+        self.drawn_end = i32::min_value();
+        self.remaining_lines = remaining_lines;
+    }
+
+    /// Returns the next glyph.
+    ///
+    /// Newlines move the position of the next glyph to the start of the next line.
+    fn next_glyph(&mut self) -> Option<LineGlyph> {
+        loop {
+            if let Some(glyph) = self.line.next() {
+                return Some(glyph);
+            }
+
+            let text = self.remaining_lines?;
+            self.line_y += self.text.char_height() + self.text.line_spacing;
+            self.start_line(text);
+        }
+    }
+
+    /// Returns the coverage of the current pixel of a glyph, including bold and text decorations.
+    fn coverage(&self, glyph: &LineGlyph) -> u8 {
+        let font = &self.text.font;
+        let y = self.char_walk_y / self.text.scale_y;
+
+        if self.char_walk_x < glyph.advance
+            && (self.style.underline && y == font.underline_position()
+                || self.style.strikethrough && y == font.strikethrough_position())
+        {
+            return 255;
+        }
+
+        if self.char_walk_x >= glyph.width {
+            return 0;
+        }
+
+        let x = self.char_walk_x / self.text.scale_x;
+        let bitmap_width = glyph.width / self.text.scale_x - u32::from(self.text.bold);
+
+        let coverage = if x < bitmap_width {
+            font.coverage(glyph.glyph, x, y)
+        } else {
+            0
+        };
+
+        // Bold glyphs are drawn a second time, one pixel to the right
+        if self.text.bold && x > 0 {
+            coverage.max(font.coverage(glyph.glyph, x - 1, y))
+        } else {
+            coverage
+        }
+    }
+}
+
+impl<'a, C, F> IntoIterator for Styled<Text<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    type Item = Pixel<C>;
+    type IntoIter = TextIterator<'a, C, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TextIterator::new(&self.primitive, self.style)
+    }
+}
+
+impl<'a, C, F> IntoIterator for &Styled<Text<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    type IntoIter = TextIterator<'a, C, F>;
+    type Item = Pixel<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TextIterator::new(&self.primitive, self.style)
+    }
+}
+
+impl<'a, C, F> TextIterator<'a, C, F>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    /// Returns the position of the current pixel of a glyph.
+    fn point(&self, glyph: &LineGlyph) -> Point {
+        let pos = self.text.pos;
+
+        Point::new(
+            pos.x + self.line_x + glyph.x + self.char_walk_x as i32,
+            pos.y + self.top + (self.line_y + self.char_walk_y) as i32,
+        )
+    }
+
+    /// Returns the color of the current pixel of a glyph or `None` if the pixel is transparent.
+    fn color(&self, glyph: &LineGlyph) -> Option<C> {
+        let column = glyph.x + self.char_walk_x as i32;
+        let coverage = self.coverage(glyph);
+
+        let background = if self.char_walk_x < glyph.advance && column >= self.drawn_end {
+            self.style
+                .background_color_at(self.point(glyph), self.text.pos)
+        } else {
+            None
+        };
+
+        let text_color = self
+            .style
+            .text_color
+            .unwrap_or_else(|| BinaryColor::On.into());
+
+        match (coverage, background) {
+            (0, background) => background,
+            (255, _) => Some(text_color),
+            (alpha, Some(background)) => Some(text_color.blend(background, alpha)),
+            // Without a background there is no color to blend with
+            (alpha, None) if alpha >= 128 => Some(text_color),
+            (_, None) => None,
+        }
+    }
+
+    /// Returns the end of the run of columns which have the same color as the current pixel.
+    ///
+    /// The run ends at the next scaled glyph pixel or at the next column where the background or
+    /// decorations start or end. Only a single column is returned for patterned backgrounds.
+    fn run_end(&self, glyph: &LineGlyph) -> u32 {
+        let walk_x = self.char_walk_x;
+        if self.style.background_pattern.is_some() {
+            return walk_x + 1;
+        }
+
+        let scale_x = self.text.scale_x;
+        let mut end = (walk_x / scale_x + 1) * scale_x;
+
+        for &boundary in [glyph.advance, glyph.width].iter() {
+            if walk_x < boundary {
+                end = end.min(boundary);
+            }
+        }
+
+        let column = glyph.x + walk_x as i32;
+        if column < self.drawn_end {
+            end = end.min((self.drawn_end - glyph.x) as u32);
+        }
+
+        end.min(glyph.extent())
+    }
+
+    /// Moves on to the next row or glyph if the end of the current row of a glyph was reached.
+    fn finish_row(&mut self, glyph: &LineGlyph) {
+        if self.char_walk_x < glyph.extent() {
+            return;
+        }
+
+        self.char_walk_x = 0;
+        self.char_walk_y += 1;
+
+        // Done with this char, move on to the next one
+        if self.char_walk_y >= self.text.char_height() {
+            self.char_walk_y = 0;
+            self.drawn_end = self.drawn_end.max(glyph.x + glyph.extent() as i32);
+            self.current_glyph = self.next_glyph();
+        }
+    }
+
+    /// Returns the next span of pixels with the same color.
+    ///
+    /// Runs of pixels are found by comparing the colors of the scaled glyph pixels, which means
+    /// that scaled text isn't evaluated for every drawn pixel.
+    fn next_span(&mut self) -> Option<Span<C>> {
+        loop {
+            let glyph = self.current_glyph?;
+            let start = self.point(&glyph);
+            let start_walk_x = self.char_walk_x;
+            let color = self.color(&glyph);
+
+            self.char_walk_x = self.run_end(&glyph);
+            while self.char_walk_x < glyph.extent() && self.color(&glyph) == color {
+                self.char_walk_x = self.run_end(&glyph);
+            }
+
+            let x_end = start.x + (self.char_walk_x - start_walk_x) as i32 - 1;
+            self.finish_row(&glyph);
+
+            // Skip to next run if the pixels are transparent
+            if let Some(color) = color {
+                break Some(Span::new(start.y, start.x, x_end, color));
+            }
+        }
+    }
+}
+
+impl<'a, C, F> Iterator for TextIterator<'a, C, F>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let glyph = self.current_glyph?;
+            let point = self.point(&glyph);
+            let color = self.color(&glyph);
+
+            self.char_walk_x += 1;
+            self.finish_row(&glyph);
+
+            // Skip to next point if pixel is transparent
+            if let Some(color) = color {
+                break Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+/// Span iterator for the `Text` object
+///
+/// The spans are returned glyph by glyph. Each span is a run of pixels with the same color in a
+/// single row of a glyph.
+#[derive(Debug, Clone, Copy)]
+pub struct TextSpans<'a, C, F>
+where
+    C: PixelColor,
+{
+    iter: TextIterator<'a, C, F>,
+}
+
+impl<'a, C, F> Iterator for TextSpans<'a, C, F>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_span()
+    }
+}
+
+impl<'a, C, F> Drawable<C> for &Styled<Text<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    /// Draws the text as horizontal spans.
+    ///
+    /// The spans are created from runs of glyph pixels with the same color, without visiting each
+    /// pixel of scaled glyphs. This lets displays with accelerated span drawing fill the blocks of
+    /// scaled text without setting every pixel.
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) {
+        display.draw_hline_spans(self.spans());
+    }
+}
+
+impl<'a, F> Transform for Text<'a, F>
+where
+    F: Copy,
+{
+    /// Translate the image from its current position to a new position by (x, y) pixels, returning
+    /// a new `Font8x16`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::fonts::{ Font, Font8x16 };
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// // 8px x 1px test image
+    /// let text = Font8x16::render_str("Hello world");
+    /// let moved = text.translate(Point::new(25, 30));
+    ///
+    /// assert_eq!(text.pos, Point::new(0, 0));
+    /// assert_eq!(moved.pos, Point::new(25, 30));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            pos: self.pos + by,
+            ..*self
+        }
+    }
+
+    /// Translate the font origin from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::fonts::{ Font, Font8x16 };
+    /// # use embedded_graphics::prelude::*;
+    /// #
+    /// // 8px x 1px test image
+    /// let mut text = Font8x16::render_str("Hello world");
+    /// text.translate_mut(Point::new(25, 30));
+    ///
+    /// assert_eq!(text.pos, Point::new(25, 30));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.pos += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+
+    /// Font with filled 2x2 glyphs, "i" is 1 pixel wide
+    #[derive(Debug, Copy, Clone)]
+    struct BlockFont;
+
+    impl GlyphSource for BlockFont {
+        fn char_height(&self) -> u32 {
+            2
+        }
+
+        fn baseline(&self) -> u32 {
+            1
+        }
+
+        fn glyph(&self, c: char) -> u32 {
+            c as u32
+        }
+
+        fn glyph_width(&self, glyph: u32) -> (u32, u32) {
+            if glyph == 'i' as u32 {
+                (1, 2)
+            } else {
+                (2, 3)
+            }
+        }
+
+        fn pixel(&self, _glyph: u32, _x: u32, _y: u32) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn multi_line_size() {
+        let text = Text::new("ab\ni", BlockFont);
+        assert_eq!(text.size(), Size::new(6, 4));
+        assert_eq!(text.line_spacing(1).size(), Size::new(6, 5));
+
+        // Trailing newlines add an empty line
+        assert_eq!(Text::new("i\n", BlockFont).size(), Size::new(2, 4));
+        assert_eq!(Text::new("\n\n", BlockFont).size(), Size::zero());
+    }
+
+    #[test]
+    fn multi_line_draw() {
+        let mut display = MockDisplay::new();
+        Text::new("ab\ni", BlockFont)
+            .line_spacing(1)
+            .translate(Point::new(1, 0))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " ## ## ", //
+                " ## ## ", //
+                "       ", //
+                " #     ", //
+                " #     ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn empty_lines() {
+        let mut display = MockDisplay::new();
+        Text::new("\ni\n\ni", BlockFont)
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "  ", //
+                "  ", //
+                "#.", //
+                "#.", //
+                "  ", //
+                "  ", //
+                "#.", //
+                "#.", //
+            ])
+        );
+    }
+
+    #[test]
+    fn alignment_bounding_box() {
+        let text = Text::new("ab\ni", BlockFont).translate(Point::new(10, 20));
+
+        let centered = text.horizontal_alignment(HorizontalAlignment::Center);
+        assert_eq!(centered.top_left(), Point::new(7, 20));
+        assert_eq!(centered.bottom_right(), Point::new(13, 24));
+
+        let right = text.horizontal_alignment(HorizontalAlignment::Right);
+        assert_eq!(right.top_left(), Point::new(4, 20));
+
+        let middle = text.vertical_alignment(VerticalAlignment::Middle);
+        assert_eq!(middle.top_left(), Point::new(10, 18));
+
+        let baseline = text.vertical_alignment(VerticalAlignment::Baseline);
+        assert_eq!(baseline.top_left(), Point::new(10, 19));
+
+        let bottom = text
+            .line_spacing(2)
+            .vertical_alignment(VerticalAlignment::Bottom);
+        assert_eq!(bottom.top_left(), Point::new(10, 14));
+        assert_eq!(bottom.bottom_right(), Point::new(16, 20));
+    }
+
+    #[test]
+    fn aligned_draw() {
+        let mut display = MockDisplay::new();
+        Text::new("ab\ni", BlockFont)
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .translate(Point::new(3, 0))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ## ", //
+                "## ## ", //
+                "  #   ", //
+                "  #   ", //
+            ])
+        );
+
+        let mut display = MockDisplay::new();
+        Text::new("ab\ni", BlockFont)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .translate(Point::new(6, 4))
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ## ", //
+                "## ## ", //
+                "    # ", //
+                "    # ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn scaled_size() {
+        let text = Text::new("ab\ni", BlockFont).translate(Point::new(10, 20));
+        assert_eq!(text.scale_factor(2).size(), Size::new(12, 8));
+        assert_eq!(text.scale_factor_xy(3, 1).size(), Size::new(18, 4));
+        assert_eq!(text.scale_factor(0).size(), Size::new(6, 4));
+
+        let baseline = text
+            .scale_factor_xy(1, 3)
+            .vertical_alignment(VerticalAlignment::Baseline);
+        assert_eq!(baseline.top_left(), Point::new(10, 17));
+    }
+
+    #[test]
+    fn scaled_draw() {
+        let mut display = MockDisplay::new();
+        Text::new("i\ni", BlockFont)
+            .scale_factor_xy(2, 3)
+            .line_spacing(1)
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .background_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "##..", //
+                "##..", //
+                "##..", //
+                "##..", //
+                "##..", //
+                "##..", //
+                "    ", //
+                "##..", //
+                "##..", //
+                "##..", //
+                "##..", //
+                "##..", //
+                "##..", //
+            ])
+        );
+
+        let spans = Text::new("ab", BlockFont)
+            .scale_factor(2)
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .spans();
+        // Each glyph is drawn row by row
+        let expected = [
+            (0, 3),
+            (0, 3),
+            (0, 3),
+            (0, 3),
+            (6, 9),
+            (6, 9),
+            (6, 9),
+            (6, 9),
+        ];
+        assert!(spans
+            .map(|span| (span.x_start, span.x_end))
+            .eq(expected.iter().cloned()));
+
+        // Glyph pixels and the background are merged into one span per run and scaled row
+        let style = TextStyle::with_text_color(BinaryColor::On)
+            .background_color(Some(BinaryColor::Off))
+            .underline(true);
+        let text = Text::new("ia", BlockFont).scale_factor_xy(3, 2);
+        let spans = text.into_styled(style).spans();
+        let expected = [
+            Span::new(0, 0, 2, BinaryColor::On),
+            Span::new(0, 3, 5, BinaryColor::Off),
+            Span::new(1, 0, 2, BinaryColor::On),
+            Span::new(1, 3, 5, BinaryColor::Off),
+            Span::new(2, 0, 5, BinaryColor::On),
+            Span::new(3, 0, 5, BinaryColor::On),
+            Span::new(0, 6, 11, BinaryColor::On),
+            Span::new(0, 12, 14, BinaryColor::Off),
+            Span::new(1, 6, 11, BinaryColor::On),
+            Span::new(1, 12, 14, BinaryColor::Off),
+            Span::new(2, 6, 14, BinaryColor::On),
+            Span::new(3, 6, 14, BinaryColor::On),
+        ];
+        assert!(spans.eq(expected.iter().cloned()));
+
+        // The spans draw the same pixels as the pixel iterator
+        let mut display = MockDisplay::new();
+        text.into_styled(style).draw(&mut display);
+        let mut expected = MockDisplay::new();
+        expected.draw_iter(text.into_styled(style));
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn decorations() {
+        use crate::fonts::{Font, Font6x8};
+
+        let mut display = MockDisplay::new();
+        Font6x8::render_str("  ")
+            .into_styled(
+                TextStyle::with_text_color(BinaryColor::On)
+                    .underline(true)
+                    .strikethrough(true),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "            ", //
+                "            ", //
+                "            ", //
+                "            ", //
+                "############", //
+                "            ", //
+                "            ", //
+                "############", //
+            ])
+        );
+    }
+
+    #[test]
+    fn decorations_scaled() {
+        let mut display = MockDisplay::new();
+        Text::new("i", BlockFont)
+            .scale_factor_xy(1, 2)
+            .into_styled(TextStyle::with_text_color(BinaryColor::On).underline(true))
+            .draw(&mut display);
+
+        // The underline is on the bottom row, which covers the whole advance width
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "# ", //
+                "# ", //
+                "##", //
+                "##", //
+            ])
+        );
+    }
+
+    #[test]
+    fn measure() {
+        let metrics = BlockFont.measure("ab\ni");
+        assert_eq!(
+            metrics.bounding_box,
+            Rect::new(Point::zero(), Size::new(6, 4))
+        );
+        assert_eq!(metrics.next_position, Point::new(2, 2));
+        assert_eq!(metrics.line_count, 2);
+
+        let metrics = BlockFont.measure("");
+        assert_eq!(metrics.bounding_box.size, Size::zero());
+        assert_eq!(metrics.next_position, Point::zero());
+        assert_eq!(metrics.line_count, 1);
+    }
+
+    #[test]
+    fn measure_prefix() {
+        let (prefix, metrics) = BlockFont.measure_prefix("aib", 5);
+        assert_eq!(prefix, "ai");
+        assert_eq!(metrics.bounding_box.size, Size::new(5, 2));
+        assert_eq!(metrics.next_position, Point::new(5, 0));
+
+        assert_eq!(BlockFont.measure_prefix("ab\nc", 100).0, "ab");
+        assert_eq!(BlockFont.measure_prefix("ab", 2).0, "");
+        assert_eq!(BlockFont.measure_prefix("abc", u32::max_value()).0, "abc");
+    }
+
+    #[test]
+    fn custom_color() {
+        use crate::pixelcolor::raw::RawU1;
+
+        /// Color type which only implements the traits required by text
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        struct CustomColor(bool);
+
+        impl PixelColor for CustomColor {
+            type Raw = RawU1;
+        }
+
+        impl From<BinaryColor> for CustomColor {
+            fn from(color: BinaryColor) -> Self {
+                Self(color.is_on())
+            }
+        }
+
+        let pixels = Text::new("ai", BlockFont)
+            .into_styled(TextStyle::with_text_color(CustomColor(true)))
+            .into_iter()
+            .filter(|Pixel(_, color)| *color == CustomColor(true))
+            .count();
+
+        assert_eq!(pixels, 6);
+    }
+
+    #[test]
+    fn measure_bold() {
+        let text = Text::new("ab", BlockFont).letter_spacing(-1);
+        let style = TextStyle::with_text_color(BinaryColor::On).bold(true);
+
+        assert_eq!(text.metrics().bounding_box.size, Size::new(4, 2));
+        assert_eq!(
+            text.into_styled(style).metrics().bounding_box.size,
+            Size::new(5, 2)
+        );
+
+        assert_eq!(text.prefix_metrics(4).0, "ab");
+        assert_eq!(text.into_styled(style).prefix_metrics(4).0, "a");
+    }
+}
@@ -0,0 +1,692 @@
+//! Word wrapped text inside a rectangle.
+
+use crate::drawable::{Drawable, Pixel};
+use crate::fonts::{GlyphSource, HorizontalAlignment, Text, TextIterator, VerticalAlignment};
+use crate::geometry::{Dimensions, Point, Rect, Size};
+use crate::pixelcolor::{BinaryColor, PixelColor};
+use crate::span::Spans;
+use crate::style::{Styled, TextStyle};
+use crate::transform::Transform;
+use crate::DrawTarget;
+
+/// String which is appended to truncated text
+const ELLIPSIS: &str = "...";
+
+/// Handling of text which doesn't fit into a text box
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Overflow {
+    /// Lines below the text box are clipped.
+    ///
+    /// A line which is only partially inside the text box is drawn up to the bottom edge of the
+    /// box.
+    Clip,
+
+    /// The last line which completely fits into the text box is truncated and ends with `...`.
+    Ellipsis,
+}
+
+/// Word wrapped text inside a rectangle
+///
+/// The text is wrapped at whitespace to fit the width of the text box. Words which are longer than
+/// a line are broken at the last character that fits. Newline characters always start a new line.
+///
+/// The font, line spacing and alignment are taken from a [`Text`]. The horizontal alignment is
+/// applied relative to the edges of the text box. The vertical alignment places the lines at the
+/// top, middle or bottom of the text box if they fit into the box, `VerticalAlignment::Baseline`
+/// is treated like `VerticalAlignment::Top`. The position of the `Text` is ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::fonts::{Font, Font6x8, HorizontalAlignment, TextBox};
+/// use embedded_graphics::geometry::Rect;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::style::TextStyle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// let text = Font6x8::render_str("The quick brown fox jumps over the lazy dog.")
+///     .horizontal_alignment(HorizontalAlignment::Center)
+///     .line_spacing(1);
+///
+/// let text_box = TextBox::new(text, Rect::new(Point::new(2, 2), Size::new(60, 40)));
+/// assert_eq!(text_box.line_count(), 5);
+///
+/// text_box
+///     .into_styled(TextStyle::with_text_color(BinaryColor::On))
+///     .draw(&mut display);
+/// ```
+///
+/// [`Text`]: struct.Text.html
+#[derive(Debug, Copy, Clone)]
+pub struct TextBox<'a, F> {
+    /// Text, font and layout settings
+    text: Text<'a, F>,
+
+    /// Area of the text box
+    pub bounds: Rect,
+
+    overflow: Overflow,
+}
+
+impl<'a, F> TextBox<'a, F> {
+    /// Creates a new text box.
+    pub const fn new(text: Text<'a, F>, bounds: Rect) -> Self {
+        Self {
+            text,
+            bounds,
+            overflow: Overflow::Clip,
+        }
+    }
+
+    /// Sets the handling of text which doesn't fit into the text box.
+    ///
+    /// The default is `Overflow::Clip`.
+    pub fn overflow(self, overflow: Overflow) -> Self {
+        Self { overflow, ..self }
+    }
+
+    /// Converts this text box into a styled text box with the given style.
+    pub fn into_styled<C>(self, style: TextStyle<C>) -> Styled<Self, TextStyle<C>>
+    where
+        C: PixelColor,
+    {
+        Styled::new(self, style)
+    }
+}
+
+impl<'a, F> TextBox<'a, F>
+where
+    F: GlyphSource,
+{
+    /// Returns the number of lines which are needed to display the whole text.
+    ///
+    /// The result doesn't depend on the height of the text box and includes lines which are
+    /// clipped or removed by the overflow handling. Bold glyphs are wider and can wrap the text
+    /// into more lines, so styled text boxes should be measured with [`Styled::line_count`]
+    /// instead.
+    ///
+    /// [`Styled::line_count`]: ../style/struct.Styled.html#method.line_count
+    pub fn line_count(&self) -> u32 {
+        self.lines().count() as u32
+    }
+
+    /// Returns the height which is needed to display the whole text.
+    pub fn required_height(&self) -> u32 {
+        let lines = self.line_count();
+
+        if lines > 0 {
+            lines * self.text.char_height() + (lines - 1) * self.text.line_spacing
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of lines which completely fit into the text box.
+    fn max_lines(&self) -> u32 {
+        let line_height = self.text.char_height() + self.text.line_spacing;
+
+        (self.bounds.size.height + self.text.line_spacing)
+            .checked_div(line_height)
+            .unwrap_or(u32::max_value())
+    }
+
+    /// Returns the vertical offset of the first line from the top of the text box.
+    fn top_offset(&self) -> u32 {
+        let height = self.required_height();
+        let free_space = match self.bounds.size.height.checked_sub(height) {
+            Some(free_space) => free_space,
+            None => return 0,
+        };
+
+        match self.text.vertical_alignment {
+            VerticalAlignment::Top | VerticalAlignment::Baseline => 0,
+            VerticalAlignment::Middle => free_space / 2,
+            VerticalAlignment::Bottom => free_space,
+        }
+    }
+
+    fn lines(&self) -> Lines<'a, F> {
+        Lines {
+            text: self.text,
+            width: self.bounds.size.width,
+            remaining: if self.text.text.is_empty() {
+                None
+            } else {
+                Some(self.text.text)
+            },
+        }
+    }
+}
+
+impl<'a, C, F> Styled<TextBox<'a, F>, TextStyle<C>>
+where
+    C: PixelColor,
+    F: GlyphSource,
+{
+    /// Returns the number of lines which are needed to display the whole styled text.
+    ///
+    /// This is the same as [`TextBox::line_count`], but includes the extra column of bold glyphs.
+    ///
+    /// [`TextBox::line_count`]: ../fonts/struct.TextBox.html#method.line_count
+    pub fn line_count(&self) -> u32 {
+        self.layout().line_count()
+    }
+
+    /// Returns the height which is needed to display the whole styled text.
+    pub fn required_height(&self) -> u32 {
+        self.layout().required_height()
+    }
+
+    /// Returns the text box with the layout settings of the style.
+    fn layout(&self) -> TextBox<'a, F> {
+        TextBox {
+            text: Text {
+                bold: self.style.bold,
+                ..self.primitive.text
+            },
+            ..self.primitive
+        }
+    }
+}
+
+impl<'a, F> Dimensions for TextBox<'a, F> {
+    fn top_left(&self) -> Point {
+        self.bounds.top_left
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.bounds.top_left + self.bounds.size
+    }
+
+    fn size(&self) -> Size {
+        self.bounds.size
+    }
+}
+
+impl<'a, C, F> Dimensions for Styled<TextBox<'a, F>, TextStyle<C>>
+where
+    C: PixelColor,
+{
+    fn top_left(&self) -> Point {
+        self.primitive.top_left()
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.primitive.bottom_right()
+    }
+
+    fn size(&self) -> Size {
+        self.primitive.size()
+    }
+}
+
+impl<'a, F> Transform for TextBox<'a, F>
+where
+    F: Copy,
+{
+    /// Translate the text box from its current position to a new position by (x, y) pixels,
+    /// returning a new `TextBox`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            bounds: self.bounds.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the text box from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.bounds.translate_mut(by);
+
+        self
+    }
+}
+
+/// Iterator over the wrapped lines of a text
+///
+/// Trailing whitespace is removed from the lines.
+#[derive(Debug, Copy, Clone)]
+struct Lines<'a, F> {
+    /// Font and layout settings
+    text: Text<'a, F>,
+    width: u32,
+    remaining: Option<&'a str>,
+}
+
+impl<'a, F> Iterator for Lines<'a, F>
+where
+    F: GlyphSource,
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.remaining?;
+
+        // Start of the whitespace before the current word
+        let mut space_start = None;
+        // End of the current line and start of the next line if the line is wrapped at whitespace
+        let mut word_break = None;
+
+        for glyph in self.text.line_glyphs(text) {
+            let index = glyph.index;
+
+            if glyph.c == '\n' {
+                self.remaining = Some(&text[index + 1..]);
+                return Some(text[..index].trim_end());
+            }
+
+            if glyph.c.is_whitespace() {
+                // Whitespace at the end of a line doesn't need to fit into the line
+                if space_start.is_none() {
+                    space_start = Some(index);
+                }
+            } else {
+                if let Some(start) = space_start.take() {
+                    if !text[..start].trim_start().is_empty() {
+                        word_break = Some((start, index));
+                    }
+                }
+
+                if glyph.x + glyph.extent() as i32 > self.width as i32 && index > 0 {
+                    let (end, next) = word_break.unwrap_or((index, index));
+
+                    self.remaining = Some(&text[next..]);
+                    return Some(text[..end].trim_end());
+                }
+            }
+        }
+
+        self.remaining = None;
+        Some(text.trim_end())
+    }
+}
+
+/// Pixel iterator for the `TextBox` object
+#[derive(Debug, Copy, Clone)]
+pub struct TextBoxIterator<'a, C, F>
+where
+    C: PixelColor,
+{
+    lines: Lines<'a, F>,
+    /// Current line
+    line: Option<TextIterator<'a, C, F>>,
+    /// Ellipsis after the current line
+    ellipsis: Option<TextIterator<'a, C, F>>,
+    text: Text<'a, F>,
+    bounds: Rect,
+    style: TextStyle<C>,
+    /// Vertical offset of the next line from the top of the text box
+    line_y: u32,
+    /// Number of lines which can be drawn before the iterator ends
+    remaining_lines: u32,
+    overflow: Overflow,
+}
+
+impl<'a, C, F> TextBoxIterator<'a, C, F>
+where
+    C: PixelColor,
+    F: GlyphSource,
+{
+    /// Creates an iterator for a text box with the layout settings of the style.
+    fn new(text_box: &TextBox<'a, F>, style: TextStyle<C>) -> Self {
+        let remaining_lines = match text_box.overflow {
+            Overflow::Clip => u32::max_value(),
+            Overflow::Ellipsis => text_box.max_lines(),
+        };
+
+        Self {
+            lines: text_box.lines(),
+            line: None,
+            ellipsis: None,
+            text: text_box.text,
+            bounds: text_box.bounds,
+            style,
+            line_y: text_box.top_offset(),
+            remaining_lines,
+            overflow: text_box.overflow,
+        }
+    }
+
+    /// Starts drawing the next line, returns `false` if there are no more visible lines.
+    fn next_line(&mut self) -> bool {
+        if self.remaining_lines == 0 || self.line_y >= self.bounds.size.height {
+            return false;
+        }
+
+        let mut line = match self.lines.next() {
+            Some(line) => line,
+            None => return false,
+        };
+
+        self.remaining_lines -= 1;
+
+        let text = self.text;
+        let truncate = self.overflow == Overflow::Ellipsis
+            && self.remaining_lines == 0
+            && self.lines.remaining.is_some();
+
+        let mut width = text.line_width(line);
+        if truncate {
+            let ellipsis_width = text.line_width(ELLIPSIS);
+
+            // Remove characters until the line and the ellipsis fit into the text box
+            while !line.is_empty()
+                && text.advance_width(line) + ellipsis_width > self.bounds.size.width
+            {
+                let last = line.chars().next_back().map_or(0, char::len_utf8);
+                line = line[..line.len() - last].trim_end();
+            }
+
+            width = text.advance_width(line) + ellipsis_width;
+        }
+
+        let free_space = self.bounds.size.width.saturating_sub(width);
+        let x = match self.text.horizontal_alignment {
+            HorizontalAlignment::Left => 0,
+            HorizontalAlignment::Center => free_space / 2,
+            HorizontalAlignment::Right => free_space,
+        };
+
+        let position = self.bounds.top_left + Point::new(x as i32, self.line_y as i32);
+        let line_text = Text {
+            pos: position,
+            text: line,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            ..self.text
+        };
+
+        self.line = Some(TextIterator::new(&line_text, self.style));
+        self.ellipsis = if truncate {
+            let ellipsis_text = Text {
+                pos: position + Point::new(text.advance_width(line) as i32, 0),
+                text: ELLIPSIS,
+                ..line_text
+            };
+
+            Some(TextIterator::new(&ellipsis_text, self.style))
+        } else {
+            None
+        };
+
+        self.line_y += text.char_height() + text.line_spacing;
+
+        true
+    }
+}
+
+impl<'a, C, F> Iterator for TextBoxIterator<'a, C, F>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pixel = match self.line.as_mut().and_then(Iterator::next) {
+                Some(pixel) => Some(pixel),
+                None => self.ellipsis.as_mut().and_then(Iterator::next),
+            };
+
+            match pixel {
+                Some(pixel) => {
+                    if self.bounds.contains(pixel.0) {
+                        break Some(pixel);
+                    }
+                }
+                None => {
+                    if !self.next_line() {
+                        break None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a, C, F> IntoIterator for Styled<TextBox<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    type Item = Pixel<C>;
+    type IntoIter = TextBoxIterator<'a, C, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TextBoxIterator::new(&self.layout(), self.style)
+    }
+}
+
+impl<'a, C, F> IntoIterator for &Styled<TextBox<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    type Item = Pixel<C>;
+    type IntoIter = TextBoxIterator<'a, C, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TextBoxIterator::new(&self.layout(), self.style)
+    }
+}
+
+impl<'a, C, F> Styled<TextBox<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    /// Returns an iterator over the horizontal spans of the rendered text box.
+    pub fn spans(&self) -> Spans<TextBoxIterator<'a, C, F>, C> {
+        Spans::new(self)
+    }
+}
+
+impl<'a, C, F> Drawable<C> for &Styled<TextBox<'a, F>, TextStyle<C>>
+where
+    C: PixelColor + From<BinaryColor>,
+    F: GlyphSource,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) {
+        display.draw_hline_spans(self.spans());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::MockDisplay;
+
+    /// Monospace font with filled 2x2 glyphs and an advance width of 3 pixels
+    #[derive(Debug, Copy, Clone)]
+    struct BlockFont;
+
+    impl GlyphSource for BlockFont {
+        fn char_height(&self) -> u32 {
+            2
+        }
+
+        fn baseline(&self) -> u32 {
+            1
+        }
+
+        fn glyph(&self, c: char) -> u32 {
+            c as u32
+        }
+
+        fn glyph_width(&self, _glyph: u32) -> (u32, u32) {
+            (2, 3)
+        }
+
+        fn pixel(&self, glyph: u32, _x: u32, _y: u32) -> bool {
+            glyph != ' ' as u32
+        }
+    }
+
+    fn text_box(text: &str, width: u32, height: u32) -> TextBox<'_, BlockFont> {
+        TextBox::new(
+            Text::new(text, BlockFont),
+            Rect::new(Point::zero(), Size::new(width, height)),
+        )
+    }
+
+    fn lines(text: &str, width: u32) -> Lines<'_, BlockFont> {
+        text_box(text, width, 0).lines()
+    }
+
+    #[test]
+    fn word_wrap() {
+        assert!(lines("aa bb cc", 9).eq(["aa", "bb", "cc"].iter().cloned()));
+        assert!(lines("aa   bb", 9).eq(["aa", "bb"].iter().cloned()));
+        assert!(lines("a b c d", 9).eq(["a b", "c d"].iter().cloned()));
+    }
+
+    #[test]
+    fn hard_break() {
+        assert!(lines("abcdefg", 9).eq(["abc", "def", "g"].iter().cloned()));
+        assert!(lines("a bcdefg", 9).eq(["a", "bcd", "efg"].iter().cloned()));
+
+        // At least one character is placed on each line
+        assert!(lines("ab", 1).eq(["a", "b"].iter().cloned()));
+    }
+
+    #[test]
+    fn newlines() {
+        assert!(lines("a\n\nb c\n", 9).eq(["a", "", "b c", ""].iter().cloned()));
+    }
+
+    #[test]
+    fn line_count() {
+        assert_eq!(text_box("aa bb cc", 9, 0).line_count(), 3);
+        assert_eq!(text_box("aa bb cc", 9, 0).required_height(), 6);
+        assert_eq!(text_box("aa bb cc", 18, 0).line_count(), 2);
+        assert_eq!(text_box("", 9, 0).line_count(), 0);
+        assert_eq!(text_box("", 9, 0).required_height(), 0);
+
+        let spaced = TextBox::new(
+            Text::new("aa bb cc", BlockFont).line_spacing(1),
+            Rect::new(Point::zero(), Size::new(9, 0)),
+        );
+        assert_eq!(spaced.required_height(), 8);
+
+        let scaled = TextBox::new(
+            Text::new("aa bb cc", BlockFont).scale_factor(2),
+            Rect::new(Point::zero(), Size::new(18, 0)),
+        );
+        assert_eq!(scaled.line_count(), 3);
+        assert_eq!(scaled.required_height(), 12);
+    }
+
+    #[test]
+    fn line_count_bold() {
+        use crate::fonts::{Font, Font6x8};
+
+        let text_box = TextBox::new(
+            Font6x8::render_str("aaaaa"),
+            Rect::new(Point::zero(), Size::new(30, 16)),
+        );
+        let styled = text_box.into_styled(TextStyle::with_text_color(BinaryColor::On).bold(true));
+
+        assert_eq!(text_box.line_count(), 1);
+
+        // The last bold glyph is one column wider than the text box
+        assert_eq!(styled.line_count(), 2);
+        assert_eq!(styled.required_height(), 16);
+
+        // The wrapped glyph is drawn on the second line
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display);
+        assert!((8..16).any(|y| (0..30).any(|x| display.get_pixel(Point::new(x, y)).is_some())));
+    }
+
+    #[test]
+    fn draw_aligned() {
+        let mut display = MockDisplay::new();
+        TextBox::new(
+            Text::new("ab cde", BlockFont).horizontal_alignment(HorizontalAlignment::Center),
+            Rect::new(Point::new(1, 0), Size::new(9, 4)),
+        )
+        .into_styled(TextStyle::with_text_color(BinaryColor::On))
+        .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "  ## ##   ", //
+                "  ## ##   ", //
+                " ## ## ## ", //
+                " ## ## ## ", //
+            ])
+        );
+
+        let mut display = MockDisplay::new();
+        TextBox::new(
+            Text::new("ab", BlockFont)
+                .horizontal_alignment(HorizontalAlignment::Right)
+                .vertical_alignment(VerticalAlignment::Middle),
+            Rect::new(Point::zero(), Size::new(9, 6)),
+        )
+        .into_styled(TextStyle::with_text_color(BinaryColor::On))
+        .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "         ", //
+                "         ", //
+                "   ## ## ", //
+                "   ## ## ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn clip() {
+        let mut display = MockDisplay::new();
+        text_box("ab cd ef", 15, 3)
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ##    ## ##", //
+                "## ##    ## ##", //
+                "## ##         ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn ellipsis() {
+        let mut display = MockDisplay::new();
+        text_box("ab cd ef", 15, 3)
+            .overflow(Overflow::Ellipsis)
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ## ## ## ##", //
+                "## ## ## ## ##", //
+            ])
+        );
+
+        // Text which fits isn't truncated
+        let mut display = MockDisplay::new();
+        text_box("ab cd", 15, 3)
+            .overflow(Overflow::Ellipsis)
+            .into_styled(TextStyle::with_text_color(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "## ##    ## ##", //
+                "## ##    ## ##", //
+            ])
+        );
+    }
+}
@@ -0,0 +1,459 @@
+use crate::geometry::{Angle, Point};
+use crate::transform::Axis;
+use core::ops::Mul;
+
+/// Number of fractional bits of the fixed point matrix coefficients.
+const FRACTION_BITS: u32 = 16;
+
+/// Fixed point value of `1.0`.
+const ONE: i32 = 1 << FRACTION_BITS;
+
+/// Divides `value` by `2^FRACTION_BITS`, rounding to the nearest integer with ties away from zero.
+fn round_fixed(value: i128) -> i128 {
+    let half = 1 << (FRACTION_BITS - 1);
+
+    if value >= 0 {
+        (value + half) >> FRACTION_BITS
+    } else {
+        -((-value + half) >> FRACTION_BITS)
+    }
+}
+
+/// Converts `value` to an `i64`, saturating at the bounds of the `i64` range.
+fn saturate_i64(value: i128) -> i64 {
+    value
+        .max(i128::from(i64::min_value()))
+        .min(i128::from(i64::max_value())) as i64
+}
+
+/// Converts `value` to an `i32`, saturating at the bounds of the `i32` range.
+fn saturate_i32(value: i128) -> i32 {
+    value
+        .max(i128::from(i32::min_value()))
+        .min(i128::from(i32::max_value())) as i32
+}
+
+/// 2D affine transform.
+///
+/// An affine transform maps each point with a 2x3 matrix, which can combine any number of
+/// translations, rotations, scalings, skews and mirrorings into a single transform. The matrix
+/// coefficients are stored as 16.16 fixed point numbers, so no floating point math is required.
+/// The translation is stored with 48 integer bits, which covers the whole coordinate range of
+/// `Point`. Transformed points which don't fit into a `Point` are saturated.
+///
+/// Transforms are combined with [`then`], which applies the transforms in the order in which they
+/// are written. Rotations and skews are applied around the origin, so to rotate around another
+/// point translate it to the origin first.
+///
+/// An `Affine` transform can be applied to single points with [`apply`], to pixel iterators with
+/// [`TransformedPixels`] or to everything drawn on a display with [`TransformedDisplay`].
+///
+/// [Nalgebra] support can be enabled with the `nalgebra_support` feature. This implements
+/// `From<Matrix3<f32>>` and `Into<Matrix3<f32>>` for `Affine`, where the last row of the
+/// `nalgebra` matrix is ignored.
+///
+/// # Examples
+///
+/// ## Rotate around a point
+///
+/// ```rust
+/// use embedded_graphics::geometry::{Affine, Angle, Point};
+///
+/// let center = Point::new(10, 10);
+///
+/// let transform = Affine::translate(Point::zero() - center)
+///     .then(&Affine::rotate(Angle::from_degrees(90)))
+///     .then(&Affine::translate(center));
+///
+/// assert_eq!(transform.apply(Point::new(15, 10)), Point::new(10, 15));
+/// ```
+///
+/// ## Convert to and from an `nalgebra` matrix
+///
+/// ```rust
+/// # #[cfg(feature = "nalgebra_support")] {
+/// use embedded_graphics::geometry::{Affine, Point};
+/// use nalgebra::Matrix3;
+///
+/// let matrix = Matrix3::new(2.0, 0.0, 5.0, 0.0, 0.5, 0.0, 0.0, 0.0, 1.0);
+/// let transform = Affine::from(matrix);
+///
+/// assert_eq!(transform.apply(Point::new(10, 10)), Point::new(25, 5));
+/// assert_eq!(Matrix3::from(transform), matrix);
+/// # }
+/// ```
+///
+/// [`then`]: #method.then
+/// [`apply`]: #method.apply
+/// [`TransformedPixels`]: ../transform/struct.TransformedPixels.html
+/// [`TransformedDisplay`]: ../transform/struct.TransformedDisplay.html
+/// [Nalgebra]: https://docs.rs/nalgebra
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Affine {
+    // The matrix is
+    //
+    //  | a  b  tx |
+    //  | c  d  ty |
+    a: i32,
+    b: i32,
+    c: i32,
+    d: i32,
+    tx: i64,
+    ty: i64,
+}
+
+impl Affine {
+    /// Creates the identity transform, which doesn't change points.
+    pub const fn identity() -> Self {
+        Self {
+            a: ONE,
+            b: 0,
+            c: 0,
+            d: ONE,
+            tx: 0,
+            ty: 0,
+        }
+    }
+
+    /// Creates a transform which moves points by `by`.
+    pub const fn translate(by: Point) -> Self {
+        Self {
+            tx: (by.x as i64) << FRACTION_BITS,
+            ty: (by.y as i64) << FRACTION_BITS,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a transform which rotates points around the origin.
+    ///
+    /// Positive angles rotate clockwise on screen, like the [`Rotate`] trait.
+    ///
+    /// [`Rotate`]: ../transform/trait.Rotate.html
+    pub fn rotate(angle: Angle) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        Self {
+            a: cos,
+            b: -sin,
+            c: sin,
+            d: cos,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a transform which scales points relative to the origin by
+    /// `numerator / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn scale(numerator: u32, denominator: u32) -> Self {
+        assert!(denominator != 0, "scale denominator must not be zero");
+
+        let factor = (u64::from(numerator) << FRACTION_BITS) / u64::from(denominator);
+        let factor = factor as i32;
+
+        Self {
+            a: factor,
+            d: factor,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a transform which skews points along the X and Y axes.
+    ///
+    /// `x` is the angle by which vertical lines are tilted and `y` the angle by which horizontal
+    /// lines are tilted. Positive angles tilt clockwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an angle is an odd multiple of 90 degrees, because the skew would be infinite.
+    pub fn skew(x: Angle, y: Angle) -> Self {
+        Self {
+            b: -x.tan(),
+            c: y.tan(),
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a transform which mirrors points across `axis`, like the [`Mirror`] trait.
+    ///
+    /// [`Mirror`]: ../transform/trait.Mirror.html
+    pub fn mirror(axis: Axis) -> Self {
+        match axis {
+            Axis::Vertical(x) => Self {
+                a: -ONE,
+                tx: (2 * i64::from(x)) << FRACTION_BITS,
+                ..Self::identity()
+            },
+            Axis::Horizontal(y) => Self {
+                d: -ONE,
+                ty: (2 * i64::from(y)) << FRACTION_BITS,
+                ..Self::identity()
+            },
+        }
+    }
+
+    /// Returns a transform which applies `self` first and then `other`.
+    pub fn then(&self, other: &Affine) -> Affine {
+        let mul = |x: i32, y: i64, z: i32, w: i64| {
+            round_fixed(i128::from(x) * i128::from(y) + i128::from(z) * i128::from(w))
+        };
+
+        Affine {
+            a: mul(other.a, self.a.into(), other.b, self.c.into()) as i32,
+            b: mul(other.a, self.b.into(), other.b, self.d.into()) as i32,
+            c: mul(other.c, self.a.into(), other.d, self.c.into()) as i32,
+            d: mul(other.c, self.b.into(), other.d, self.d.into()) as i32,
+            tx: saturate_i64(mul(other.a, self.tx, other.b, self.ty) + i128::from(other.tx)),
+            ty: saturate_i64(mul(other.c, self.tx, other.d, self.ty) + i128::from(other.ty)),
+        }
+    }
+
+    /// Returns the inverse transform, which maps transformed points back to where they came from.
+    ///
+    /// `None` is returned if the transform can't be inverted, which is the case if it maps all
+    /// points onto a single line or point.
+    pub fn inverse(&self) -> Option<Affine> {
+        // Fixed point with 32 fractional bits
+        let det = i128::from(self.a) * i128::from(self.d) - i128::from(self.b) * i128::from(self.c);
+        if det == 0 {
+            return None;
+        }
+
+        let div = |value: i32| ((i128::from(value) << (2 * FRACTION_BITS)) / det) as i32;
+
+        let inverse = Affine {
+            a: div(self.d),
+            b: div(-self.b),
+            c: div(-self.c),
+            d: div(self.a),
+            tx: 0,
+            ty: 0,
+        };
+
+        // The translation is removed first and then the linear part is undone
+        let origin = Affine {
+            tx: -self.tx,
+            ty: -self.ty,
+            ..Affine::identity()
+        };
+
+        Some(origin.then(&inverse))
+    }
+
+    /// Applies the transform to a point.
+    ///
+    /// The result is rounded to the nearest pixel and saturated to the coordinate range of `Point`.
+    pub fn apply(&self, point: Point) -> Point {
+        let x = i128::from(point.x);
+        let y = i128::from(point.y);
+
+        Point::new(
+            saturate_i32(round_fixed(
+                i128::from(self.a) * x + i128::from(self.b) * y + i128::from(self.tx),
+            )),
+            saturate_i32(round_fixed(
+                i128::from(self.c) * x + i128::from(self.d) * y + i128::from(self.ty),
+            )),
+        )
+    }
+}
+
+impl Default for Affine {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul for Affine {
+    type Output = Affine;
+
+    /// Multiplies two transform matrices.
+    ///
+    /// Like matrix multiplication, `a * b` applies `b` first and then `a`. This is the same as
+    /// `b.then(&a)`.
+    fn mul(self, other: Affine) -> Affine {
+        other.then(&self)
+    }
+}
+
+#[cfg(feature = "nalgebra_support")]
+use nalgebra::Matrix3;
+
+#[cfg(feature = "nalgebra_support")]
+fn to_fixed(value: f32) -> i64 {
+    let value = value * ONE as f32;
+
+    if value >= 0.0 {
+        (value + 0.5) as i64
+    } else {
+        (value - 0.5) as i64
+    }
+}
+
+#[cfg(feature = "nalgebra_support")]
+impl From<Matrix3<f32>> for Affine {
+    fn from(other: Matrix3<f32>) -> Self {
+        Self::from(&other)
+    }
+}
+
+#[cfg(feature = "nalgebra_support")]
+impl From<&Matrix3<f32>> for Affine {
+    fn from(other: &Matrix3<f32>) -> Self {
+        Self {
+            a: saturate_i32(to_fixed(other[(0, 0)]).into()),
+            b: saturate_i32(to_fixed(other[(0, 1)]).into()),
+            c: saturate_i32(to_fixed(other[(1, 0)]).into()),
+            d: saturate_i32(to_fixed(other[(1, 1)]).into()),
+            tx: to_fixed(other[(0, 2)]),
+            ty: to_fixed(other[(1, 2)]),
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra_support")]
+impl From<Affine> for Matrix3<f32> {
+    fn from(other: Affine) -> Self {
+        let f = |value: i64| value as f32 / ONE as f32;
+
+        Matrix3::new(
+            f(other.a.into()),
+            f(other.b.into()),
+            f(other.tx),
+            f(other.c.into()),
+            f(other.d.into()),
+            f(other.ty),
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        let point = Point::new(3, 4);
+
+        assert_eq!(Affine::identity().apply(point), point);
+        assert_eq!(
+            Affine::translate(Point::new(-1, 2)).apply(point),
+            Point::new(2, 6)
+        );
+        assert_eq!(
+            Affine::rotate(Angle::from_degrees(90)).apply(point),
+            Point::new(-4, 3)
+        );
+        assert_eq!(Affine::scale(3, 2).apply(point), Point::new(5, 6));
+        assert_eq!(
+            Affine::skew(Angle::from_degrees(45), Angle::zero()).apply(point),
+            Point::new(-1, 4)
+        );
+        assert_eq!(
+            Affine::mirror(Axis::Vertical(5)).apply(point),
+            Point::new(7, 4)
+        );
+        assert_eq!(
+            Affine::mirror(Axis::Horizontal(5)).apply(point),
+            Point::new(3, 6)
+        );
+    }
+
+    #[test]
+    fn matches_point_transforms() {
+        use crate::transform::Rotate;
+
+        let center = Point::new(20, 30);
+        let transform = Affine::translate(Point::zero() - center)
+            .then(&Affine::rotate(Angle::from_degrees(30)))
+            .then(&Affine::translate(center));
+
+        for &point in &[Point::new(0, 0), Point::new(25, 31), Point::new(-40, 100)] {
+            assert_eq!(
+                transform.apply(point),
+                point.rotate(center, Angle::from_degrees(30))
+            );
+        }
+    }
+
+    #[test]
+    fn composition_order() {
+        let translate = Affine::translate(Point::new(10, 0));
+        let scale = Affine::scale(2, 1);
+        let point = Point::new(1, 1);
+
+        assert_eq!(translate.then(&scale).apply(point), Point::new(22, 2));
+        assert_eq!(scale.then(&translate).apply(point), Point::new(12, 2));
+        assert_eq!(scale * translate, translate.then(&scale));
+    }
+
+    #[test]
+    fn inverse() {
+        let transform = Affine::rotate(Angle::from_degrees(90))
+            .then(&Affine::scale(2, 1))
+            .then(&Affine::translate(Point::new(5, -7)));
+        let inverse = transform.inverse().unwrap();
+
+        for &point in &[Point::new(0, 0), Point::new(13, -4), Point::new(-100, 50)] {
+            assert_eq!(inverse.apply(transform.apply(point)), point);
+        }
+
+        assert_eq!(Affine::scale(0, 1).inverse(), None);
+    }
+
+    #[test]
+    fn large_translations() {
+        let far = Point::new(100_000, -100_000);
+        let point = Point::new(3, 4);
+
+        assert_eq!(
+            Affine::translate(far).apply(point),
+            Point::new(100_003, -99_996)
+        );
+        assert_eq!(
+            Affine::mirror(Axis::Vertical(100_000)).apply(point),
+            Point::new(199_997, 4)
+        );
+        assert_eq!(
+            Affine::mirror(Axis::Horizontal(-100_000)).apply(point),
+            Point::new(3, -200_004)
+        );
+        assert_eq!(
+            Affine::translate(far)
+                .then(&Affine::translate(far))
+                .apply(point),
+            Point::new(200_003, -199_996)
+        );
+
+        let max = Point::new(i32::max_value(), i32::min_value());
+        assert_eq!(
+            Affine::translate(max)
+                .then(&Affine::translate(max))
+                .apply(point),
+            max
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn skew_90_degrees() {
+        Affine::skew(Angle::from_degrees(90), Angle::zero());
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra_support")]
+    fn nalgebra_support() {
+        let transform =
+            Affine::rotate(Angle::from_degrees(90)).then(&Affine::translate(Point::new(3, 4)));
+        let matrix = Matrix3::new(0.0, -1.0, 3.0, 1.0, 0.0, 4.0, 0.0, 0.0, 1.0);
+
+        assert_eq!(Matrix3::from(transform), matrix);
+        assert_eq!(Affine::from(matrix), transform);
+        assert_eq!(Affine::from(&matrix), transform);
+    }
+}
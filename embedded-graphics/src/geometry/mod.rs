@@ -1,10 +1,46 @@
 //! Geometry module.
 
+mod affine;
+mod angle;
 mod point;
+mod rect;
 mod size;
+mod subpixel_point;
 
+pub use affine::Affine;
+pub use angle::Angle;
 pub use point::Point;
+pub use rect::{Rect, RectPoints};
 pub use size::Size;
+pub use subpixel_point::SubpixelPoint;
+pub(crate) use subpixel_point::{SUBPIXEL_BITS, SUBPIXEL_ONE};
+
+use crate::transform::{Mirror, Rotate, Scale};
+use core::ops::{Add, AddAssign};
+
+/// Point types which can be used as the vertices of primitives.
+///
+/// Lines, circles, triangles and quads can be defined with integer [`Point`]s or with
+/// [`SubpixelPoint`]s. This trait is implemented for both types and provides the operations
+/// these primitives need from their points.
+///
+/// [`Point`]: struct.Point.html
+/// [`SubpixelPoint`]: struct.SubpixelPoint.html
+pub trait Vertex:
+    Copy
+    + PartialEq
+    + Into<SubpixelPoint>
+    + Add<Point, Output = Self>
+    + AddAssign<Point>
+    + Rotate<Output = Self>
+    + Scale<Output = Self>
+    + Mirror<Output = Self>
+{
+}
+
+impl Vertex for Point {}
+
+impl Vertex for SubpixelPoint {}
 
 /// Adds the ability to get the dimensions/position of a graphics object
 ///
@@ -20,4 +56,39 @@ pub trait Dimensions {
 
     /// Get the width and height for an object
     fn size(&self) -> Size;
+
+    /// Get the bounding box of an object
+    ///
+    /// The bounding box is the smallest rectangle which contains all pixels drawn by the object.
+    fn bounding_box(&self) -> Rect {
+        Rect::new(self.top_left(), self.size())
+    }
+}
+
+/// Hit testing for graphics objects.
+///
+/// `contains` returns `true` for exactly the pixels that are covered by the object when it is
+/// drawn with both a stroke and a fill color set. Areas which are transparent because a color
+/// is `None` or a fill pattern bit isn't set still count as part of the object.
+///
+/// Unstyled geometry like a [`Circle`] assumes a stroke width of 1px. A [`Styled`] primitive
+/// uses the stroke width of its style instead.
+///
+/// This can be used to find out which object the user touched in an interactive UI:
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::primitives::Circle;
+///
+/// let button = Circle::new(Point::new(20, 20), 10);
+///
+/// assert!(button.contains(Point::new(25, 25)));
+/// assert!(!button.contains(Point::new(30, 30)));
+/// ```
+///
+/// [`Circle`]: ../primitives/circle/struct.Circle.html
+/// [`Styled`]: ../style/struct.Styled.html
+pub trait ContainsPoint {
+    /// Returns `true` if the point is part of the object.
+    fn contains(&self, point: Point) -> bool;
 }
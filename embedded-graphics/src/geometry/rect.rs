@@ -0,0 +1,416 @@
+use crate::geometry::{ContainsPoint, Point, Size};
+use crate::transform::Transform;
+use core::cmp::{max, min};
+
+/// Axis aligned rectangle.
+///
+/// `Rect` describes an area of the display by its top left corner and its size. Unlike the
+/// [`Rectangle`] primitive it has no style and can't be drawn. It is used for geometric
+/// calculations like clipping, layout and keeping track of changed display areas, and is returned
+/// by [`Dimensions::bounding_box`].
+///
+/// A rectangle with a zero width or height is empty and doesn't contain any points.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::geometry::{Point, Rect, Size};
+///
+/// let a = Rect::new(Point::new(0, 0), Size::new(10, 10));
+/// let b = Rect::new(Point::new(5, 5), Size::new(10, 10));
+///
+/// assert_eq!(a.intersection(&b), Rect::new(Point::new(5, 5), Size::new(5, 5)));
+/// assert_eq!(a.union(&b), Rect::new(Point::new(0, 0), Size::new(15, 15)));
+/// assert!(a.contains(Point::new(9, 9)));
+/// assert!(!a.contains(Point::new(10, 10)));
+/// ```
+///
+/// [`Rectangle`]: ../primitives/rectangle/struct.Rectangle.html
+/// [`Dimensions::bounding_box`]: trait.Dimensions.html#method.bounding_box
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Rect {
+    /// Top left corner.
+    pub top_left: Point,
+
+    /// Size of the rectangle.
+    pub size: Size,
+}
+
+impl Rect {
+    /// Creates a rectangle from its top left corner and its size.
+    pub const fn new(top_left: Point, size: Size) -> Self {
+        Self { top_left, size }
+    }
+
+    /// Creates the rectangle which is used as the area of displays with an unknown size.
+    ///
+    /// The rectangle covers the whole coordinate space.
+    pub(crate) const fn unbounded() -> Self {
+        Self::new(
+            Point::new(i32::min_value(), i32::min_value()),
+            Size::new(u32::max_value(), u32::max_value()),
+        )
+    }
+
+    /// Creates a rectangle from two opposite corners.
+    ///
+    /// Both corners are part of the rectangle and can be given in any order.
+    pub fn with_corners(corner_1: Point, corner_2: Point) -> Self {
+        let top_left = Point::new(min(corner_1.x, corner_2.x), min(corner_1.y, corner_2.y));
+
+        Self {
+            top_left,
+            size: Size::from_bounding_box(corner_1, corner_2) + Size::new(1, 1),
+        }
+    }
+
+    /// Returns the bottom right corner of the rectangle.
+    ///
+    /// The bottom right corner is the last point inside the rectangle. `None` is returned for
+    /// empty rectangles. The corner is saturated to the coordinate range of `Point`.
+    pub fn bottom_right(&self) -> Option<Point> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let clamp_coord = |v: i64| min(v, i64::from(i32::max_value())) as i32;
+
+        Some(Point::new(
+            clamp_coord(self.x_range().1 - 1),
+            clamp_coord(self.y_range().1 - 1),
+        ))
+    }
+
+    /// Returns `true` if the rectangle has a zero width or height.
+    pub fn is_empty(&self) -> bool {
+        self.size.width == 0 || self.size.height == 0
+    }
+
+    /// Returns `true` if the point is inside the rectangle.
+    pub fn contains(&self, point: Point) -> bool {
+        let (x_start, x_end) = self.x_range();
+        let (y_start, y_end) = self.y_range();
+
+        let (x, y) = (i64::from(point.x), i64::from(point.y));
+
+        x >= x_start && x < x_end && y >= y_start && y < y_end
+    }
+
+    /// Returns the center of the rectangle.
+    ///
+    /// The center is rounded towards the top left corner for rectangles with an even width or
+    /// height.
+    pub fn center(&self) -> Point {
+        let offset = Size::new(
+            self.size.width.saturating_sub(1) / 2,
+            self.size.height.saturating_sub(1) / 2,
+        );
+
+        self.top_left + offset
+    }
+
+    /// Returns the area covered by both rectangles.
+    ///
+    /// An empty rectangle is returned if the rectangles don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let (x_start, x_end) = intersect_ranges(self.x_range(), other.x_range());
+        let (y_start, y_end) = intersect_ranges(self.y_range(), other.y_range());
+
+        if x_start >= x_end || y_start >= y_end {
+            return Rect::default();
+        }
+
+        Rect::from_ranges((x_start, x_end), (y_start, y_end))
+    }
+
+    /// Returns the smallest rectangle which contains both rectangles.
+    ///
+    /// Empty rectangles are ignored, which makes an empty rectangle a useful starting point to
+    /// accumulate the bounding box of a number of rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            return *other;
+        } else if other.is_empty() {
+            return *self;
+        }
+
+        let (a, b) = (self.x_range(), other.x_range());
+        let x_range = (min(a.0, b.0), max(a.1, b.1));
+
+        let (a, b) = (self.y_range(), other.y_range());
+        let y_range = (min(a.0, b.0), max(a.1, b.1));
+
+        Rect::from_ranges(x_range, y_range)
+    }
+
+    /// Returns a rectangle with all edges moved outwards by `offset` pixels.
+    ///
+    /// A negative offset moves the edges inwards. The size of the returned rectangle is clamped to
+    /// zero if the rectangle is shrunk by more than its size.
+    pub fn offset(&self, offset: i32) -> Rect {
+        let offset = i64::from(offset);
+
+        let resize = |length: u32| (i64::from(length) + 2 * offset).max(0);
+        let width = resize(self.size.width);
+        let height = resize(self.size.height);
+
+        // Keep the center in place if the rectangle collapses
+        let x_start = i64::from(self.top_left.x) - offset.max(-i64::from(self.size.width) / 2);
+        let y_start = i64::from(self.top_left.y) - offset.max(-i64::from(self.size.height) / 2);
+
+        Rect::from_ranges((x_start, x_start + width), (y_start, y_start + height))
+    }
+
+    /// Returns a rectangle with all edges moved inwards by `inset` pixels.
+    ///
+    /// This is the same as `offset(-inset)`.
+    pub fn inset(&self, inset: i32) -> Rect {
+        self.offset(inset.saturating_neg())
+    }
+
+    /// Returns an iterator over all points inside the rectangle.
+    ///
+    /// The points are returned row by row, starting at the top left corner.
+    pub fn points(&self) -> RectPoints {
+        let bottom_right = self.bottom_right();
+
+        RectPoints {
+            x_start: self.top_left.x,
+            x_end: bottom_right.map_or(0, |p| p.x),
+            y_end: bottom_right.map_or(0, |p| p.y),
+            p: bottom_right.map(|_| self.top_left),
+        }
+    }
+
+    /// Returns the range of x coordinates covered by the rectangle, with an exclusive end.
+    pub(crate) fn x_range(&self) -> (i64, i64) {
+        let start = i64::from(self.top_left.x);
+
+        (start, start + i64::from(self.size.width))
+    }
+
+    /// Returns the range of y coordinates covered by the rectangle, with an exclusive end.
+    pub(crate) fn y_range(&self) -> (i64, i64) {
+        let start = i64::from(self.top_left.y);
+
+        (start, start + i64::from(self.size.height))
+    }
+
+    /// Creates a rectangle from coordinate ranges with exclusive ends.
+    ///
+    /// The ranges are saturated to the coordinate range of `Point` and `Size`.
+    pub(crate) fn from_ranges(x_range: (i64, i64), y_range: (i64, i64)) -> Self {
+        let clamp_coord = |v: i64| {
+            max(
+                min(v, i64::from(i32::max_value())),
+                i64::from(i32::min_value()),
+            ) as i32
+        };
+        let clamp_size = |v: i64| min(max(v, 0), i64::from(u32::max_value())) as u32;
+
+        Rect::new(
+            Point::new(clamp_coord(x_range.0), clamp_coord(y_range.0)),
+            Size::new(
+                clamp_size(x_range.1 - x_range.0),
+                clamp_size(y_range.1 - y_range.0),
+            ),
+        )
+    }
+}
+
+impl ContainsPoint for Rect {
+    fn contains(&self, point: Point) -> bool {
+        Rect::contains(self, point)
+    }
+}
+
+fn intersect_ranges(a: (i64, i64), b: (i64, i64)) -> (i64, i64) {
+    (max(a.0, b.0), min(a.1, b.1))
+}
+
+impl Transform for Rect {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+/// Iterator over all points inside a [`Rect`].
+///
+/// [`Rect`]: struct.Rect.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RectPoints {
+    x_start: i32,
+    x_end: i32,
+    y_end: i32,
+    p: Option<Point>,
+}
+
+impl Iterator for RectPoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.p?;
+
+        self.p = if point.x < self.x_end {
+            Some(Point::new(point.x + 1, point.y))
+        } else if point.y < self.y_end {
+            Some(Point::new(self.x_start, point.y + 1))
+        } else {
+            None
+        };
+
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_corners() {
+        let expected = Rect::new(Point::new(1, 2), Size::new(4, 6));
+
+        assert_eq!(
+            Rect::with_corners(Point::new(1, 2), Point::new(4, 7)),
+            expected
+        );
+        assert_eq!(
+            Rect::with_corners(Point::new(4, 7), Point::new(1, 2)),
+            expected
+        );
+        assert_eq!(
+            Rect::with_corners(Point::new(1, 7), Point::new(4, 2)),
+            expected
+        );
+        assert_eq!(expected.bottom_right(), Some(Point::new(4, 7)));
+    }
+
+    #[test]
+    fn bottom_right_saturates() {
+        let rect = Rect::new(
+            Point::new(i32::min_value(), 0),
+            Size::new(u32::max_value(), u32::max_value()),
+        );
+
+        assert_eq!(
+            rect.bottom_right(),
+            Some(Point::new(i32::max_value() - 1, i32::max_value()))
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let rect = Rect::new(Point::new(5, 5), Size::new(0, 10));
+
+        assert!(rect.is_empty());
+        assert_eq!(rect.bottom_right(), None);
+        assert!(!rect.contains(Point::new(5, 5)));
+        assert_eq!(rect.points().next(), None);
+    }
+
+    #[test]
+    fn contains() {
+        let rect = Rect::new(Point::new(-2, 3), Size::new(4, 2));
+
+        assert!(rect.contains(Point::new(-2, 3)));
+        assert!(rect.contains(Point::new(1, 4)));
+        assert!(!rect.contains(Point::new(2, 4)));
+        assert!(!rect.contains(Point::new(1, 5)));
+        assert!(!rect.contains(Point::new(-3, 3)));
+    }
+
+    #[test]
+    fn center() {
+        assert_eq!(
+            Rect::new(Point::new(1, 1), Size::new(5, 3)).center(),
+            Point::new(3, 2)
+        );
+        assert_eq!(
+            Rect::new(Point::new(1, 1), Size::new(4, 2)).center(),
+            Point::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Rect::new(Point::new(0, 0), Size::new(10, 10));
+        let b = Rect::new(Point::new(-5, 3), Size::new(8, 20));
+
+        assert_eq!(
+            a.intersection(&b),
+            Rect::new(Point::new(0, 3), Size::new(3, 7))
+        );
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+
+        let touching = Rect::new(Point::new(10, 0), Size::new(10, 10));
+        assert!(a.intersection(&touching).is_empty());
+    }
+
+    #[test]
+    fn union() {
+        let a = Rect::new(Point::new(0, 0), Size::new(10, 10));
+        let b = Rect::new(Point::new(-5, 3), Size::new(8, 20));
+
+        assert_eq!(a.union(&b), Rect::new(Point::new(-5, 0), Size::new(15, 23)));
+        assert_eq!(a.union(&b), b.union(&a));
+        assert_eq!(a.union(&Rect::default()), a);
+        assert_eq!(Rect::default().union(&b), b);
+    }
+
+    #[test]
+    fn offset_and_inset() {
+        let rect = Rect::new(Point::new(10, 20), Size::new(6, 5));
+
+        assert_eq!(
+            rect.offset(2),
+            Rect::new(Point::new(8, 18), Size::new(10, 9))
+        );
+        assert_eq!(
+            rect.inset(2),
+            Rect::new(Point::new(12, 22), Size::new(2, 1))
+        );
+        assert_eq!(rect.offset(-2), rect.inset(2));
+
+        let collapsed = rect.inset(10);
+        assert!(collapsed.is_empty());
+        assert_eq!(collapsed.top_left, Point::new(13, 22));
+    }
+
+    #[test]
+    fn points() {
+        let rect = Rect::new(Point::new(-1, 2), Size::new(3, 2));
+
+        assert!(rect.points().eq([
+            Point::new(-1, 2),
+            Point::new(0, 2),
+            Point::new(1, 2),
+            Point::new(-1, 3),
+            Point::new(0, 3),
+            Point::new(1, 3),
+        ]
+        .iter()
+        .cloned()));
+
+        assert!(rect.points().all(|p| rect.contains(p)));
+    }
+
+    #[test]
+    fn translate() {
+        let rect = Rect::new(Point::new(1, 2), Size::new(3, 4));
+
+        assert_eq!(
+            rect.translate(Point::new(-5, 5)),
+            Rect::new(Point::new(-4, 7), Size::new(3, 4))
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::geometry::Point;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Number of fractional bits of a `SubpixelPoint` coordinate.
+pub(crate) const SUBPIXEL_BITS: u32 = 8;
+
+/// Value of one pixel in subpixel units.
+pub(crate) const SUBPIXEL_ONE: i32 = 1 << SUBPIXEL_BITS;
+
+/// Sub-pixel 2D point.
+///
+/// The coordinates are 24.8 fixed point numbers, which means that each pixel is divided into 256
+/// steps along both axes. Integer coordinates are the centers of pixels, like for [`Point`].
+///
+/// Lines, circles, triangles and quads accept `SubpixelPoint`s instead of `Point`s, which makes it
+/// possible to move objects smoothly and to position them between pixels. They are still drawn
+/// with whole pixels, but the pixels are chosen based on the exact sub-pixel position. Primitives
+/// with integer coordinates are drawn exactly like primitives which use `Point`s.
+///
+/// # Examples
+///
+/// ## Convert between sub-pixel and integer points
+///
+/// ```rust
+/// use embedded_graphics::geometry::{Point, SubpixelPoint};
+///
+/// // 10.5, 20.25
+/// let point = SubpixelPoint::new(10 * 256 + 128, 20 * 256 + 64);
+///
+/// assert_eq!(point.round(), Point::new(11, 20));
+/// assert_eq!(point.floor(), Point::new(10, 20));
+/// assert_eq!(point.ceil(), Point::new(11, 21));
+///
+/// assert_eq!(SubpixelPoint::from(Point::new(3, 4)), SubpixelPoint::new(768, 1024));
+/// ```
+///
+/// ## Draw a line between pixels
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::geometry::SubpixelPoint;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::primitives::Line;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // A line from (0, 0) to (20, 10.5)
+/// Line::new(SubpixelPoint::new(0, 0), SubpixelPoint::new(20 * 256, 10 * 256 + 128))
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut display);
+/// ```
+///
+/// [`Point`]: struct.Point.html
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct SubpixelPoint {
+    /// The x coordinate in 1/256 pixels.
+    pub x: i32,
+
+    /// The y coordinate in 1/256 pixels.
+    pub y: i32,
+}
+
+impl SubpixelPoint {
+    /// Creates a point from X and Y coordinates in 1/256 pixels.
+    pub const fn new(x: i32, y: i32) -> Self {
+        SubpixelPoint { x, y }
+    }
+
+    /// Creates a point at the origin.
+    pub const fn zero() -> Self {
+        SubpixelPoint { x: 0, y: 0 }
+    }
+
+    /// Returns the nearest integer point.
+    ///
+    /// Coordinates exactly between two pixels are rounded up.
+    pub fn round(self) -> Point {
+        let half = SUBPIXEL_ONE / 2;
+
+        Point::new(
+            (self.x + half) >> SUBPIXEL_BITS,
+            (self.y + half) >> SUBPIXEL_BITS,
+        )
+    }
+
+    /// Returns the largest integer point which is less than or equal to this point.
+    pub fn floor(self) -> Point {
+        Point::new(self.x >> SUBPIXEL_BITS, self.y >> SUBPIXEL_BITS)
+    }
+
+    /// Returns the smallest integer point which is greater than or equal to this point.
+    pub fn ceil(self) -> Point {
+        let max_fraction = SUBPIXEL_ONE - 1;
+
+        Point::new(
+            (self.x + max_fraction) >> SUBPIXEL_BITS,
+            (self.y + max_fraction) >> SUBPIXEL_BITS,
+        )
+    }
+
+    /// Returns `true` if both coordinates are whole pixels.
+    pub fn is_integer(self) -> bool {
+        (self.x | self.y) & (SUBPIXEL_ONE - 1) == 0
+    }
+}
+
+impl From<Point> for SubpixelPoint {
+    fn from(other: Point) -> Self {
+        SubpixelPoint::new(other.x << SUBPIXEL_BITS, other.y << SUBPIXEL_BITS)
+    }
+}
+
+impl From<&Point> for SubpixelPoint {
+    fn from(other: &Point) -> Self {
+        SubpixelPoint::from(*other)
+    }
+}
+
+impl Add for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn add(self, other: SubpixelPoint) -> SubpixelPoint {
+        SubpixelPoint::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Point> for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn add(self, other: Point) -> SubpixelPoint {
+        self + SubpixelPoint::from(other)
+    }
+}
+
+impl AddAssign for SubpixelPoint {
+    fn add_assign(&mut self, other: SubpixelPoint) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl AddAssign<Point> for SubpixelPoint {
+    fn add_assign(&mut self, other: Point) {
+        *self += SubpixelPoint::from(other);
+    }
+}
+
+impl Sub for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn sub(self, other: SubpixelPoint) -> SubpixelPoint {
+        SubpixelPoint::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Sub<Point> for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn sub(self, other: Point) -> SubpixelPoint {
+        self - SubpixelPoint::from(other)
+    }
+}
+
+impl SubAssign for SubpixelPoint {
+    fn sub_assign(&mut self, other: SubpixelPoint) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl SubAssign<Point> for SubpixelPoint {
+    fn sub_assign(&mut self, other: Point) {
+        *self -= SubpixelPoint::from(other);
+    }
+}
+
+impl Neg for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn neg(self) -> Self::Output {
+        SubpixelPoint::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding() {
+        let point = SubpixelPoint::new(-3 * 256 - 128, 2 * 256 + 127);
+
+        assert_eq!(point.round(), Point::new(-3, 2));
+        assert_eq!(point.floor(), Point::new(-4, 2));
+        assert_eq!(point.ceil(), Point::new(-3, 3));
+        assert!(!point.is_integer());
+
+        let point = SubpixelPoint::from(Point::new(-5, 7));
+        assert_eq!(point.round(), Point::new(-5, 7));
+        assert_eq!(point.floor(), Point::new(-5, 7));
+        assert_eq!(point.ceil(), Point::new(-5, 7));
+        assert!(point.is_integer());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut point = SubpixelPoint::new(100, -50);
+
+        assert_eq!(point + Point::new(1, 1), SubpixelPoint::new(356, 206));
+        assert_eq!(
+            point - SubpixelPoint::new(100, 50),
+            SubpixelPoint::new(0, -100)
+        );
+        assert_eq!(-point, SubpixelPoint::new(-100, 50));
+
+        point += Point::new(-1, 0);
+        assert_eq!(point, SubpixelPoint::new(-156, -50));
+
+        point -= SubpixelPoint::new(4, 4);
+        assert_eq!(point, SubpixelPoint::new(-160, -54));
+    }
+}
@@ -81,12 +81,15 @@
 //! use embedded_graphics::prelude::*;
 //! use embedded_graphics::primitives::Circle;
 //! use embedded_graphics::fonts::Font6x8;
+//! use embedded_graphics::style::{PrimitiveStyle, TextStyle};
 //! use embedded_graphics::pixelcolor::Rgb565;
 //! # use embedded_graphics::mock_display::MockDisplay;
 //! # let mut display = MockDisplay::default();
 //!
-//! let c = Circle::new(Point::new(20, 20), 8).fill_color(Some(Rgb565::RED));
-//! let t = Font6x8::render_str("Hello Rust!").fill_color(Some(Rgb565::GREEN)).translate(Point::new(20, 16));
+//! let c = Circle::new(Point::new(20, 20), 8).into_styled(PrimitiveStyle::with_fill(Rgb565::RED));
+//! let t = Font6x8::render_str("Hello Rust!")
+//!     .translate(Point::new(20, 16))
+//!     .into_styled(TextStyle::with_text_color(Rgb565::GREEN));
 //!
 //! c.draw(&mut display);
 //! t.draw(&mut display);
@@ -105,7 +108,7 @@
 //! # let mut display = MockDisplay::default();
 //!
 //! let c = egcircle!((20, 20), 8, fill_color = Some(Rgb565::RED));
-//! let t = text_6x8!("Hello Rust!", fill_color = Some(Rgb565::GREEN)).translate(Point::new(20, 16));
+//! let t = text_6x8!("Hello Rust!", text_color = Some(Rgb565::GREEN)).translate(Point::new(20, 16));
 //!
 //! c.draw(&mut display);
 //! t.draw(&mut display);
@@ -124,7 +127,7 @@
 //! fn build_thing(text: &'static str) -> impl Iterator<Item = Pixel<Rgb565>> {
 //!     egrectangle!((0, 0), (40, 40)).into_iter()
 //!         .chain(egcircle!((20, 20), 8, fill_color = Some(Rgb565::RED)))
-//!         .chain(text_6x8!(text, fill_color = Some(Rgb565::GREEN)).translate(Point::new(20, 16)))
+//!         .chain(text_6x8!(text, text_color = Some(Rgb565::GREEN)).translate(Point::new(20, 16)))
 //! }
 //!
 //! fn main() {
@@ -165,6 +168,7 @@ extern crate nalgebra;
 
 mod check_readme;
 pub mod drawable;
+pub mod flood_fill;
 pub mod fonts;
 pub mod geometry;
 pub mod image;
@@ -173,13 +177,15 @@ pub mod mock_display;
 pub mod pixelcolor;
 pub mod prelude;
 pub mod primitives;
+pub mod span;
 pub mod style;
 pub mod transform;
 
 use crate::drawable::Drawable;
-use crate::geometry::{Dimensions, Point, Size};
+use crate::geometry::{Dimensions, Point, Rect, Size};
 use crate::pixelcolor::PixelColor;
-use crate::style::WithStyle;
+use crate::primitives::Primitive;
+use crate::style::{PrimitiveStyle, Styled};
 
 /// Defines a display that can be used to render [`Drawable`] objects.
 ///
@@ -269,6 +275,7 @@ use crate::style::WithStyle;
 /// # use embedded_graphics::DrawTarget;
 /// # use embedded_graphics::egrectangle;
 /// # use embedded_graphics::primitives::rectangle::Rectangle;
+/// # use embedded_graphics::style::{PrimitiveStyle, Styled};
 /// # use embedded_graphics::pixelcolor::{Gray8, GrayColor};
 /// # use embedded_graphics::drawable::Pixel;
 /// #
@@ -293,7 +300,7 @@ use crate::style::WithStyle;
 ///     }
 ///
 ///     /// A HW-accelerated method for drawing rectangles
-///     pub fn fast_rectangle(&self, rect: &Rectangle<Gray8>) {
+///     pub fn fast_rectangle(&self, rect: &Rectangle, style: &PrimitiveStyle<Gray8>) {
 ///         // Does some speedy drawing
 ///     }
 /// }
@@ -312,8 +319,8 @@ use crate::style::WithStyle;
 ///     }
 ///
 ///     /// Use the accelerated method when drawing rectangles
-///     fn draw_rectangle(&mut self, item: &Rectangle<Gray8>) {
-///         self.fast_rectangle(item);
+///     fn draw_rectangle(&mut self, item: &Styled<Rectangle, PrimitiveStyle<Gray8>>) {
+///         self.fast_rectangle(&item.primitive, &item.style);
 ///     }
 /// }
 ///
@@ -352,9 +359,53 @@ where
         }
     }
 
+    /// Draws horizontal spans of pixels.
+    ///
+    /// The default primitive drawing methods, like [`draw_rectangle`], draw filled primitives as
+    /// spans. This default implementation draws each pixel of each span with [`draw_pixel`] and
+    /// should be overridden if a display provides a fast way to fill a horizontal line, like
+    /// setting an address window and streaming the color. This accelerates all filled
+    /// primitives at once.
+    ///
+    /// [`draw_rectangle`]: #method.draw_rectangle
+    /// [`draw_pixel`]: #tymethod.draw_pixel
+    fn draw_hline_spans<T>(&mut self, spans: T)
+    where
+        T: IntoIterator<Item = span::Span<C>>,
+    {
+        for span in spans {
+            self.draw_iter(span.pixels());
+        }
+    }
+
     /// Returns the dimensions of the `DrawTarget` in pixels.
+    ///
+    /// The default implementations of the primitive drawing methods, like [`draw_line`], use the
+    /// size to skip pixels outside the display without visiting them. Drivers which don't know the
+    /// size of the display can return `Size::zero()`, which disables clipping.
+    ///
+    /// [`draw_line`]: #method.draw_line
     fn size(&self) -> Size;
 
+    /// Returns the area of the display as a rectangle.
+    ///
+    /// The default implementation returns a rectangle of the display [`size`] with its top left
+    /// corner at the origin. If the size is zero the returned rectangle covers the whole coordinate
+    /// space, because the display area is unknown. Wrappers which move the coordinate system, like
+    /// [`TransformedDisplay`], return the area that is visible on the underlying display instead.
+    ///
+    /// [`size`]: #tymethod.size
+    /// [`TransformedDisplay`]: ./transform/struct.TransformedDisplay.html
+    fn display_area(&self) -> Rect {
+        let size = self.size();
+
+        if size == Size::zero() {
+            Rect::unbounded()
+        } else {
+            Rect::new(Point::zero(), size)
+        }
+    }
+
     /// Clears the display with the supplied color.
     ///
     /// This default implementation should be replaced if the implementing driver provides an
@@ -364,7 +415,7 @@ where
         Self: Sized,
     {
         primitives::Rectangle::new(Point::zero(), Point::zero() + self.size())
-            .fill_color(Some(color))
+            .into_styled(PrimitiveStyle::with_fill(color))
             .draw(self);
     }
 
@@ -388,8 +439,8 @@ where
     ///
     /// [`Line`]: ./primitives/line/struct.Line.html
     /// [`draw`]: ./trait.DrawTarget.html#method.draw
-    fn draw_line(&mut self, item: &primitives::Line<C>) {
-        self.draw_iter(item);
+    fn draw_line(&mut self, item: &Styled<primitives::Line, PrimitiveStyle<C>>) {
+        self.draw_iter(item.clipped_iter(&self.display_area()));
     }
 
     /// Draws a triangle primitive.
@@ -405,8 +456,8 @@ where
     ///
     /// [`Triangle`]: ./primitives/triangle/struct.Triangle.html
     /// [`draw`]: ./trait.DrawTarget.html#method.draw
-    fn draw_triangle(&mut self, item: &primitives::Triangle<C>) {
-        self.draw_iter(item);
+    fn draw_triangle(&mut self, item: &Styled<primitives::Triangle, PrimitiveStyle<C>>) {
+        self.draw_hline_spans(item.clipped_spans(&self.display_area()));
     }
 
     /// Draws a rectangle primitive.
@@ -422,8 +473,8 @@ where
     ///
     /// [`Rectangle`]: ./primitives/rectangle/struct.Rectangle.html
     /// [`draw`]: ./trait.DrawTarget.html#method.draw
-    fn draw_rectangle(&mut self, item: &primitives::Rectangle<C>) {
-        self.draw_iter(item);
+    fn draw_rectangle(&mut self, item: &Styled<primitives::Rectangle, PrimitiveStyle<C>>) {
+        self.draw_hline_spans(item.clipped_spans(&self.display_area()));
     }
 
     /// Draws a circle primitive.
@@ -439,7 +490,26 @@ where
     ///
     /// [`Circle`]: ./primitives/circle/struct.Circle.html
     /// [`draw`]: ./trait.DrawTarget.html#method.draw
-    fn draw_circle(&mut self, item: &primitives::Circle<C>) {
-        self.draw_iter(item);
+    fn draw_circle(&mut self, item: &Styled<primitives::Circle, PrimitiveStyle<C>>) {
+        self.draw_hline_spans(item.clipped_spans(&self.display_area()));
     }
 }
+
+/// Defines a display which can read back the color of its pixels.
+///
+/// Some drawing operations, like a [`FloodFill`], depend on the current content of the display and
+/// can only be used with a `GetPixel` target. Colors returned by [`pixel`] must reflect all
+/// previously drawn pixels, which might not be possible for some displays that buffer drawing
+/// operations.
+///
+/// [`FloodFill`]: ./flood_fill/struct.FloodFill.html
+/// [`pixel`]: #tymethod.pixel
+pub trait GetPixel<C>: DrawTarget<C>
+where
+    C: PixelColor,
+{
+    /// Returns the color of the pixel at a point.
+    ///
+    /// Returns `None` if the point is outside the display.
+    fn pixel(&self, point: Point) -> Option<C>;
+}
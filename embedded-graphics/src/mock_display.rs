@@ -19,10 +19,14 @@
 //! | `'.'`     | `Some(BinaryColor::Off)` | Pixel was changed to `BinaryColor::Off` |
 //! | `'#'`     | `Some(BinaryColor::On)`  | Pixel was changed to `BinaryColor::On`  |
 
+#[cfg(test)]
+use crate::drawable::Drawable;
 use crate::drawable::Pixel;
+#[cfg(test)]
+use crate::geometry::{ContainsPoint, Rect};
 use crate::geometry::{Point, Size};
 use crate::pixelcolor::{BinaryColor, PixelColor};
-use crate::DrawTarget;
+use crate::{DrawTarget, GetPixel};
 use core::{
     cmp::PartialEq,
     fmt::{self, Write},
@@ -185,6 +189,82 @@ where
     }
 }
 
+impl<C> GetPixel<C> for MockDisplay<C>
+where
+    C: PixelColor,
+{
+    /// Returns the color of a pixel.
+    ///
+    /// Pixels that weren't changed by any drawing operation are treated like pixels outside the
+    /// display and `None` is returned.
+    fn pixel(&self, point: Point) -> Option<C> {
+        let Point { x, y } = point;
+        if !(0..SIZE).contains(&(x as usize)) || !(0..SIZE).contains(&(y as usize)) {
+            return None;
+        }
+
+        self.get_pixel(point)
+    }
+}
+
+/// Checks that a shape contains exactly the points which are drawn by it.
+///
+/// The shape is drawn to a new mock display and compared for all points in the top left 32x32
+/// pixel area.
+#[cfg(test)]
+pub(crate) fn assert_contains_drawn_points<T, C>(shape: &T)
+where
+    T: ContainsPoint + fmt::Debug,
+    for<'a> &'a T: Drawable<C>,
+    C: PixelColor,
+{
+    let mut display = MockDisplay::new();
+    shape.draw(&mut display);
+
+    for point in (0..32).flat_map(|y| (0..32).map(move |x| Point::new(x, y))) {
+        assert_eq!(
+            shape.contains(point),
+            display.get_pixel(point).is_some(),
+            "{:?} {:?}",
+            shape,
+            point
+        );
+    }
+}
+
+/// Checks that a clipped pixel iterator returns the pixels of the unclipped iterator which lie
+/// inside the clip rectangle.
+///
+/// `clipped_iter` is called with clip rectangles which cover the whole shape, parts of it, a
+/// single column, no pixels and an area next to the shape.
+#[cfg(test)]
+pub(crate) fn assert_clipped_iter<'a, T, C, I, F>(shape: &'a T, clipped_iter: F)
+where
+    T: fmt::Debug,
+    &'a T: IntoIterator<Item = Pixel<C>>,
+    C: PixelColor,
+    I: Iterator<Item = Pixel<C>>,
+    F: Fn(&Rect) -> I,
+{
+    let clips = [
+        Rect::new(Point::new(0, 0), Size::new(64, 64)),
+        Rect::new(Point::new(5, 7), Size::new(6, 4)),
+        Rect::new(Point::new(-10, -3), Size::new(15, 12)),
+        Rect::new(Point::new(12, 0), Size::new(1, 30)),
+        Rect::new(Point::new(100, 100), Size::new(10, 10)),
+        Rect::new(Point::new(3, 3), Size::zero()),
+    ];
+
+    for clip in clips.iter() {
+        assert!(
+            clipped_iter(clip).eq(shape.into_iter().filter(|Pixel(p, _)| clip.contains(*p))),
+            "{:?} {:?}",
+            shape,
+            clip
+        );
+    }
+}
+
 /// Mapping between `char`s and colors.
 ///
 /// See the [module-level documentation] for a table of implemented mappings.
@@ -0,0 +1,39 @@
+//! Blending of color channels.
+
+/// Blends a single channel value.
+///
+/// `alpha` is the opacity of the foreground value, from `0` for fully transparent to `255` for
+/// fully opaque.
+pub(crate) fn blend_channel(foreground: u8, background: u8, alpha: u8) -> u8 {
+    let alpha = u32::from(alpha);
+    let value = u32::from(foreground) * alpha + u32::from(background) * (255 - alpha);
+
+    ((value + 127) / 255) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixelcolor::{Gray2, Gray4, GrayColor, PixelColor, Rgb565, Rgb888, RgbColor};
+
+    #[test]
+    fn blend_rgb() {
+        let foreground = Rgb888::new(255, 100, 0);
+        let background = Rgb888::new(0, 200, 50);
+
+        assert_eq!(foreground.blend(background, 255), foreground);
+        assert_eq!(foreground.blend(background, 0), background);
+        assert_eq!(foreground.blend(background, 51), Rgb888::new(51, 180, 40));
+
+        // Channels are blended in the range of the color type
+        assert_eq!(
+            Rgb565::WHITE.blend(Rgb565::BLACK, 128),
+            Rgb565::new(16, 32, 16)
+        );
+    }
+
+    #[test]
+    fn blend_gray() {
+        assert_eq!(Gray4::WHITE.blend(Gray4::BLACK, 85), Gray4::new(5));
+        assert_eq!(Gray2::new(1).blend(Gray2::new(3), 255), Gray2::new(1));
+    }
+}
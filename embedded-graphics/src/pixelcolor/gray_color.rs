@@ -1,4 +1,5 @@
 use crate::pixelcolor::{
+    blend::blend_channel,
     raw::{RawData, RawU2, RawU4, RawU8},
     PixelColor,
 };
@@ -34,6 +35,10 @@ macro_rules! gray_color {
 
         impl PixelColor for $type {
             type Raw = $raw_type;
+
+            fn blend(self, background: Self, alpha: u8) -> Self {
+                Self::new(blend_channel(self.luma(), background.luma(), alpha))
+            }
         }
 
         impl GrayColor for $type {
@@ -68,6 +68,7 @@
 //! [`raw` module]: raw/index.html
 
 mod binary_color;
+mod blend;
 mod conversion;
 mod gray_color;
 pub mod raw;
@@ -93,4 +94,31 @@ pub trait PixelColor: Copy + PartialEq {
     ///
     /// [`raw` module documentation]: raw/index.html
     type Raw: raw::RawData;
+
+    /// Blends this color over a background color.
+    ///
+    /// Blending is used to draw anti-aliased text, which stores the coverage of each pixel of a
+    /// glyph. `alpha` is the opacity of this color, from `0` for fully transparent to `255` for
+    /// fully opaque.
+    ///
+    /// The RGB and grayscale colors mix the channels of both colors. The default implementation
+    /// doesn't mix colors and returns `self` if `alpha` is at least `128` or `background`
+    /// otherwise, which is used by `BinaryColor` and custom color types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::pixelcolor::{BinaryColor, Gray8, PixelColor};
+    ///
+    /// assert_eq!(Gray8::new(200).blend(Gray8::new(100), 128), Gray8::new(150));
+    /// assert_eq!(BinaryColor::On.blend(BinaryColor::Off, 128), BinaryColor::On);
+    /// assert_eq!(BinaryColor::On.blend(BinaryColor::Off, 127), BinaryColor::Off);
+    /// ```
+    fn blend(self, background: Self, alpha: u8) -> Self {
+        if alpha >= 128 {
+            self
+        } else {
+            background
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::pixelcolor::{
+    blend::blend_channel,
     raw::{RawData, RawU16, RawU24},
     PixelColor,
 };
@@ -146,6 +147,14 @@ macro_rules! impl_rgb_color {
 
         impl PixelColor for $type {
             type Raw = $data_type;
+
+            fn blend(self, background: Self, alpha: u8) -> Self {
+                Self::new(
+                    blend_channel(self.r(), background.r(), alpha),
+                    blend_channel(self.g(), background.g(), alpha),
+                    blend_channel(self.b(), background.b(), alpha),
+                )
+            }
         }
 
         impl From<$data_type> for $type {
@@ -3,9 +3,10 @@
 pub use super::drawable::Drawable;
 pub use super::drawable::Pixel;
 pub use super::fonts::Font;
-pub use super::geometry::{Dimensions, Point, Size};
+pub use super::geometry::{Angle, ContainsPoint, Dimensions, Point, Size};
 pub use super::image::ImageFile;
 pub use super::pixelcolor::{raw::RawData, GrayColor, PixelColor, RgbColor};
-pub use super::style::{Style, WithStyle};
-pub use super::transform::Transform;
-pub use super::DrawTarget;
+pub use super::primitives::Primitive;
+pub use super::style::{PrimitiveStyle, Styled, TextStyle};
+pub use super::transform::{Mirror, Rotate, Scale, Transform};
+pub use super::{DrawTarget, GetPixel};
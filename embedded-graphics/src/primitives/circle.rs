@@ -1,16 +1,22 @@
 //! The circle primitive
 
 use super::super::drawable::{Drawable, Pixel};
-use super::super::transform::Transform;
-use crate::geometry::{Dimensions, Point, Size};
+use super::super::transform::{Axis, Mirror, Rotate, Scale, Transform};
+use crate::geometry::{
+    Angle, ContainsPoint, Dimensions, Point, Rect, Size, SubpixelPoint, Vertex, SUBPIXEL_BITS,
+    SUBPIXEL_ONE,
+};
 use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
-use crate::style::Style;
-use crate::style::WithStyle;
+use crate::primitives::{isqrt, Primitive};
+use crate::span::{Area, RowSpans, Span};
+use crate::style::{PrimitiveStyle, Styled};
 use crate::DrawTarget;
 
 /// Circle primitive
 ///
+/// The center of a circle is a [`Point`] by default. A [`SubpixelPoint`] can be used instead to
+/// position the circle with sub-pixel precision.
+///
 /// # Examples
 ///
 /// The [macro examples](../../macro.egcircle.html) make for more concise code.
@@ -21,105 +27,182 @@ use crate::DrawTarget;
 /// use embedded_graphics::prelude::*;
 /// use embedded_graphics::primitives::Circle;
 /// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::style::PrimitiveStyle;
 /// # use embedded_graphics::mock_display::MockDisplay;
 /// # let mut display = MockDisplay::default();
 ///
-/// // Default circle with only a stroke centered around (10, 20) with a radius of 30
-/// Circle::new(Point::new(10, 20), 30).draw(&mut display);
+/// // Circle with a 1 pixel wide stroke centered around (10, 20) with a radius of 30
+/// Circle::new(Point::new(10, 20), 30)
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
+///     .draw(&mut display);
 ///
 /// // Circle with styled stroke and fill centered around (50, 20) with a radius of 30
 /// Circle::new(Point::new(50, 20), 30)
-///     .stroke_color(Some(Rgb565::RED))
-///     .stroke_width(3)
-///     .fill_color(Some(Rgb565::GREEN))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 3).fill_color(Some(Rgb565::GREEN)))
 ///     .draw(&mut display);
 ///
 /// // Circle with no stroke and a translation applied
 /// Circle::new(Point::new(10, 20), 30)
-///     .stroke_color(None)
-///     .fill_color(Some(Rgb565::BLUE))
 ///     .translate(Point::new(65, 35))
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
 ///     .draw(&mut display);
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct Circle<C: PixelColor> {
+///
+/// [`Point`]: ../../geometry/struct.Point.html
+/// [`SubpixelPoint`]: ../../geometry/struct.SubpixelPoint.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Circle<P = Point> {
     /// Center point of circle
-    pub center: Point,
+    pub center: P,
 
     /// Radius of the circle
     pub radius: u32,
-
-    /// Style of the circle
-    pub style: Style<C>,
 }
 
-impl<C> Circle<C>
-where
-    C: PixelColor,
-{
+impl<P> Circle<P> {
     /// Create a new circle centered around a given point with a specific radius
-    pub fn new(center: Point, radius: u32) -> Self {
-        Circle {
-            center,
-            radius,
-            style: Style::default(),
+    pub fn new(center: P, radius: u32) -> Self {
+        Circle { center, radius }
+    }
+}
+
+impl<P: Vertex> Circle<P> {
+    /// Returns `true` if the point is drawn when the circle is styled with the given stroke width.
+    fn contains_with_stroke_width(&self, point: Point, stroke_width: i32) -> bool {
+        let top_left = self.top_left();
+        let bottom_right = self.bottom_right();
+
+        if point.x < top_left.x
+            || point.y < top_left.y
+            || point.x > bottom_right.x
+            || point.y > bottom_right.y
+        {
+            return false;
         }
+
+        let t = SubpixelPoint::from(point) - self.center.into();
+        let (is_border, is_fill) = classify(t, self.radius as i32, stroke_width);
+
+        is_border || is_fill
     }
 }
 
-impl<C> Primitive for Circle<C> where C: PixelColor {}
+impl<P: Vertex> Primitive for Circle<P> {}
+
+impl<P: Vertex> ContainsPoint for Circle<P> {
+    /// Returns `true` if the point is drawn when the circle is styled with a 1 pixel wide stroke
+    /// and a fill.
+    fn contains(&self, point: Point) -> bool {
+        self.contains_with_stroke_width(point, 1)
+    }
+}
 
-impl<C> Dimensions for Circle<C>
+impl<C, P> ContainsPoint for Styled<Circle<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
+    fn contains(&self, point: Point) -> bool {
+        self.primitive
+            .contains_with_stroke_width(point, self.style.stroke_width_i32())
+    }
+}
+
+/// Returns whether a point relative to the center of a circle is part of the border and the fill.
+///
+/// The offset `t` is given in sub-pixel units, which is why the thresholds are scaled by the square
+/// of a pixel.
+fn classify(t: SubpixelPoint, outer_radius: i32, stroke_width: i32) -> (bool, bool) {
+    let (inner, outer, fill) = thresholds(outer_radius, stroke_width);
+
+    let len = (i64::from(t.x) * i64::from(t.x)).saturating_add(i64::from(t.y) * i64::from(t.y));
+
+    let is_border = len > inner && len < outer;
+    let is_fill = len <= fill;
+
+    (is_border, is_fill)
+}
+
+/// Returns the squared distances from the center used by [`classify`].
+///
+/// A point is part of the border if its squared distance is greater than the first and less than
+/// the second threshold, and part of the fill if it's less than or equal to the third threshold.
+///
+/// [`classify`]: fn.classify.html
+fn thresholds(outer_radius: i32, stroke_width: i32) -> (i64, i64, i64) {
+    let radius = i64::from(outer_radius) - i64::from(stroke_width) + 1;
+    let outer_radius = i64::from(outer_radius);
+
+    let scale = |value: i64| value.saturating_mul(i64::from(SUBPIXEL_ONE * SUBPIXEL_ONE));
+
+    let radius_sq = radius.saturating_mul(radius);
+    let outer_radius_sq = outer_radius * outer_radius;
+
+    (
+        scale(radius_sq - radius),
+        scale(outer_radius_sq.saturating_add(radius)),
+        scale(outer_radius_sq + 1),
+    )
+}
+
+impl<P: Vertex> Dimensions for Circle<P> {
     fn top_left(&self) -> Point {
-        let radius_coord = Point::new(self.radius as i32, self.radius as i32);
+        let extent = ((self.radius as i32) << SUBPIXEL_BITS) + SUBPIXEL_ONE / 2;
 
-        self.center - radius_coord
+        (self.center.into() - SubpixelPoint::new(extent, extent)).ceil()
     }
 
     fn bottom_right(&self) -> Point {
-        self.top_left() + self.size()
+        let extent = ((self.radius as i32) << SUBPIXEL_BITS) + SUBPIXEL_ONE / 2;
+
+        (self.center.into() + SubpixelPoint::new(extent, extent)).floor()
     }
 
     fn size(&self) -> Size {
-        Size::new(self.radius * 2, self.radius * 2)
+        let size = self.bottom_right() - self.top_left();
+
+        Size::new(size.x as u32, size.y as u32)
+    }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::with_corners(self.top_left(), self.bottom_right())
     }
 }
 
-impl<C> WithStyle<C> for Circle<C>
+impl<C, P> Styled<Circle<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
-    fn style(mut self, style: Style<C>) -> Self {
-        self.style = style;
-
-        self
-    }
-
-    fn stroke_color(mut self, color: Option<C>) -> Self {
-        self.style.stroke_color = color;
-
-        self
+    /// Returns an iterator over the pixels of the circle that lie inside the clip rectangle.
+    ///
+    /// Pixels outside the clip rectangle are skipped without being visited, which makes drawing
+    /// large, mostly offscreen circles fast.
+    pub fn clipped_iter(&self, clip: &Rect) -> CircleIterator<C> {
+        self.into_iter().clip(clip)
     }
 
-    fn stroke_width(mut self, width: u32) -> Self {
-        self.style.stroke_width = width;
-
-        self
+    /// Returns an iterator over the horizontal spans of the circle.
+    ///
+    /// Each span is a run of pixels with the same color in a single row, which can be drawn with
+    /// [`DrawTarget::draw_hline_spans`].
+    ///
+    /// [`DrawTarget::draw_hline_spans`]: ../../trait.DrawTarget.html#method.draw_hline_spans
+    pub fn spans(&self) -> CircleSpans<C> {
+        CircleSpans::new(self.into_iter())
     }
 
-    fn fill_color(mut self, color: Option<C>) -> Self {
-        self.style.fill_color = color;
-
-        self
+    /// Returns an iterator over the horizontal spans of the circle that lie inside the clip
+    /// rectangle.
+    pub fn clipped_spans(&self, clip: &Rect) -> CircleSpans<C> {
+        CircleSpans::new(self.clipped_iter(clip))
     }
 }
 
-impl<C> IntoIterator for Circle<C>
+impl<C, P> IntoIterator for Styled<Circle<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
     type Item = Pixel<C>;
     type IntoIter = CircleIterator<C>;
@@ -129,19 +212,27 @@ where
     }
 }
 
-impl<'a, C> IntoIterator for &'a Circle<C>
+impl<'a, C, P> IntoIterator for &'a Styled<Circle<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
     type Item = Pixel<C>;
     type IntoIter = CircleIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let top_left = self.primitive.top_left();
+        let bottom_right = self.primitive.bottom_right();
+
         CircleIterator {
-            center: self.center,
-            radius: self.radius,
+            center: self.primitive.center.into(),
+            radius: self.primitive.radius,
             style: self.style,
-            p: Point::new(-(self.radius as i32), -(self.radius as i32)),
+            top_left,
+            p: top_left,
+            x_start: top_left.x,
+            x_end: bottom_right.x,
+            y_end: bottom_right.y,
         }
     }
 }
@@ -149,10 +240,99 @@ where
 /// Pixel iterator for each pixel in the circle border
 #[derive(Debug, Copy, Clone)]
 pub struct CircleIterator<C: PixelColor> {
-    center: Point,
+    center: SubpixelPoint,
     radius: u32,
-    style: Style<C>,
+    style: PrimitiveStyle<C>,
+    /// Top left corner of the bounding box, used to position fill patterns
+    top_left: Point,
+    /// Current point
     p: Point,
+    /// First column to iterate over
+    x_start: i32,
+    /// Last column to iterate over
+    x_end: i32,
+    /// Last row to iterate over
+    y_end: i32,
+}
+
+impl<C> CircleIterator<C>
+where
+    C: PixelColor,
+{
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    fn clip(mut self, clip: &Rect) -> Self {
+        if let Some(clip_bottom_right) = clip.bottom_right() {
+            self.x_start = self.x_start.max(clip.top_left.x);
+            self.x_end = self.x_end.min(clip_bottom_right.x);
+            self.y_end = self.y_end.min(clip_bottom_right.y);
+            self.p = Point::new(self.x_start, self.p.y.max(clip.top_left.y));
+        } else {
+            self.x_end = self.x_start - 1;
+        }
+
+        self
+    }
+
+    /// Returns the first and last column of the pixels in a row whose squared distance to the
+    /// center is less than or equal to `threshold`.
+    ///
+    /// `t_y` is the vertical offset of the row to the center in sub-pixel units.
+    fn row_extent(&self, t_y: i32, threshold: i64) -> (i64, i64) {
+        let remaining = threshold.saturating_sub(i64::from(t_y) * i64::from(t_y));
+        if remaining < 0 {
+            return (1, 0);
+        }
+
+        let offset = isqrt(remaining as u64) as i64;
+        let center_x = i64::from(self.center.x);
+        let one = i64::from(SUBPIXEL_ONE);
+
+        (
+            -(offset - center_x).div_euclid(one),
+            (center_x + offset).div_euclid(one),
+        )
+    }
+
+    /// Returns the spans of a row.
+    ///
+    /// The border and the fill are bounded by circles, so a row changes its area only at the
+    /// columns where it crosses one of them.
+    fn row(&self, y: i32) -> RowSpans<C> {
+        let outer_radius = self.radius as i32;
+        let stroke_width = self.style.stroke_width_i32();
+        let has_stroke = self.style.stroke_color.is_some();
+        let center = self.center;
+
+        let t_y = (SubpixelPoint::from(Point::new(0, y)) - center).y;
+        let (inner, outer, fill) = thresholds(outer_radius, stroke_width);
+
+        let mut breakpoints = [0; 6];
+        for (columns, &threshold) in breakpoints
+            .chunks_mut(2)
+            .zip([inner, outer.saturating_sub(1), fill].iter())
+        {
+            let (start, end) = self.row_extent(t_y, threshold);
+            columns[0] = start;
+            columns[1] = end + 1;
+        }
+
+        RowSpans::new(
+            y,
+            (self.x_start, self.x_end),
+            &breakpoints,
+            |x| {
+                let t = SubpixelPoint::from(Point::new(x, y)) - center;
+
+                match classify(t, outer_radius, stroke_width) {
+                    (true, _) if has_stroke => Area::Stroke,
+                    (_, true) => Area::Fill,
+                    _ => Area::Outside,
+                }
+            },
+            self.style,
+            self.top_left,
+        )
+    }
 }
 
 impl<C> Iterator for CircleIterator<C>
@@ -168,45 +348,38 @@ where
             return None;
         }
 
-        let radius = self.radius as i32 - self.style.stroke_width_i32() + 1;
         let outer_radius = self.radius as i32;
 
-        let radius_sq = radius * radius;
-        let outer_radius_sq = outer_radius * outer_radius;
-
         loop {
-            let t = self.p;
-            let len = t.x * t.x + t.y * t.y;
+            if self.p.y > self.y_end || self.x_start > self.x_end {
+                break None;
+            }
 
-            let is_border = len > radius_sq - radius && len < outer_radius_sq + radius;
+            let p = self.p;
+            let t = SubpixelPoint::from(p) - self.center;
 
-            let is_fill = len <= outer_radius_sq + 1;
+            let (is_border, is_fill) = classify(t, outer_radius, self.style.stroke_width_i32());
 
             let item = if is_border && self.style.stroke_color.is_some() {
                 Some(Pixel(
-                    self.center + t,
+                    p,
                     self.style.stroke_color.expect("Border color not defined"),
                 ))
-            } else if is_fill && self.style.fill_color.is_some() {
-                Some(Pixel(
-                    self.center + t,
-                    self.style.fill_color.expect("Fill color not defined"),
-                ))
+            } else if is_fill {
+                self.style
+                    .fill_color_at(p, self.top_left)
+                    .map(|fill| Pixel(p, fill))
             } else {
                 None
             };
 
             self.p.x += 1;
 
-            if self.p.x > self.radius as i32 {
-                self.p.x = -(self.radius as i32);
+            if self.p.x > self.x_end {
+                self.p.x = self.x_start;
                 self.p.y += 1;
             }
 
-            if self.p.y > self.radius as i32 {
-                break None;
-            }
-
             if item.is_some() {
                 break item;
             }
@@ -214,7 +387,60 @@ where
     }
 }
 
-impl<'a, C: 'a> Drawable<C> for &Circle<C>
+/// Span iterator for the rows of a circle
+///
+/// The rows are split at the columns where they cross the edges of the border and the fill,
+/// without checking every pixel.
+#[derive(Debug, Clone, Copy)]
+pub struct CircleSpans<C>
+where
+    C: PixelColor,
+{
+    iter: CircleIterator<C>,
+    row: RowSpans<C>,
+}
+
+impl<C> CircleSpans<C>
+where
+    C: PixelColor,
+{
+    fn new(iter: CircleIterator<C>) -> Self {
+        Self {
+            iter,
+            row: RowSpans::empty(iter.style),
+        }
+    }
+}
+
+impl<C> Iterator for CircleSpans<C>
+where
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.iter;
+
+        if iter.style.stroke_color.is_none() && iter.style.fill_color.is_none() {
+            return None;
+        }
+
+        loop {
+            if let Some(span) = self.row.next() {
+                break Some(span);
+            }
+
+            if iter.p.y > iter.y_end || iter.x_start > iter.x_end {
+                break None;
+            }
+
+            self.row = iter.row(iter.p.y);
+            iter.p.y += 1;
+        }
+    }
+}
+
+impl<'a, C: 'a> Drawable<C> for &Styled<Circle, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
@@ -223,22 +449,24 @@ where
     }
 }
 
-impl<C> Transform for Circle<C>
+impl<'a, C: 'a> Drawable<C> for &Styled<Circle<SubpixelPoint>, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
+    fn draw<T: DrawTarget<C>>(self, display: &mut T) {
+        display.draw_hline_spans(self.clipped_spans(&display.display_area()));
+    }
+}
+
+impl<P: Vertex> Transform for Circle<P> {
     /// Translate the circle center from its current position to a new position by (x, y) pixels,
     /// returning a new `Circle`. For a mutating transform, see `translate_mut`.
     ///
     /// ```
     /// # use embedded_graphics::primitives::Circle;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::Rgb565;
-    /// #
-    /// # let style = Style::stroke_color(Rgb565::RED);
     /// #
-    /// let circle = Circle::new(Point::new(5, 10), 10)
-    /// #    .style(style);
+    /// let circle = Circle::new(Point::new(5, 10), 10);
     /// let moved = circle.translate(Point::new(10, 10));
     ///
     /// assert_eq!(moved.center, Point::new(15, 20));
@@ -255,12 +483,8 @@ where
     /// ```
     /// # use embedded_graphics::primitives::Circle;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::Rgb565;
     /// #
-    /// # let style = Style::stroke_color(Rgb565::RED);
-    /// #
-    /// let mut circle = Circle::new(Point::new(5, 10), 10)
-    /// #    .style(style);
+    /// let mut circle = Circle::new(Point::new(5, 10), 10);
     /// circle.translate_mut(Point::new(10, 10));
     ///
     /// assert_eq!(circle.center, Point::new(15, 20));
@@ -272,19 +496,69 @@ where
     }
 }
 
+impl<P: Vertex> Rotate for Circle<P> {
+    type Output = Circle<P>;
+
+    /// Rotates the center of the circle around `center`.
+    fn rotate(&self, center: Point, angle: Angle) -> Circle<P> {
+        Circle::new(self.center.rotate(center, angle), self.radius)
+    }
+}
+
+impl<P: Vertex> Scale for Circle<P> {
+    type Output = Circle<P>;
+
+    /// Scales the circle relative to `center`.
+    ///
+    /// The center is moved relative to `center` and the radius is scaled by the same factor and
+    /// rounded to the nearest pixel.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Circle;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::Scale;
+    ///
+    /// let circle = Circle::new(Point::new(20, 10), 5);
+    /// let scaled = circle.scale(Point::new(10, 10), 3, 2);
+    ///
+    /// assert_eq!(scaled, Circle::new(Point::new(25, 10), 8));
+    /// ```
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Circle<P> {
+        let center = self.center.scale(center, numerator, denominator);
+
+        let numerator = u64::from(numerator);
+        let denominator = u64::from(denominator);
+        let radius = (u64::from(self.radius) * numerator + denominator / 2) / denominator;
+
+        Circle::new(center, radius as u32)
+    }
+}
+
+impl<P: Vertex> Mirror for Circle<P> {
+    type Output = Circle<P>;
+
+    /// Mirrors the center of the circle across `axis`.
+    fn mirror(&self, axis: Axis) -> Circle<P> {
+        Circle::new(self.center.mirror(axis), self.radius)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_display::{assert_clipped_iter, assert_contains_drawn_points, MockDisplay};
     use crate::pixelcolor::BinaryColor;
+    use crate::span::Spans;
+    use crate::style::Pattern;
 
     /// Test for issue #143
     #[test]
     fn issue_143_stroke_and_fill() {
-        let circle_no_stroke: Circle<BinaryColor> =
-            Circle::new(Point::new(10, 16), 3).fill_color(Some(BinaryColor::On));
-        let circle_stroke: Circle<BinaryColor> = Circle::new(Point::new(10, 16), 3)
-            .fill_color(Some(BinaryColor::On))
-            .stroke_color(Some(BinaryColor::On));
+        let circle_no_stroke = Circle::new(Point::new(10, 16), 3)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+        let circle_stroke = Circle::new(Point::new(10, 16), 3).into_styled(
+            PrimitiveStyle::with_fill(BinaryColor::On).stroke_color(Some(BinaryColor::On)),
+        );
 
         assert_eq!(circle_stroke.size(), circle_no_stroke.size());
         assert!(circle_no_stroke.into_iter().eq(circle_stroke.into_iter()));
@@ -292,7 +566,7 @@ mod tests {
 
     #[test]
     fn negative_dimensions() {
-        let circ: Circle<BinaryColor> = Circle::new(Point::new(-10, -10), 5);
+        let circ = Circle::new(Point::new(-10, -10), 5);
 
         assert_eq!(circ.top_left(), Point::new(-15, -15));
         assert_eq!(circ.bottom_right(), Point::new(-5, -5));
@@ -301,7 +575,7 @@ mod tests {
 
     #[test]
     fn dimensions() {
-        let circ: Circle<BinaryColor> = Circle::new(Point::new(10, 20), 5);
+        let circ = Circle::new(Point::new(10, 20), 5);
 
         assert_eq!(circ.top_left(), Point::new(5, 15));
         assert_eq!(circ.bottom_right(), Point::new(15, 25));
@@ -310,7 +584,7 @@ mod tests {
 
     #[test]
     fn large_radius() {
-        let circ: Circle<BinaryColor> = Circle::new(Point::new(5, 5), 10);
+        let circ = Circle::new(Point::new(5, 5), 10);
 
         assert_eq!(circ.top_left(), Point::new(-5, -5));
         assert_eq!(circ.bottom_right(), Point::new(15, 15));
@@ -319,25 +593,148 @@ mod tests {
 
     #[test]
     fn transparent_border() {
-        let circ: Circle<BinaryColor> = Circle::new(Point::new(5, 5), 10)
-            .stroke_color(None)
-            .fill_color(Some(BinaryColor::On));
+        let circ = Circle::new(Point::new(5, 5), 10)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On).stroke_color(None));
 
         assert!(circ.into_iter().count() > 0);
     }
 
     #[test]
     fn it_handles_negative_coordinates() {
-        let positive: CircleIterator<BinaryColor> = Circle::new(Point::new(10, 10), 5)
-            .style(Style::stroke_color(BinaryColor::On))
+        let positive = Circle::new(Point::new(10, 10), 5)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
             .into_iter();
 
-        let negative: CircleIterator<BinaryColor> = Circle::new(Point::new(-10, -10), 5)
-            .style(Style::stroke_color(BinaryColor::On))
+        let negative = Circle::new(Point::new(-10, -10), 5)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
             .into_iter();
 
         assert!(negative.into_iter().eq(positive
             .into_iter()
             .map(|Pixel(p, c)| Pixel(p - Point::new(20, 20), c))));
     }
+
+    #[test]
+    fn contains() {
+        for &(radius, stroke_width) in &[(0, 1), (1, 1), (5, 1), (10, 3), (7, 0), (4, 20)] {
+            let circle = Circle::new(Point::new(15, 15), radius).into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)
+                    .fill_color(Some(BinaryColor::Off)),
+            );
+
+            assert_contains_drawn_points(&circle);
+
+            if stroke_width == 1 {
+                for point in (0..32).flat_map(|y| (0..32).map(move |x| Point::new(x, y))) {
+                    assert_eq!(circle.primitive.contains(point), circle.contains(point));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let circle = Circle::new(Point::new(10, 10), 5)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        let bounding_box = circle.bounding_box();
+        assert_eq!(bounding_box, Rect::new(Point::new(5, 5), Size::new(11, 11)));
+        assert!(circle
+            .into_iter()
+            .all(|Pixel(p, _)| bounding_box.contains(p)));
+    }
+
+    #[test]
+    fn clipped_iter() {
+        let shapes = [
+            Circle::new(Point::new(8, 8), 6),
+            Circle::new(Point::new(-2, 3), 12),
+            Circle::new(Point::new(12, 4), 0),
+        ];
+
+        for shape in shapes.iter() {
+            let shape = shape.into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 2).fill_color(Some(BinaryColor::Off)),
+            );
+
+            assert_clipped_iter(&shape, |clip| shape.clipped_iter(clip));
+        }
+    }
+
+    #[test]
+    fn spans_cover_pixels() {
+        let circle = Circle::new(Point::new(8, 8), 6).into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off)),
+        );
+        let clip = Rect::new(Point::new(4, 0), Size::new(8, 16));
+
+        assert!(circle
+            .clipped_spans(&clip)
+            .flat_map(|span| span.pixels())
+            .eq(circle.clipped_iter(&clip)));
+    }
+
+    #[test]
+    fn spans_are_row_segments() {
+        let center = SubpixelPoint::new(10 * 256 + 100, 9 * 256 + 30);
+
+        for radius in 0..8 {
+            for stroke_width in 0..5 {
+                for &pattern in [None, Some(Pattern::CHECKERBOARD)].iter() {
+                    let circle = Circle::new(center, radius).into_styled(
+                        PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)
+                            .fill_color(Some(BinaryColor::Off))
+                            .fill_pattern(pattern),
+                    );
+
+                    assert!(circle.spans().eq(Spans::new(&circle)), "{:?}", circle);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subpixel_integer_center() {
+        for stroke_width in 0..4 {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)
+                .fill_color(Some(BinaryColor::Off));
+
+            let circle = Circle::new(Point::new(12, 9), 7);
+            let subpixel_circle = Circle::new(SubpixelPoint::from(circle.center), circle.radius);
+
+            assert_eq!(subpixel_circle.bounding_box(), circle.bounding_box());
+            assert!(subpixel_circle
+                .into_styled(style)
+                .into_iter()
+                .eq(circle.into_styled(style).into_iter()));
+        }
+    }
+
+    #[test]
+    fn subpixel_center() {
+        // Centered between the pixels (10, 10) and (11, 11)
+        let circle = Circle::new(SubpixelPoint::new(10 * 256 + 128, 10 * 256 + 128), 3);
+        let styled = circle.into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        assert_eq!(circle.top_left(), Point::new(7, 7));
+        assert_eq!(circle.bottom_right(), Point::new(14, 14));
+
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display);
+
+        // The circle is symmetric around its center
+        for Pixel(p, _) in styled.into_iter() {
+            for &mirrored in [
+                Point::new(21 - p.x, p.y),
+                Point::new(p.x, 21 - p.y),
+                Point::new(p.y, p.x),
+            ]
+            .iter()
+            {
+                assert!(display.get_pixel(mirrored).is_some(), "{:?}", mirrored);
+            }
+        }
+
+        assert_contains_drawn_points(&styled);
+    }
 }
@@ -0,0 +1,476 @@
+//! Edge functions used to rasterize triangles and other convex polygons.
+
+use crate::drawable::Pixel;
+use crate::geometry::{Point, Rect, SubpixelPoint, Vertex, SUBPIXEL_BITS};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::line::{Line, LineIterator};
+use crate::primitives::{isqrt, Primitive};
+use crate::span::{Area, RowSpans, Span, Spans};
+use crate::style::PrimitiveStyle;
+
+/// Maximum number of edges of a polygon drawn by an [`EdgeIterator`].
+///
+/// [`EdgeIterator`]: struct.EdgeIterator.html
+const MAX_EDGES: usize = 4;
+
+/// Edge of a convex polygon, used to test on which side of the edge a point lies.
+///
+/// Edges are oriented so that the inside of the polygon lies on the positive side. The vertices
+/// are stored with sub-pixel precision, while the tested points are always pixel centers.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Edge {
+    start: SubpixelPoint,
+    delta: SubpixelPoint,
+    /// `1` if this is a top or left edge, `0` otherwise.
+    bias: i64,
+    /// Squared length of the edge in sub-pixel units.
+    length_sq: i64,
+}
+
+impl Edge {
+    pub(super) fn new(start: SubpixelPoint, end: SubpixelPoint) -> Self {
+        let delta = end - start;
+
+        // The polygon is wound clockwise on screen, so top edges point to the right and left
+        // edges point upwards.
+        let is_top_left = (delta.y == 0 && delta.x > 0) || delta.y < 0;
+
+        Self {
+            start,
+            delta,
+            bias: if is_top_left { 1 } else { 0 },
+            length_sq: i64::from(delta.x) * i64::from(delta.x)
+                + i64::from(delta.y) * i64::from(delta.y),
+        }
+    }
+
+    /// Returns the edge function for a point, which is the distance of the point to the edge
+    /// multiplied by the length of the edge, in squared sub-pixel units.
+    pub(super) fn value(&self, point: SubpixelPoint) -> i64 {
+        i64::from(self.delta.x) * (i64::from(point.y) - i64::from(self.start.y))
+            - i64::from(self.delta.y) * (i64::from(point.x) - i64::from(self.start.x))
+    }
+
+    /// Returns `true` if the point is on the inside of this edge according to the top-left fill
+    /// rule.
+    pub(super) fn contains(&self, point: Point) -> bool {
+        self.value(point.into()) + self.bias > 0
+    }
+
+    /// Restricts the inclusive range `x_min..=x_max` to the points in row `y` that lie inside this
+    /// edge according to the top-left fill rule.
+    fn clamp_row(&self, y: i32, x_min: &mut i64, x_max: &mut i64) {
+        // A point is inside if `value + bias > 0`, which is `dy * x < c` for the given row. `x`
+        // is a whole pixel, so `dy` is scaled to sub-pixel units.
+        let dx = i64::from(self.delta.x);
+        let dy = i64::from(self.delta.y) << SUBPIXEL_BITS;
+        let y = i64::from(y) << SUBPIXEL_BITS;
+        let c = dx * (y - i64::from(self.start.y))
+            + i64::from(self.delta.y) * i64::from(self.start.x)
+            + self.bias;
+
+        if dy > 0 {
+            *x_max = (*x_max).min((c - 1).div_euclid(dy));
+        } else if dy < 0 {
+            *x_min = (*x_min).max((-c).div_euclid(-dy) + 1);
+        } else if c <= 0 {
+            // Horizontal edge with the whole row on the outside
+            *x_max = *x_min - 1;
+        }
+    }
+
+    /// Returns `true` if an inside point is closer than `width` pixels to the edge.
+    ///
+    /// Points on bottom and right edges are outside of the polygon, unless the edges were closed
+    /// with [`close_stroked`], which is why the stroke for these edges starts one step further
+    /// inside.
+    ///
+    /// [`close_stroked`]: fn.close_stroked.html
+    fn is_stroke(&self, point: Point, width: i64) -> bool {
+        let value = self.value(point.into());
+
+        // The width is scaled to sub-pixel units, like the value and length of the edge
+        let width = width << SUBPIXEL_BITS;
+        let threshold = width
+            .saturating_mul(width)
+            .saturating_mul(self.length_sq)
+            .saturating_add(1 - self.bias);
+
+        value.saturating_mul(value) < threshold
+    }
+
+    /// Returns the first and last column in row `y` which is closer than `width` pixels to the
+    /// edge according to [`is_stroke`].
+    ///
+    /// Points on both sides of the edge are included, so the result needs to be restricted to the
+    /// inside of the polygon.
+    ///
+    /// [`is_stroke`]: #method.is_stroke
+    fn stroke_columns(&self, y: i32, width: i64) -> (i64, i64) {
+        let width = width << SUBPIXEL_BITS;
+        let threshold = width
+            .saturating_mul(width)
+            .saturating_mul(self.length_sq)
+            .saturating_add(1 - self.bias);
+
+        if threshold <= 0 {
+            return (1, 0);
+        }
+
+        // The stroke contains the points with `|value| <= max_value`. The value changes by `step`
+        // for every column.
+        let max_value = isqrt((threshold - 1) as u64) as i64;
+        let value = self.value(Point::new(0, y).into());
+        let step = -i64::from(self.delta.y) << SUBPIXEL_BITS;
+
+        let low = (-max_value).saturating_sub(value);
+        let high = max_value.saturating_sub(value);
+
+        if step == 0 {
+            if low <= 0 && high >= 0 {
+                (i64::min_value(), i64::max_value())
+            } else {
+                (1, 0)
+            }
+        } else {
+            let (low, high, step) = if step > 0 {
+                (low, high, step)
+            } else {
+                (high.saturating_neg(), low.saturating_neg(), -step)
+            };
+
+            (
+                -low.saturating_neg().div_euclid(step),
+                high.div_euclid(step),
+            )
+        }
+    }
+}
+
+/// Makes the edges contain the points which lie exactly on them if the style draws a stroke.
+///
+/// This is used for polygons whose vertices are pixel centers, like triangles. The top-left fill
+/// rule only applies to their fill, because the stroke should reach all vertices.
+pub(super) fn close_stroked<C>(edges: &mut [Edge], style: &PrimitiveStyle<C>)
+where
+    C: PixelColor,
+{
+    if style.stroke_width > 0 && style.stroke_color.is_some() {
+        for edge in edges.iter_mut() {
+            edge.bias = 1;
+        }
+    }
+}
+
+/// Returns the first and last X coordinate inside all edges for a row.
+///
+/// The result is restricted to the range `x_min..=x_max`. If no point in the row is inside, the
+/// returned range is empty.
+pub(super) fn row_span(edges: &[Edge], y: i32, x_min: i32, x_max: i32) -> (i32, i32) {
+    let mut x_min = i64::from(x_min);
+    let mut x_max = i64::from(x_max);
+
+    for edge in edges.iter() {
+        edge.clamp_row(y, &mut x_min, &mut x_max);
+    }
+
+    if x_min > x_max {
+        return (1, 0);
+    }
+
+    // The range starts out as the clip range, so it always fits into an `i32`
+    (x_min as i32, x_max as i32)
+}
+
+/// Returns `true` if an inside point is part of a stroke which is drawn `width` pixels wide on
+/// the inside of the edges.
+pub(super) fn is_stroke(edges: &[Edge], point: Point, width: u32) -> bool {
+    let width = i64::from(width);
+
+    width > 0 && edges.iter().any(|edge| edge.is_stroke(point, width))
+}
+
+/// Returns the columns in row `y` at which a stroke which is drawn `width` pixels wide on the
+/// inside of the edges can start or end.
+fn stroke_breakpoints(edges: &[Edge], y: i32, width: u32) -> [i64; 2 * MAX_EDGES] {
+    let mut breakpoints = [0; 2 * MAX_EDGES];
+
+    for (columns, edge) in breakpoints.chunks_mut(2).zip(edges.iter()) {
+        let (start, end) = edge.stroke_columns(y, i64::from(width));
+        columns[0] = start;
+        columns[1] = end.saturating_add(1);
+    }
+
+    breakpoints
+}
+
+/// Returns the pair of points which spans the bounding box of all points.
+///
+/// This is used to find the line a polygon without an area is drawn as. Only the given pairs of
+/// indices are checked and the first pair is returned if none of them spans the bounding box.
+pub(super) fn outermost_pair(points: &[SubpixelPoint], pairs: &[(usize, usize)]) -> (usize, usize) {
+    let min_x = points.iter().map(|p| p.x).min().unwrap();
+    let min_y = points.iter().map(|p| p.y).min().unwrap();
+    let max_x = points.iter().map(|p| p.x).max().unwrap();
+    let max_y = points.iter().map(|p| p.y).max().unwrap();
+
+    pairs
+        .iter()
+        .cloned()
+        .find(|&(a, b)| {
+            let (a, b) = (points[a], points[b]);
+
+            a.x.min(b.x) == min_x
+                && a.x.max(b.x) == max_x
+                && a.y.min(b.y) == min_y
+                && a.y.max(b.y) == max_y
+        })
+        .unwrap_or(pairs[0])
+}
+
+/// Pixel iterator for a convex polygon, which is used by triangles and quads.
+///
+/// The polygon is rasterized row by row according to the top-left fill rule. Polygons without an
+/// area are drawn as a line instead.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct EdgeIterator<C>
+where
+    C: PixelColor,
+{
+    edges: [Edge; MAX_EDGES],
+    edge_count: usize,
+    p: Point,
+    x_end: i32,
+    /// First column inside the clip rectangle
+    x_min: i32,
+    /// Last column inside the clip rectangle
+    x_max: i32,
+    max_y: i32,
+    top_left: Point,
+    style: PrimitiveStyle<C>,
+    line: Option<LineIterator<C>>,
+}
+
+impl<C> EdgeIterator<C>
+where
+    C: PixelColor,
+{
+    /// Creates an iterator for the polygon with the given edges and bounding box.
+    pub(super) fn new(
+        edges: &[Edge],
+        top_left: Point,
+        bottom_right: Point,
+        style: PrimitiveStyle<C>,
+    ) -> Self {
+        let mut polygon_edges = [edges[0]; MAX_EDGES];
+        polygon_edges[..edges.len()].copy_from_slice(edges);
+
+        Self {
+            edges: polygon_edges,
+            edge_count: edges.len(),
+            p: Point::new(0, top_left.y - 1),
+            x_end: -1,
+            x_min: i32::min_value(),
+            x_max: i32::max_value(),
+            max_y: bottom_right.y,
+            top_left,
+            style,
+            line: None,
+        }
+    }
+
+    /// Creates an iterator for a polygon without an area, which is drawn as `line`.
+    ///
+    /// The line is drawn using the stroke color, unless the stroke width is zero.
+    pub(super) fn with_line<P>(line: Line<P>, style: PrimitiveStyle<C>) -> Self
+    where
+        P: Vertex,
+    {
+        let color = if style.stroke_width > 0 {
+            style.stroke_color
+        } else {
+            None
+        };
+
+        let start = line.start.into();
+        let line = line
+            .into_styled(PrimitiveStyle::default().stroke_color(color))
+            .into_iter();
+
+        Self {
+            edge_count: 0,
+            line: Some(line),
+            ..Self::new(
+                &[Edge::new(start, start)],
+                Point::zero(),
+                Point::zero(),
+                style,
+            )
+        }
+    }
+
+    fn edges(&self) -> &[Edge] {
+        &self.edges[..self.edge_count]
+    }
+
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    pub(super) fn clip(mut self, clip: &Rect) -> Self {
+        self.line = self.line.map(|line| line.clip(clip));
+
+        if let Some(clip_bottom_right) = clip.bottom_right() {
+            self.x_min = self.x_min.max(clip.top_left.x);
+            self.x_max = self.x_max.min(clip_bottom_right.x);
+            self.max_y = self.max_y.min(clip_bottom_right.y);
+            self.p.y = self.p.y.max(clip.top_left.y.saturating_sub(1));
+        } else {
+            self.max_y = self.p.y;
+        }
+
+        self
+    }
+
+    /// Returns the first and last X coordinate inside the polygon for a row.
+    fn row_span(&self, y: i32) -> (i32, i32) {
+        row_span(self.edges(), y, self.x_min, self.x_max)
+    }
+
+    fn is_stroke(&self, point: Point) -> bool {
+        is_stroke(self.edges(), point, self.style.stroke_width)
+    }
+
+    /// Returns the spans of a row.
+    ///
+    /// The row is restricted to the inside of the polygon and split at the columns where the
+    /// stroke along each edge starts or ends.
+    fn row(&self, y: i32) -> RowSpans<C> {
+        let has_stroke = self.style.stroke_color.is_some() && self.style.stroke_width > 0;
+        let breakpoints = stroke_breakpoints(self.edges(), y, self.style.stroke_width);
+        let breakpoints = if has_stroke {
+            &breakpoints[..2 * self.edge_count]
+        } else {
+            &[]
+        };
+
+        RowSpans::new(
+            y,
+            self.row_span(y),
+            breakpoints,
+            |x| {
+                if has_stroke && self.is_stroke(Point::new(x, y)) {
+                    Area::Stroke
+                } else {
+                    Area::Fill
+                }
+            },
+            self.style,
+            self.top_left,
+        )
+    }
+}
+
+impl<C> Iterator for EdgeIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(line) = &mut self.line {
+            return line.next();
+        }
+
+        if self.style.stroke_color.is_none() && self.style.fill_color.is_none() {
+            return None;
+        }
+
+        loop {
+            // Reached the end of the row, move on to the next one
+            if self.p.x > self.x_end {
+                self.p.y += 1;
+
+                if self.p.y > self.max_y {
+                    break None;
+                }
+
+                let (x_start, x_end) = self.row_span(self.p.y);
+                self.p.x = x_start;
+                self.x_end = x_end;
+
+                continue;
+            }
+
+            let point = self.p;
+            self.p.x += 1;
+
+            let color = match self.style.stroke_color {
+                Some(stroke_color) if self.is_stroke(point) => Some(stroke_color),
+                _ => self.style.fill_color_at(point, self.top_left),
+            };
+
+            if let Some(color) = color {
+                break Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+/// Span iterator for the rows of a convex polygon
+///
+/// The rows are restricted to the inside of the polygon and split into stroke and fill segments,
+/// without checking every pixel. The pixels of polygons which are drawn as a line are merged
+/// into spans.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct EdgeSpans<C>
+where
+    C: PixelColor,
+{
+    iter: EdgeIterator<C>,
+    row: RowSpans<C>,
+    line: Option<Spans<LineIterator<C>, C>>,
+}
+
+impl<C> EdgeSpans<C>
+where
+    C: PixelColor,
+{
+    pub(super) fn new(mut iter: EdgeIterator<C>) -> Self {
+        Self {
+            line: iter.line.take().map(Spans::new),
+            row: RowSpans::empty(iter.style),
+            iter,
+        }
+    }
+}
+
+impl<C> Iterator for EdgeSpans<C>
+where
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(line) = &mut self.line {
+            return line.next();
+        }
+
+        let iter = &mut self.iter;
+
+        if iter.style.stroke_color.is_none() && iter.style.fill_color.is_none() {
+            return None;
+        }
+
+        loop {
+            if let Some(span) = self.row.next() {
+                break Some(span);
+            }
+
+            iter.p.y += 1;
+
+            if iter.p.y > iter.max_y {
+                break None;
+            }
+
+            self.row = iter.row(iter.p.y);
+        }
+    }
+}
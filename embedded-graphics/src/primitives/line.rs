@@ -1,16 +1,20 @@
 //! The line primitive
 
 use super::super::drawable::{Drawable, Pixel};
-use super::super::transform::Transform;
-use crate::geometry::{Dimensions, Point, Size};
+use super::super::transform::{Axis, Mirror, Rotate, Scale, Transform};
+use crate::geometry::{
+    Angle, ContainsPoint, Dimensions, Point, Rect, Size, SubpixelPoint, Vertex, SUBPIXEL_BITS,
+};
 use crate::pixelcolor::PixelColor;
 use crate::primitives::Primitive;
-use crate::style::Style;
-use crate::style::WithStyle;
+use crate::style::{PrimitiveStyle, Styled};
 use crate::DrawTarget;
 
 /// Line primitive
 ///
+/// The end points of a line are [`Point`]s by default. [`SubpixelPoint`]s can be used instead to
+/// position the line with sub-pixel precision.
+///
 /// # Examples
 ///
 /// The [macro examples](../../macro.egline.html) make for more concise code.
@@ -21,42 +25,40 @@ use crate::DrawTarget;
 /// use embedded_graphics::prelude::*;
 /// use embedded_graphics::primitives::Line;
 /// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::style::PrimitiveStyle;
 /// # use embedded_graphics::mock_display::MockDisplay;
 /// # let mut display = MockDisplay::default();
 ///
-/// // Default line from (10, 20) to (30, 40)
-/// Line::new(Point::new(10, 20), Point::new(30, 40)).draw(&mut display);
-///
-/// // Line with styled stroke from (50, 20) to (60, 35)
-/// Line::new(Point::new(50, 20), Point::new(60, 35))
-///     .stroke_color(Some(Rgb565::RED))
+/// // Red line from (10, 20) to (30, 40)
+/// Line::new(Point::new(10, 20), Point::new(30, 40))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
 ///     .draw(&mut display);
 ///
 /// // Line with translation applied
 /// Line::new(Point::new(50, 20), Point::new(60, 35))
 ///     .translate(Point::new(65, 35))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::GREEN, 1))
 ///     .draw(&mut display);
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct Line<C: PixelColor> {
+///
+/// [`Point`]: ../../geometry/struct.Point.html
+/// [`SubpixelPoint`]: ../../geometry/struct.SubpixelPoint.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Line<P = Point> {
     /// Start point
-    pub start: Point,
+    pub start: P,
 
     /// End point
-    pub end: Point,
-
-    /// Line style
-    pub style: Style<C>,
+    pub end: P,
 }
 
-impl<C> Primitive for Line<C> where C: PixelColor {}
+impl<P: Vertex> Primitive for Line<P> {}
 
-impl<C> Dimensions for Line<C>
-where
-    C: PixelColor,
-{
+impl<P: Vertex> Dimensions for Line<P> {
     fn top_left(&self) -> Point {
-        Point::new(self.start.x.min(self.end.x), self.start.y.min(self.end.y))
+        let (start, end) = self.pixel_end_points();
+
+        Point::new(start.x.min(end.x), start.y.min(end.y))
     }
 
     fn bottom_right(&self) -> Point {
@@ -64,56 +66,97 @@ where
     }
 
     fn size(&self) -> Size {
-        Size::from_bounding_box(self.start, self.end)
+        let (start, end) = self.pixel_end_points();
+
+        Size::from_bounding_box(start, end)
+    }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::with_corners(self.top_left(), self.bottom_right())
     }
 }
 
-impl<C> Line<C>
-where
-    C: PixelColor,
-{
-    /// Create a new line
-    pub fn new(start: Point, end: Point) -> Self {
-        Line {
-            start,
-            end,
-            style: Style::default(),
-        }
+impl<P: Vertex> ContainsPoint for Line<P> {
+    /// Returns `true` if the point is one of the pixels of the line.
+    ///
+    /// Lines are currently always drawn one pixel wide, regardless of the stroke width.
+    fn contains(&self, point: Point) -> bool {
+        self.points()
+            .clip(&Rect::new(point, Size::new(1, 1)))
+            .next()
+            .is_some()
     }
 }
 
-impl<C> WithStyle<C> for Line<C>
+impl<C, P> ContainsPoint for Styled<Line<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
-    fn style(mut self, style: Style<C>) -> Self {
-        self.style = style;
-
-        self
+    fn contains(&self, point: Point) -> bool {
+        self.primitive.contains(point)
     }
+}
 
-    fn stroke_color(mut self, color: Option<C>) -> Self {
-        self.style.stroke_color = color;
+impl<P> Line<P> {
+    /// Create a new line
+    pub fn new(start: P, end: P) -> Self {
+        Line { start, end }
+    }
+}
 
-        self
+impl<P: Vertex> Line<P> {
+    /// Returns an iterator over the points of the line.
+    ///
+    /// Lines with integer end points are drawn with Bresenham's algorithm, all other lines with
+    /// the sub-pixel algorithm.
+    fn points(&self) -> LinePoints {
+        let start = self.start.into();
+        let end = self.end.into();
+
+        if start.is_integer() && end.is_integer() {
+            LinePoints::Integer(BresenhamPoints::new(start.round(), end.round()))
+        } else {
+            LinePoints::Subpixel(SubpixelLinePoints::new(start, end))
+        }
     }
 
-    fn stroke_width(mut self, width: u32) -> Self {
-        self.style.stroke_width = width;
+    /// Returns the first and the last pixel of the line.
+    fn pixel_end_points(&self) -> (Point, Point) {
+        let start = self.start.into();
+        let end = self.end.into();
 
-        self
-    }
+        if start.is_integer() && end.is_integer() {
+            (start.round(), end.round())
+        } else {
+            let points = SubpixelLinePoints::new(start, end);
 
-    fn fill_color(mut self, color: Option<C>) -> Self {
-        self.style.fill_color = color;
+            (
+                points.point_at(points.major),
+                points.point_at(points.major_end),
+            )
+        }
+    }
+}
 
-        self
+impl<C, P> Styled<Line<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    /// Returns an iterator over the pixels of the line that lie inside the clip rectangle.
+    ///
+    /// The iterator starts at the first visible pixel and stops after the last one, which makes
+    /// drawing long, mostly offscreen lines fast.
+    pub fn clipped_iter(&self, clip: &Rect) -> LineIterator<C> {
+        self.into_iter().clip(clip)
     }
 }
 
-impl<C> IntoIterator for Line<C>
+impl<C, P> IntoIterator for Styled<Line<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
     type Item = Pixel<C>;
     type IntoIter = LineIterator<C>;
@@ -123,12 +166,85 @@ where
     }
 }
 
-impl<'a, C: PixelColor> IntoIterator for &'a Line<C> {
+impl<'a, C, P> IntoIterator for &'a Styled<Line<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
     type Item = Pixel<C>;
     type IntoIter = LineIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut delta = self.end - self.start;
+        LineIterator {
+            stroke_color: self.style.stroke_color,
+            points: self.primitive.points(),
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in the line
+#[derive(Debug, Clone, Copy)]
+pub struct LineIterator<C>
+where
+    C: PixelColor,
+{
+    stroke_color: Option<C>,
+    points: LinePoints,
+}
+
+impl<C: PixelColor> LineIterator<C> {
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    pub(crate) fn clip(mut self, clip: &Rect) -> Self {
+        self.points = self.points.clip(clip);
+
+        self
+    }
+}
+
+/// Iterator over the points of a line
+#[derive(Debug, Clone, Copy)]
+enum LinePoints {
+    Integer(BresenhamPoints),
+    Subpixel(SubpixelLinePoints),
+}
+
+impl LinePoints {
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    fn clip(self, clip: &Rect) -> Self {
+        match self {
+            LinePoints::Integer(points) => LinePoints::Integer(points.clip(clip)),
+            LinePoints::Subpixel(points) => LinePoints::Subpixel(points.clip(clip)),
+        }
+    }
+}
+
+impl Iterator for LinePoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            LinePoints::Integer(points) => points.next(),
+            LinePoints::Subpixel(points) => points.next(),
+        }
+    }
+}
+
+/// Iterator over the points of a line with integer end points
+#[derive(Debug, Clone, Copy)]
+struct BresenhamPoints {
+    start: Point,
+    end: Point,
+    delta: Point,
+    /// in which quadrant is the line drawn (upper-left=(-1, -1), lower-right=(1, 1), ...)
+    direction: Point,
+    err: i32,
+    stop: bool,
+}
+
+// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm)
+impl BresenhamPoints {
+    fn new(start: Point, end: Point) -> Self {
+        let mut delta = end - start;
         if delta.x < 0 {
             delta = Point::new(-delta.x, delta.y);
         }
@@ -136,51 +252,145 @@ impl<'a, C: PixelColor> IntoIterator for &'a Line<C> {
             delta = Point::new(delta.x, -delta.y);
         }
 
-        let direction = match (self.start.x >= self.end.x, self.start.y >= self.end.y) {
+        let direction = match (start.x >= end.x, start.y >= end.y) {
             (false, false) => Point::new(1, 1),
             (false, true) => Point::new(1, -1),
             (true, false) => Point::new(-1, 1),
             (true, true) => Point::new(-1, -1),
         };
 
-        LineIterator {
-            style: self.style,
-
-            start: self.start,
-            end: self.end,
+        Self {
+            start,
+            end,
             delta,
             direction,
             err: delta.x + delta.y,
-            stop: self.start == self.end, // if line length is zero, draw nothing
+            stop: start == end, // if line length is zero, draw nothing
+        }
+    }
+
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    ///
+    /// Instead of testing each pixel against the clip rectangle, the iterator skips directly to
+    /// the first visible pixel and stops after the last one. The offset of a pixel along the minor
+    /// axis of the line only depends on its offset along the major axis, which is used to find
+    /// the visible range and the error term at its start. The clipped iterator returns exactly the
+    /// same pixels as the unclipped one.
+    fn clip(mut self, clip: &Rect) -> Self {
+        if self.stop {
+            return self;
+        }
+
+        let clip_bottom_right = match clip.bottom_right() {
+            Some(point) => point,
+            None => {
+                self.stop = true;
+                return self;
+            }
+        };
+
+        let delta_x = i64::from(self.delta.x);
+        let delta_y = i64::from(self.delta.y);
+
+        // `delta.y` is never positive
+        let x_major = delta_x >= -delta_y;
+        let (major_len, minor_len) = if x_major {
+            (delta_x, -delta_y)
+        } else {
+            (-delta_y, delta_x)
+        };
+
+        // Offset along the minor axis after `k` steps along the major axis. `major_len` can't be
+        // zero, because lines with zero length are already stopped.
+        let minor_offset = |k: i64| (2 * k * minor_len + major_len - 1).div_euclid(2 * major_len);
+
+        let start = self.start;
+        let direction = self.direction;
+
+        let x_range = offset_range(start.x, direction.x, clip.top_left.x, clip_bottom_right.x);
+        let y_range = offset_range(start.y, direction.y, clip.top_left.y, clip_bottom_right.y);
+        let (major_range, minor_range) = if x_major {
+            (x_range, y_range)
+        } else {
+            (y_range, x_range)
+        };
+
+        // The minor offset never decreases, which makes it possible to find the steps with a
+        // visible minor coordinate by binary search.
+        let first = major_range
+            .0
+            .max(0)
+            .max(partition_point(0, major_len + 1, |k| {
+                minor_offset(k) < minor_range.0
+            }));
+        let last = major_range
+            .1
+            .min(major_len)
+            .min(partition_point(0, major_len + 1, |k| minor_offset(k) <= minor_range.1) - 1);
+
+        if first > last {
+            self.stop = true;
+            return self;
         }
+
+        let point_at = |k: i64| {
+            let minor = minor_offset(k);
+            let (steps_x, steps_y) = if x_major { (k, minor) } else { (minor, k) };
+
+            let point = Point::new(
+                (i64::from(start.x) + steps_x * i64::from(direction.x)) as i32,
+                (i64::from(start.y) + steps_y * i64::from(direction.y)) as i32,
+            );
+
+            // Every step in X direction adds `delta.y` to the error, every step in Y direction
+            // adds `delta.x`.
+            let err = delta_x + delta_y + steps_x * delta_y + steps_y * delta_x;
+
+            (point, err as i32)
+        };
+
+        let (first_point, err) = point_at(first);
+        self.start = first_point;
+        self.err = err;
+        self.end = point_at(last).0;
+
+        self
     }
 }
 
-/// Pixel iterator for each pixel in the line
-#[derive(Debug, Clone, Copy)]
-pub struct LineIterator<C>
-where
-    C: PixelColor,
-{
-    style: Style<C>,
+/// Returns the range of step counts for which `start + steps * direction` lies in `min..=max`.
+fn offset_range(start: i32, direction: i32, min: i32, max: i32) -> (i64, i64) {
+    let (start, min, max) = (i64::from(start), i64::from(min), i64::from(max));
 
-    start: Point,
-    end: Point,
-    delta: Point,
-    /// in which quadrant is the line drawn (upper-left=(-1, -1), lower-right=(1, 1), ...)
-    direction: Point,
-    err: i32,
-    stop: bool,
+    if direction > 0 {
+        (min - start, max - start)
+    } else {
+        (start - max, start - min)
+    }
 }
 
-// [Bresenham's line algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm)
-impl<C: PixelColor> Iterator for LineIterator<C> {
-    type Item = Pixel<C>;
+/// Returns the first value in `start..end` for which `pred` is `false`, or `end` if there is none.
+///
+/// `pred` must be `true` for all values before the returned value and `false` for all values after
+/// it.
+fn partition_point(mut start: i64, mut end: i64, pred: impl Fn(i64) -> bool) -> i64 {
+    while start < end {
+        let mid = start + (end - start) / 2;
+
+        if pred(mid) {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        // return none if stroke color is none
-        self.style.stroke_color?;
+    start
+}
+
+impl Iterator for BresenhamPoints {
+    type Item = Point;
 
+    fn next(&mut self) -> Option<Self::Item> {
         if !self.stop {
             let point = self.start;
 
@@ -197,14 +407,178 @@ impl<C: PixelColor> Iterator for LineIterator<C> {
                 self.start += Point::new(0, self.direction.y);
             }
 
-            Some(Pixel(point, self.style.stroke_color.unwrap()))
+            Some(point)
         } else {
             None
         }
     }
 }
 
-impl<'a, C: 'a> Drawable<C> for &Line<C>
+/// Iterator over the points of a line with sub-pixel end points
+///
+/// The line steps one pixel at a time along its major axis, from the pixel nearest to the start
+/// point to the pixel nearest to the end point. The minor coordinate of each pixel is the exact
+/// position of the line at the center of the pixel, rounded to the nearest pixel.
+#[derive(Debug, Clone, Copy)]
+struct SubpixelLinePoints {
+    /// `true` if the line is steeper in X direction than in Y direction
+    x_major: bool,
+    /// Start point as (major, minor) coordinates in sub-pixel units
+    start: (i64, i64),
+    /// Difference between end and start as (major, minor) coordinates in sub-pixel units
+    delta: (i64, i64),
+    /// Current pixel along the major axis
+    major: i32,
+    /// Last pixel along the major axis
+    major_end: i32,
+    /// Step along the major axis, either 1 or -1
+    step: i32,
+    /// Range of visible pixels along the minor axis
+    minor_range: (i32, i32),
+    stop: bool,
+}
+
+impl SubpixelLinePoints {
+    fn new(start: SubpixelPoint, end: SubpixelPoint) -> Self {
+        let delta = end - start;
+        let x_major = delta.x.abs() >= delta.y.abs();
+
+        let (start, delta) = if x_major {
+            ((start.x, start.y), (delta.x, delta.y))
+        } else {
+            ((start.y, start.x), (delta.y, delta.x))
+        };
+
+        let major = round(start.0);
+        let major_end = round(start.0 + delta.0);
+
+        Self {
+            x_major,
+            start: (i64::from(start.0), i64::from(start.1)),
+            delta: (i64::from(delta.0), i64::from(delta.1)),
+            major,
+            major_end,
+            step: if major_end >= major { 1 } else { -1 },
+            minor_range: (i32::min_value(), i32::max_value()),
+            stop: delta == (0, 0), // if line length is zero, draw nothing
+        }
+    }
+
+    /// Returns the pixel of the line at the given position along the major axis.
+    fn point_at(&self, major: i32) -> Point {
+        let (start_major, start_minor) = self.start;
+        let (delta_major, delta_minor) = self.delta;
+
+        // Rounded minor coordinate of the line at the center of the pixel, which is
+        // `start_minor + (major - start_major) * delta_minor / delta_major` in pixels.
+        let offset = (i64::from(major) << SUBPIXEL_BITS) - start_major;
+        let (mut numerator, mut denominator) = if delta_major != 0 {
+            (
+                start_minor * delta_major + offset * delta_minor,
+                delta_major << SUBPIXEL_BITS,
+            )
+        } else {
+            // Only lines with zero length have no extent along the major axis.
+            (start_minor, 1 << SUBPIXEL_BITS)
+        };
+        if denominator < 0 {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+
+        let minor = (2 * numerator + denominator).div_euclid(2 * denominator) as i32;
+
+        if self.x_major {
+            Point::new(major, minor)
+        } else {
+            Point::new(minor, major)
+        }
+    }
+
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    ///
+    /// The range along the major axis is restricted before iterating, pixels outside the range
+    /// along the minor axis are skipped.
+    fn clip(mut self, clip: &Rect) -> Self {
+        let clip_bottom_right = match clip.bottom_right() {
+            Some(point) => point,
+            None => {
+                self.stop = true;
+                return self;
+            }
+        };
+
+        let (major_range, minor_range) = if self.x_major {
+            (
+                (clip.top_left.x, clip_bottom_right.x),
+                (clip.top_left.y, clip_bottom_right.y),
+            )
+        } else {
+            (
+                (clip.top_left.y, clip_bottom_right.y),
+                (clip.top_left.x, clip_bottom_right.x),
+            )
+        };
+
+        if self.step > 0 {
+            self.major = self.major.max(major_range.0);
+            self.major_end = self.major_end.min(major_range.1);
+            self.stop |= self.major > self.major_end;
+        } else {
+            self.major = self.major.min(major_range.1);
+            self.major_end = self.major_end.max(major_range.0);
+            self.stop |= self.major < self.major_end;
+        }
+
+        self.minor_range = (
+            self.minor_range.0.max(minor_range.0),
+            self.minor_range.1.min(minor_range.1),
+        );
+
+        self
+    }
+}
+
+impl Iterator for SubpixelLinePoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.stop {
+            let point = self.point_at(self.major);
+
+            if self.major == self.major_end {
+                self.stop = true;
+            } else {
+                self.major += self.step;
+            }
+
+            let minor = if self.x_major { point.y } else { point.x };
+            if minor >= self.minor_range.0 && minor <= self.minor_range.1 {
+                return Some(point);
+            }
+        }
+
+        None
+    }
+}
+
+/// Rounds a sub-pixel coordinate to the nearest pixel.
+fn round(value: i32) -> i32 {
+    (value + (1 << (SUBPIXEL_BITS - 1))) >> SUBPIXEL_BITS
+}
+
+impl<C: PixelColor> Iterator for LineIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // return none if stroke color is none
+        let stroke_color = self.stroke_color?;
+
+        self.points.next().map(|point| Pixel(point, stroke_color))
+    }
+}
+
+impl<'a, C: 'a> Drawable<C> for &Styled<Line, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
@@ -213,22 +587,24 @@ where
     }
 }
 
-impl<C> Transform for Line<C>
+impl<'a, C: 'a> Drawable<C> for &Styled<Line<SubpixelPoint>, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
+    fn draw<T: DrawTarget<C>>(self, display: &mut T) {
+        display.draw_iter(self.clipped_iter(&display.display_area()));
+    }
+}
+
+impl<P: Vertex> Transform for Line<P> {
     /// Translate the line from its current position to a new position by (x, y) pixels, returning
     /// a new `Line`. For a mutating transform, see `translate_mut`.
     ///
     /// ```
     /// # use embedded_graphics::primitives::Line;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::BinaryColor;
-    /// #
-    /// # let style = Style::stroke_color(BinaryColor::On);
     /// #
-    /// let line = Line::new(Point::new(5, 10), Point::new(15, 20))
-    /// #    .style(style);
+    /// let line = Line::new(Point::new(5, 10), Point::new(15, 20));
     /// let moved = line.translate(Point::new(10, 10));
     ///
     /// assert_eq!(moved.start, Point::new(15, 20));
@@ -238,7 +614,6 @@ where
         Self {
             start: self.start + by,
             end: self.end + by,
-            ..*self
         }
     }
 
@@ -247,12 +622,8 @@ where
     /// ```
     /// # use embedded_graphics::primitives::Line;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::BinaryColor;
     /// #
-    /// # let style = Style::stroke_color(BinaryColor::On);
-    /// #
-    /// let mut line = Line::new(Point::new(5, 10), Point::new(15, 20))
-    /// #    .style(style);
+    /// let mut line = Line::new(Point::new(5, 10), Point::new(15, 20));
     /// line.translate_mut(Point::new(10, 10));
     ///
     /// assert_eq!(line.start, Point::new(15, 20));
@@ -266,15 +637,61 @@ where
     }
 }
 
+impl<P: Vertex> Rotate for Line<P> {
+    type Output = Line<P>;
+
+    /// Rotates the line around `center`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Line;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::geometry::Angle;
+    /// use embedded_graphics::transform::Rotate;
+    ///
+    /// let line = Line::new(Point::new(10, 10), Point::new(20, 10));
+    /// let rotated = line.rotate(Point::new(10, 10), Angle::from_degrees(90));
+    ///
+    /// assert_eq!(rotated, Line::new(Point::new(10, 10), Point::new(10, 20)));
+    /// ```
+    fn rotate(&self, center: Point, angle: Angle) -> Line<P> {
+        Line::new(
+            self.start.rotate(center, angle),
+            self.end.rotate(center, angle),
+        )
+    }
+}
+
+impl<P: Vertex> Scale for Line<P> {
+    type Output = Line<P>;
+
+    /// Scales the line relative to `center`.
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Line<P> {
+        Line::new(
+            self.start.scale(center, numerator, denominator),
+            self.end.scale(center, numerator, denominator),
+        )
+    }
+}
+
+impl<P: Vertex> Mirror for Line<P> {
+    type Output = Line<P>;
+
+    /// Mirrors the line across `axis`.
+    fn mirror(&self, axis: Axis) -> Line<P> {
+        Line::new(self.start.mirror(axis), self.end.mirror(axis))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::drawable::Pixel;
+    use crate::mock_display::{assert_clipped_iter, assert_contains_drawn_points};
     use crate::pixelcolor::BinaryColor;
-    use crate::style::Style;
 
     fn test_expected_line(start: Point, end: Point, expected: &[(i32, i32)]) {
-        let line = Line::new(start, end).style(Style::stroke_color(BinaryColor::On));
+        let line =
+            Line::new(start, end).into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
         let mut expected_iter = expected.iter();
         for Pixel(coord, _) in line.into_iter() {
             match expected_iter.next() {
@@ -292,8 +709,8 @@ mod tests {
         let start = Point::new(10, 10);
         let end = Point::new(20, 20);
 
-        let line: Line<BinaryColor> = Line::new(start, end);
-        let backwards_line: Line<BinaryColor> = Line::new(end, start);
+        let line = Line::new(start, end);
+        let backwards_line = Line::new(end, start);
 
         assert_eq!(line.top_left(), start);
         assert_eq!(line.bottom_right(), end);
@@ -383,4 +800,145 @@ mod tests {
         let expected = [(10, 10), (11, 9), (12, 9), (13, 8), (14, 8), (15, 7)];
         test_expected_line(start, end, &expected);
     }
+
+    #[test]
+    fn contains() {
+        let lines = [
+            ((2, 3), (20, 9)),
+            ((20, 9), (2, 3)),
+            ((5, 1), (8, 25)),
+            ((10, 10), (10, 20)),
+            ((3, 3), (3, 3)),
+        ];
+
+        for &(start, end) in lines.iter() {
+            let line = Line::new(Point::from(start), Point::from(end))
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3));
+
+            assert_contains_drawn_points(&line);
+        }
+    }
+
+    #[test]
+    fn clipped_iter() {
+        for x0 in (-4..16).step_by(3) {
+            for y0 in (-4..16).step_by(4) {
+                for &(x1, y1) in &[(15, 2), (-3, 9), (4, 20), (11, 11), (0, -4), (x0, 6)] {
+                    let line = Line::new(Point::new(x0, y0), Point::new(x1, y1))
+                        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+                    assert_clipped_iter(&line, |clip| line.clipped_iter(clip));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_long_line() {
+        let line = Line::new(
+            Point::new(-1_000_000, -300_000),
+            Point::new(1_000_000, 300_001),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+        let clip = Rect::new(Point::new(0, 0), Size::new(64, 64));
+
+        let clipped = line.clipped_iter(&clip);
+        assert!(clipped.clone().all(|Pixel(p, _)| clip.contains(p)));
+
+        let first = clipped.clone().next().unwrap().0;
+        assert!(line.contains(first));
+        assert_eq!(first.x, 0);
+        assert_eq!(clipped.count(), 64);
+    }
+
+    #[test]
+    fn subpixel_integer_points() {
+        let lines = [
+            ((2, 3), (30, 9)),
+            ((30, 9), (2, 3)),
+            ((-5, 20), (4, -7)),
+            ((8, 8), (8, 20)),
+            ((3, 3), (3, 3)),
+        ];
+
+        for &(start, end) in lines.iter() {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+            let line = Line::new(Point::from(start), Point::from(end));
+            let subpixel_line = Line::new(
+                SubpixelPoint::from(line.start),
+                SubpixelPoint::from(line.end),
+            );
+
+            assert_eq!(subpixel_line.bounding_box(), line.bounding_box());
+            assert!(
+                subpixel_line
+                    .into_styled(style)
+                    .into_iter()
+                    .eq(line.into_styled(style).into_iter()),
+                "{:?}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn subpixel_end_point() {
+        // From (0, 0) to (4, 1.5)
+        let line = Line::new(
+            SubpixelPoint::new(0, 0),
+            SubpixelPoint::new(4 * 256, 256 + 128),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        assert!(line.into_iter().map(|Pixel(p, _)| p).eq([
+            Point::new(0, 0),
+            Point::new(1, 0),
+            Point::new(2, 1),
+            Point::new(3, 1),
+            Point::new(4, 2),
+        ]
+        .iter()
+        .cloned()));
+        assert_eq!(line.top_left(), Point::new(0, 0));
+        assert_eq!(line.bottom_right(), Point::new(4, 2));
+    }
+
+    #[test]
+    fn subpixel_short_line() {
+        // A line inside a single pixel is drawn as a dot
+        let line = Line::new(SubpixelPoint::new(10, 20), SubpixelPoint::new(100, 60))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        assert!(line
+            .into_iter()
+            .map(|Pixel(p, _)| p)
+            .eq(Some(Point::zero())));
+    }
+
+    #[test]
+    fn subpixel_clipped_iter() {
+        let clip = Rect::new(Point::new(-2, 3), Size::new(9, 5));
+
+        for &(start, end) in [
+            ((-700, 100), (3000, 2100)),
+            ((3000, 2100), (-700, 100)),
+            ((500, -300), (1200, 4000)),
+            ((1900, 1950), (-50, 500)),
+        ]
+        .iter()
+        {
+            let line = Line::new(
+                SubpixelPoint::new(start.0, start.1),
+                SubpixelPoint::new(end.0, end.1),
+            )
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+            assert!(
+                line.clipped_iter(&clip)
+                    .eq(line.into_iter().filter(|Pixel(p, _)| clip.contains(*p))),
+                "{:?}",
+                line
+            );
+        }
+    }
 }
@@ -1,233 +1,322 @@
 //! Graphics primitives
+//!
+//! Primitives only describe geometry. To draw a primitive it needs to be combined with a
+//! [`PrimitiveStyle`] by calling [`into_styled`], or by creating it with one of the `eg*`
+//! macros.
+//!
+//! [`PrimitiveStyle`]: ../style/struct.PrimitiveStyle.html
+//! [`into_styled`]: trait.Primitive.html#method.into_styled
 
 use crate::geometry::Dimensions;
+use crate::style::Styled;
 
 pub mod circle;
+mod edge;
 pub mod line;
+pub mod quad;
 pub mod rectangle;
 pub mod triangle;
 
 /// Primitive trait
-pub trait Primitive: Dimensions {}
+pub trait Primitive: Dimensions {
+    /// Converts this primitive into a `Styled` object which can be drawn.
+    ///
+    /// ```rust
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::primitives::Circle;
+    /// use embedded_graphics::style::PrimitiveStyle;
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// Circle::new(Point::new(10, 10), 5)
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///     .draw(&mut display);
+    /// ```
+    fn into_styled<S>(self, style: S) -> Styled<Self, S>
+    where
+        Self: Sized,
+    {
+        Styled::new(self, style)
+    }
+}
 
 pub use self::circle::Circle;
 pub use self::line::Line;
+pub use self::quad::Quad;
 pub use self::rectangle::Rectangle;
 pub use self::triangle::Triangle;
 
-/// Create a [`Circle`](./primitives/circle/struct.Circle.html) with optional styling using a
-/// convenient macro.
+/// Returns the square root of `value`, rounded down.
+///
+/// This is used to find the scanline bounds of primitives which are defined by squared distances.
+fn isqrt(value: u64) -> u64 {
+    let mut value = value;
+    let mut root = 0;
+    let mut bit = 1 << 62;
+
+    while bit > value {
+        bit >>= 2;
+    }
+
+    while bit != 0 {
+        if value >= root + bit {
+            value -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+
+        bit >>= 2;
+    }
+
+    root
+}
+
+/// Internal macro used to style primitives in the `eg*` macros. Do not use directly!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_primitive_style {
+    ($primitive:expr, style = $style:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::primitives::Primitive;
+        $primitive.into_styled($style)
+    }};
+    ($primitive:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::primitives::Primitive;
+        $primitive.into_styled(
+            $crate::style::PrimitiveStyle::default()
+                $( .$style_key($style_value) )*
+        )
+    }};
+}
+
+/// Create a styled [`Circle`](./primitives/circle/struct.Circle.html) using a convenient macro.
 ///
 /// ```rust
-/// use embedded_graphics::pixelcolor::Rgb565;
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egcircle, primitives::Circle, style::Style};
+/// use embedded_graphics::{
+///     egcircle,
+///     primitives::Circle,
+///     style::{PrimitiveStyle, Styled},
+/// };
+/// use embedded_graphics::pixelcolor::Rgb565;
 ///
-/// let line_circle: Circle<Rgb565> = egcircle!((10, 20), 30);
-/// let line_circle: Circle<Rgb565> = egcircle!(Point::new(10, 20), 30);
-/// let filled_circle: Circle<Rgb565> = egcircle!(
+/// let line_circle: Styled<Circle, PrimitiveStyle<Rgb565>> = egcircle!((10, 20), 30);
+/// let line_circle: Styled<Circle, PrimitiveStyle<Rgb565>> = egcircle!(Point::new(10, 20), 30);
+/// let filled_circle: Styled<Circle, PrimitiveStyle<Rgb565>> = egcircle!(
 ///     (10, 20),
 ///     30,
 ///     stroke_color = Some(Rgb565::RED),
 ///     fill_color = Some(Rgb565::GREEN)
 /// );
-/// let default_style: Circle<Rgb565> = egcircle!((10, 20), 30, style = Style::default());
+/// let default_style: Styled<Circle, PrimitiveStyle<Rgb565>> =
+///     egcircle!((10, 20), 30, style = PrimitiveStyle::default());
 /// ```
 ///
-/// Style properties like `stroke_color` map to the method calls on the
-/// [`WithStyle`](style/trait.WithStyle.html) trait. For example, the following code makes two
-/// identical circles:
+/// Style properties like `stroke_color` map to the method calls on
+/// [`PrimitiveStyle`](style/struct.PrimitiveStyle.html). For example, the following code makes
+/// two identical circles:
 ///
 /// ```rust
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egcircle, pixelcolor::Rgb565, primitives::Circle, style::Style};
+/// use embedded_graphics::{egcircle, pixelcolor::Rgb565, primitives::Circle, style::PrimitiveStyle};
 ///
-/// let circle: Circle<Rgb565> = egcircle!(
+/// let circle = egcircle!(
 ///     (10, 20),
 ///     30,
 ///     stroke_color = Some(Rgb565::RED),
 ///     fill_color = Some(Rgb565::GREEN)
 /// );
-/// let circle: Circle<Rgb565> = egcircle!(
-///     Point::new(10, 20),
-///     30,
-///     stroke_color = Some(Rgb565::RED),
-///     fill_color = Some(Rgb565::GREEN)
+/// let circle = Circle::new(Point::new(10, 20), 30).into_styled(
+///     PrimitiveStyle::default()
+///         .stroke_color(Some(Rgb565::RED))
+///         .fill_color(Some(Rgb565::GREEN)),
 /// );
-/// let circle: Circle<Rgb565> = Circle::new(Point::new(10, 20), 30)
-///     .stroke_color(Some(Rgb565::RED))
-///     .fill_color(Some(Rgb565::GREEN));
 /// ```
 #[macro_export]
 macro_rules! egcircle {
-    ($center:expr, $r:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
-        #[allow(unused_imports)]
-        use $crate::style::WithStyle;
-        $crate::primitives::Circle::new($crate::geometry::Point::from($center), $r)
-            $( .$style_key($style_value) )*
-    }};
+    ($center:expr, $r:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
+        $crate::impl_primitive_style!(
+            $crate::primitives::Circle::new($crate::geometry::Point::from($center), $r)
+            $(, $style_key = $style_value )*
+        )
+    };
 }
 
-/// Create a [`Line`](./primitives/line/struct.Line.html) with optional styling using a
-/// convenient macro.
+/// Create a styled [`Line`](./primitives/line/struct.Line.html) using a convenient macro.
 ///
-/// Note that only the `stroke` property has any effect on lines currently.
+/// Note that only the `stroke_color` property has any effect on lines currently.
 ///
 /// ```rust
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egline, pixelcolor::Rgb565, primitives::Line, style::Style};
+/// use embedded_graphics::{
+///     egline,
+///     pixelcolor::Rgb565,
+///     primitives::Line,
+///     style::{PrimitiveStyle, Styled},
+/// };
 ///
-/// let line: Line<Rgb565> = egline!((10, 20), (30, 40));
-/// let line: Line<Rgb565> = egline!(Point::new(10, 20), Point::new(30, 40));
-/// let stroke_line: Line<Rgb565> = egline!((10, 20), (30, 40), stroke_color = Some(Rgb565::BLUE));
+/// let line: Styled<Line, PrimitiveStyle<Rgb565>> = egline!((10, 20), (30, 40));
+/// let line: Styled<Line, PrimitiveStyle<Rgb565>> =
+///     egline!(Point::new(10, 20), Point::new(30, 40));
+/// let stroke_line: Styled<Line, PrimitiveStyle<Rgb565>> =
+///     egline!((10, 20), (30, 40), stroke_color = Some(Rgb565::BLUE));
 /// ```
 ///
-/// Style properties like `stroke` map to the method calls on the
-/// [`WithStyle`](style/trait.WithStyle.html) trait. For example, the following code makes two
-/// identical lines:
+/// Style properties like `stroke_color` map to the method calls on
+/// [`PrimitiveStyle`](style/struct.PrimitiveStyle.html). For example, the following code makes
+/// two identical lines:
 ///
 /// ```rust
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egline, pixelcolor::Rgb565, primitives::Line, style::Style};
+/// use embedded_graphics::{egline, pixelcolor::Rgb565, primitives::Line, style::PrimitiveStyle};
 ///
-/// let Line: Line<Rgb565> = egline!(
+/// let line = egline!(
 ///     Point::new(10, 20),
 ///     Point::new(30, 40),
-///     stroke_color = Some(Rgb565::BLUE),
-///     fill_color = Some(Rgb565::YELLOW)
+///     stroke_color = Some(Rgb565::BLUE)
 /// );
-/// let Line: Line<Rgb565> = egline!(
-///     (10, 20),
-///     (30, 40),
-///     stroke_color = Some(Rgb565::BLUE),
-///     fill_color = Some(Rgb565::YELLOW)
-/// );
-/// let Line: Line<Rgb565> = Line::new(Point::new(10, 20), Point::new(30, 40))
-///     .stroke_color(Some(Rgb565::BLUE))
-///     .fill_color(Some(Rgb565::YELLOW));
+/// let line = Line::new(Point::new(10, 20), Point::new(30, 40))
+///     .into_styled(PrimitiveStyle::default().stroke_color(Some(Rgb565::BLUE)));
 /// ```
 #[macro_export]
 macro_rules! egline {
-    ($start:expr, $end:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
-        #[allow(unused_imports)]
-        use $crate::style::WithStyle;
-        $crate::primitives::Line::new(
-            $crate::geometry::Point::from($start),
-            $crate::geometry::Point::from($end)
+    ($start:expr, $end:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
+        $crate::impl_primitive_style!(
+            $crate::primitives::Line::new(
+                $crate::geometry::Point::from($start),
+                $crate::geometry::Point::from($end)
+            )
+            $(, $style_key = $style_value )*
         )
-            $( .$style_key($style_value) )*
-    }};
+    };
 }
 
-/// Create a [`Rectangle`](./primitives/rectangle/struct.Rectangle.html) with optional styling using a
+/// Create a styled [`Rectangle`](./primitives/rectangle/struct.Rectangle.html) using a
 /// convenient macro.
 ///
 /// ```rust
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egrectangle, pixelcolor::Rgb565, primitives::Rectangle, style::Style};
+/// use embedded_graphics::{
+///     egrectangle,
+///     pixelcolor::Rgb565,
+///     primitives::Rectangle,
+///     style::{PrimitiveStyle, Styled},
+/// };
 ///
-/// let empty_rect: Rectangle<Rgb565> = egrectangle!((10, 20), (30, 40));
-/// let empty_rect: Rectangle<Rgb565> = egrectangle!(Point::new(10, 20), Point::new(30, 40));
-/// let filled_rect: Rectangle<Rgb565> = egrectangle!(
+/// let empty_rect: Styled<Rectangle, PrimitiveStyle<Rgb565>> = egrectangle!((10, 20), (30, 40));
+/// let empty_rect: Styled<Rectangle, PrimitiveStyle<Rgb565>> =
+///     egrectangle!(Point::new(10, 20), Point::new(30, 40));
+/// let filled_rect: Styled<Rectangle, PrimitiveStyle<Rgb565>> = egrectangle!(
 ///     (10, 20),
 ///     (30, 40),
 ///     stroke_color = Some(Rgb565::RED),
 ///     fill_color = Some(Rgb565::GREEN)
 /// );
-/// let rect_default_style: Rectangle<Rgb565> =
-///     egrectangle!((10, 20), (30, 40), style = Style::default());
+/// let rect_default_style: Styled<Rectangle, PrimitiveStyle<Rgb565>> =
+///     egrectangle!((10, 20), (30, 40), style = PrimitiveStyle::default());
 /// ```
 ///
-/// Style properties like `stroke_color` map to the method calls on the
-/// [`WithStyle`](style/trait.WithStyle.html) trait. For example, the following code makes two
-/// identical rectangles:
+/// Style properties like `stroke_color` map to the method calls on
+/// [`PrimitiveStyle`](style/struct.PrimitiveStyle.html). For example, the following code makes
+/// two identical rectangles:
 ///
 /// ```rust
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egrectangle, pixelcolor::Rgb565, primitives::Rectangle, style::Style};
+/// use embedded_graphics::{
+///     egrectangle, pixelcolor::Rgb565, primitives::Rectangle, style::PrimitiveStyle,
+/// };
 ///
-/// let Rectangle: Rectangle<Rgb565> = egrectangle!(
+/// let rectangle = egrectangle!(
 ///     (10, 20),
 ///     (30, 40),
 ///     stroke_color = Some(Rgb565::RED),
 ///     fill_color = Some(Rgb565::GREEN)
 /// );
-/// let Rectangle: Rectangle<Rgb565> = egrectangle!(
-///     Point::new(10, 20),
-///     Point::new(30, 40),
-///     stroke_color = Some(Rgb565::RED),
-///     fill_color = Some(Rgb565::GREEN)
+/// let rectangle = Rectangle::new(Point::new(10, 20), Point::new(30, 40)).into_styled(
+///     PrimitiveStyle::default()
+///         .stroke_color(Some(Rgb565::RED))
+///         .fill_color(Some(Rgb565::GREEN)),
 /// );
-/// let Rectangle: Rectangle<Rgb565> = Rectangle::new(Point::new(10, 20), Point::new(30, 40))
-///     .stroke_color(Some(Rgb565::RED))
-///     .fill_color(Some(Rgb565::GREEN));
 /// ```
 #[macro_export]
 macro_rules! egrectangle {
-    ($top_left:expr, $bottom_right:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
-        #[allow(unused_imports)]
-        use $crate::style::WithStyle;
-        $crate::primitives::Rectangle::new(
-            $crate::geometry::Point::from($top_left),
-            $crate::geometry::Point::from($bottom_right)
+    ($top_left:expr, $bottom_right:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
+        $crate::impl_primitive_style!(
+            $crate::primitives::Rectangle::new(
+                $crate::geometry::Point::from($top_left),
+                $crate::geometry::Point::from($bottom_right)
+            )
+            $(, $style_key = $style_value )*
         )
-            $( .$style_key($style_value) )*
-    }};
+    };
 }
 
-/// Create a [`Triangle`](./primitives/triangle/struct.Triangle.html) with optional styling using a
-/// convenient macro.
+/// Create a styled [`Triangle`](./primitives/triangle/struct.Triangle.html) using a convenient
+/// macro.
 ///
 /// ```rust
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egtriangle, pixelcolor::Rgb565, primitives::Triangle, style::Style};
+/// use embedded_graphics::{
+///     egtriangle,
+///     pixelcolor::Rgb565,
+///     primitives::Triangle,
+///     style::{PrimitiveStyle, Styled},
+/// };
 ///
-/// let empty_triangle: Triangle<Rgb565> = egtriangle!((10, 20), (30, 40), (50, 60));
-/// let empty_triangle: Triangle<Rgb565> =
+/// let empty_triangle: Styled<Triangle, PrimitiveStyle<Rgb565>> =
+///     egtriangle!((10, 20), (30, 40), (50, 60));
+/// let empty_triangle: Styled<Triangle, PrimitiveStyle<Rgb565>> =
 ///     egtriangle!(Point::new(10, 20), Point::new(30, 40), Point::new(50, 60));
-/// let filled_triangle: Triangle<Rgb565> = egtriangle!(
+/// let filled_triangle: Styled<Triangle, PrimitiveStyle<Rgb565>> = egtriangle!(
 ///     (10, 20),
 ///     (30, 40),
 ///     (50, 60),
 ///     stroke_color = Some(Rgb565::RED),
 ///     fill_color = Some(Rgb565::GREEN)
 /// );
-/// let triangle_default_style: Triangle<Rgb565> =
-///     egtriangle!((10, 20), (30, 40), (50, 60), style = Style::default());
+/// let triangle_default_style: Styled<Triangle, PrimitiveStyle<Rgb565>> =
+///     egtriangle!((10, 20), (30, 40), (50, 60), style = PrimitiveStyle::default());
 /// ```
 ///
-/// Style properties like `stroke_color` map to the method calls on the
-/// [`WithStyle`](style/trait.WithStyle.html) trait. For example, the following code makes two
-/// identical triangles:
+/// Style properties like `stroke_color` map to the method calls on
+/// [`PrimitiveStyle`](style/struct.PrimitiveStyle.html). For example, the following code makes
+/// two identical triangles:
 ///
 /// ```rust
 /// use embedded_graphics::prelude::*;
-/// use embedded_graphics::{egtriangle, pixelcolor::Rgb565, primitives::Triangle, style::Style};
+/// use embedded_graphics::{
+///     egtriangle, pixelcolor::Rgb565, primitives::Triangle, style::PrimitiveStyle,
+/// };
 ///
-/// let Triangle: Triangle<Rgb565> = egtriangle!(
+/// let triangle = egtriangle!(
 ///     (10, 20),
 ///     (30, 40),
 ///     (50, 60),
 ///     stroke_color = Some(Rgb565::RED),
 ///     fill_color = Some(Rgb565::GREEN)
 /// );
-/// let Triangle: Triangle<Rgb565> =
-///     Triangle::new(Point::new(10, 20), Point::new(30, 40), Point::new(50, 60))
-///         .stroke_color(Some(Rgb565::RED))
-///         .fill_color(Some(Rgb565::GREEN));
+/// let triangle = Triangle::new(Point::new(10, 20), Point::new(30, 40), Point::new(50, 60))
+///     .into_styled(
+///         PrimitiveStyle::default()
+///             .stroke_color(Some(Rgb565::RED))
+///             .fill_color(Some(Rgb565::GREEN)),
+///     );
 /// ```
 #[macro_export]
 macro_rules! egtriangle {
-    ($p1:expr, $p2:expr, $p3:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {{
-        #[allow(unused_imports)]
-        use $crate::style::WithStyle;
-        $crate::primitives::Triangle::new(
-            $crate::geometry::Point::from($p1),
-            $crate::geometry::Point::from($p2),
-            $crate::geometry::Point::from($p3)
+    ($p1:expr, $p2:expr, $p3:expr $(, $style_key:ident = $style_value:expr )* $(,)?) => {
+        $crate::impl_primitive_style!(
+            $crate::primitives::Triangle::new(
+                $crate::geometry::Point::from($p1),
+                $crate::geometry::Point::from($p2),
+                $crate::geometry::Point::from($p3)
+            )
+            $(, $style_key = $style_value )*
         )
-            $( .$style_key($style_value) )*
-    }};
+    };
 }
 
 #[cfg(test)]
@@ -235,60 +324,81 @@ mod tests {
     use super::*;
     use crate::geometry::Point;
     use crate::pixelcolor::{Rgb565, RgbColor};
-    use crate::style::Style;
+    use crate::style::PrimitiveStyle;
 
     #[test]
     fn circle() {
-        let _c: Circle<Rgb565> = egcircle!(Point::new(10, 20), 30);
-        let _c: Circle<Rgb565> = egcircle!((10, 20), 30);
-        let _c: Circle<Rgb565> = egcircle!(
+        let _c: Styled<Circle, PrimitiveStyle<Rgb565>> = egcircle!(Point::new(10, 20), 30);
+        let _c: Styled<Circle, PrimitiveStyle<Rgb565>> = egcircle!((10, 20), 30);
+        let _c: Styled<Circle, PrimitiveStyle<Rgb565>> = egcircle!(
             (10, 20),
             30,
             stroke_color = Some(Rgb565::RED),
             fill_color = Some(Rgb565::GREEN)
         );
-        let _c: Circle<Rgb565> = egcircle!((10, 20), 30, style = Style::default());
+        let _c: Styled<Circle, PrimitiveStyle<Rgb565>> =
+            egcircle!((10, 20), 30, style = PrimitiveStyle::default());
     }
 
     #[test]
     fn line() {
-        let _l: Line<Rgb565> = egline!(Point::new(10, 20), Point::new(30, 40));
-        let _l: Line<Rgb565> = egline!((10, 20), (30, 40));
-        let _l: Line<Rgb565> = egline!(
+        let _l: Styled<Line, PrimitiveStyle<Rgb565>> =
+            egline!(Point::new(10, 20), Point::new(30, 40));
+        let _l: Styled<Line, PrimitiveStyle<Rgb565>> = egline!((10, 20), (30, 40));
+        let _l: Styled<Line, PrimitiveStyle<Rgb565>> = egline!(
             (10, 20),
             (30, 40),
             stroke_color = Some(Rgb565::RED),
             fill_color = Some(Rgb565::GREEN)
         );
-        let _l: Line<Rgb565> = egline!((10, 20), (30, 40), style = Style::default());
+        let _l: Styled<Line, PrimitiveStyle<Rgb565>> =
+            egline!((10, 20), (30, 40), style = PrimitiveStyle::default());
     }
 
     #[test]
     fn rectangle() {
-        let _r: Rectangle<Rgb565> = egrectangle!(Point::new(10, 20), Point::new(30, 40));
-        let _r: Rectangle<Rgb565> = egrectangle!((10, 20), (30, 40));
-        let _r: Rectangle<Rgb565> = egrectangle!(
+        let _r: Styled<Rectangle, PrimitiveStyle<Rgb565>> =
+            egrectangle!(Point::new(10, 20), Point::new(30, 40));
+        let _r: Styled<Rectangle, PrimitiveStyle<Rgb565>> = egrectangle!((10, 20), (30, 40));
+        let _r: Styled<Rectangle, PrimitiveStyle<Rgb565>> = egrectangle!(
             (10, 20),
             (30, 40),
             stroke_color = Some(Rgb565::RED),
             fill_color = Some(Rgb565::GREEN)
         );
-        let _r: Rectangle<Rgb565> = egrectangle!((10, 20), (30, 40), style = Style::default());
+        let _r: Styled<Rectangle, PrimitiveStyle<Rgb565>> =
+            egrectangle!((10, 20), (30, 40), style = PrimitiveStyle::default());
     }
 
     #[test]
     fn triangle() {
-        let _t: Triangle<Rgb565> =
+        let _t: Styled<Triangle, PrimitiveStyle<Rgb565>> =
             egtriangle!(Point::new(10, 20), Point::new(30, 40), Point::new(50, 60));
-        let _t: Triangle<Rgb565> = egtriangle!((10, 20), (30, 40), (50, 60));
-        let _t: Triangle<Rgb565> = egtriangle!(
+        let _t: Styled<Triangle, PrimitiveStyle<Rgb565>> =
+            egtriangle!((10, 20), (30, 40), (50, 60));
+        let _t: Styled<Triangle, PrimitiveStyle<Rgb565>> = egtriangle!(
             (10, 20),
             (30, 40),
             (50, 60),
             stroke_color = Some(Rgb565::RED),
             fill_color = Some(Rgb565::GREEN)
         );
-        let _t: Triangle<Rgb565> =
-            egtriangle!((10, 20), (30, 40), (50, 60), style = Style::default());
+        let _t: Styled<Triangle, PrimitiveStyle<Rgb565>> = egtriangle!(
+            (10, 20),
+            (30, 40),
+            (50, 60),
+            style = PrimitiveStyle::default()
+        );
+    }
+
+    #[test]
+    fn integer_square_root() {
+        for value in 0..1000 {
+            let root = isqrt(value);
+            assert!(root * root <= value && (root + 1) * (root + 1) > value);
+        }
+
+        assert_eq!(isqrt(u64::max_value()), u64::from(u32::max_value()));
+        assert_eq!(isqrt(i64::max_value() as u64), 3_037_000_499);
     }
 }
@@ -0,0 +1,481 @@
+//! The quad primitive.
+
+use super::super::drawable::{Drawable, Pixel};
+use super::super::transform::{Axis, Mirror, Rotate, Scale, Transform};
+use crate::geometry::{Angle, ContainsPoint, Dimensions, Point, Rect, Size, SubpixelPoint, Vertex};
+use crate::pixelcolor::PixelColor;
+use crate::primitives::edge::{self, Edge, EdgeIterator, EdgeSpans};
+use crate::primitives::line::Line;
+use crate::primitives::Primitive;
+use crate::span::Span;
+use crate::style::{PrimitiveStyle, Styled};
+use crate::DrawTarget;
+
+/// Quad primitive
+///
+/// A quad is a convex quadrilateral, which is rasterized like a [`Triangle`] according to the
+/// top-left fill rule. Quads are most often created by rotating a [`Rectangle`]. Concave and
+/// self-intersecting quads aren't supported and aren't drawn correctly.
+///
+/// Like triangles, quads can be created from [`Point`]s or from [`SubpixelPoint`]s.
+///
+/// # Examples
+///
+/// ## Draw a rotated rectangle
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::geometry::Angle;
+/// use embedded_graphics::primitives::Rectangle;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::style::PrimitiveStyle;
+/// use embedded_graphics::transform::Rotate;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// Rectangle::new(Point::new(20, 20), Point::new(40, 30))
+///     .rotate(Point::new(30, 25), Angle::from_degrees(30))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1).fill_color(Some(Rgb565::BLUE)))
+///     .draw(&mut display);
+/// ```
+///
+/// [`Triangle`]: ../triangle/struct.Triangle.html
+/// [`Rectangle`]: ../rectangle/struct.Rectangle.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quad<P = Point> {
+    /// First point of the quad
+    pub p1: P,
+
+    /// Second point of the quad
+    pub p2: P,
+
+    /// Third point of the quad
+    pub p3: P,
+
+    /// Fourth point of the quad
+    pub p4: P,
+}
+
+impl<P: Vertex> Primitive for Quad<P> {}
+
+impl<P: Vertex> Dimensions for Quad<P> {
+    /// Returns the top left corner of the bounding box.
+    ///
+    /// Sub-pixel vertices are rounded to the nearest pixel.
+    fn top_left(&self) -> Point {
+        let points = self.rounded_points();
+        let x = points.iter().map(|p| p.x).min().unwrap();
+        let y = points.iter().map(|p| p.y).min().unwrap();
+
+        Point::new(x, y)
+    }
+
+    /// Returns the bottom right corner of the bounding box.
+    ///
+    /// Sub-pixel vertices are rounded to the nearest pixel.
+    fn bottom_right(&self) -> Point {
+        let points = self.rounded_points();
+        let x = points.iter().map(|p| p.x).max().unwrap();
+        let y = points.iter().map(|p| p.y).max().unwrap();
+
+        Point::new(x, y)
+    }
+
+    fn size(&self) -> Size {
+        Size::from_bounding_box(self.top_left(), self.bottom_right())
+    }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::with_corners(self.top_left(), self.bottom_right())
+    }
+}
+
+impl<P> Quad<P> {
+    /// Create a new quad from four points in clockwise or counterclockwise order
+    pub fn new(p1: P, p2: P, p3: P, p4: P) -> Self {
+        Quad { p1, p2, p3, p4 }
+    }
+}
+
+impl<P: Vertex> Quad<P> {
+    fn subpixel_points(&self) -> [SubpixelPoint; 4] {
+        [
+            self.p1.into(),
+            self.p2.into(),
+            self.p3.into(),
+            self.p4.into(),
+        ]
+    }
+
+    fn rounded_points(&self) -> [Point; 4] {
+        let [p1, p2, p3, p4] = self.subpixel_points();
+
+        [p1.round(), p2.round(), p3.round(), p4.round()]
+    }
+
+    /// Returns the edges of the quad, wound so that the inside is on the positive side.
+    ///
+    /// `None` is returned if the quad has no area.
+    fn edges(&self) -> Option<[Edge; 4]> {
+        let [p1, p2, p3, p4] = self.subpixel_points();
+
+        // Twice the signed area of the quad
+        let area = Edge::new(p1, p2).value(p3) + Edge::new(p1, p3).value(p4);
+        if area == 0 {
+            return None;
+        }
+
+        let (p2, p4) = if area > 0 { (p2, p4) } else { (p4, p2) };
+
+        Some([
+            Edge::new(p1, p2),
+            Edge::new(p2, p3),
+            Edge::new(p3, p4),
+            Edge::new(p4, p1),
+        ])
+    }
+
+    /// Returns the line used to draw a quad without an area.
+    ///
+    /// The line connects the two outermost points of the quad.
+    fn degenerate_line(&self) -> Line<P> {
+        let points = [self.p1, self.p2, self.p3, self.p4];
+        let (start, end) = edge::outermost_pair(
+            &self.subpixel_points(),
+            &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)],
+        );
+
+        Line::new(points[start], points[end])
+    }
+}
+
+impl<P: Vertex> ContainsPoint for Quad<P> {
+    /// Returns `true` if the point is inside the quad according to the top-left fill rule.
+    ///
+    /// Quads without an area contain the points on the line they are drawn as.
+    fn contains(&self, point: Point) -> bool {
+        match self.edges() {
+            Some(edges) => edges.iter().all(|edge| edge.contains(point)),
+            None => self.degenerate_line().contains(point),
+        }
+    }
+}
+
+impl<C, P> ContainsPoint for Styled<Quad<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    /// Returns `true` if the point is inside the quad according to the top-left fill rule.
+    ///
+    /// Quads without an area contain the points on the line they are drawn as, if the stroke
+    /// width isn't zero.
+    fn contains(&self, point: Point) -> bool {
+        if self.primitive.edges().is_none() && self.style.stroke_width == 0 {
+            return false;
+        }
+
+        self.primitive.contains(point)
+    }
+}
+
+impl<C, P> Styled<Quad<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    /// Returns an iterator over the pixels of the quad that lie inside the clip rectangle.
+    pub fn clipped_iter(&self, clip: &Rect) -> QuadIterator<C> {
+        self.into_iter().clip(clip)
+    }
+
+    /// Returns an iterator over the horizontal spans of the quad.
+    pub fn spans(&self) -> QuadSpans<C> {
+        QuadSpans(EdgeSpans::new(self.into_iter().0))
+    }
+
+    /// Returns an iterator over the horizontal spans of the quad that lie inside the clip
+    /// rectangle.
+    pub fn clipped_spans(&self, clip: &Rect) -> QuadSpans<C> {
+        QuadSpans(EdgeSpans::new(self.clipped_iter(clip).0))
+    }
+}
+
+impl<C, P> IntoIterator for Styled<Quad<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    type Item = Pixel<C>;
+    type IntoIter = QuadIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+
+impl<'a, C, P> IntoIterator for &'a Styled<Quad<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    type Item = Pixel<C>;
+    type IntoIter = QuadIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let quad = &self.primitive;
+
+        QuadIterator(match quad.edges() {
+            Some(edges) => {
+                EdgeIterator::new(&edges, quad.top_left(), quad.bottom_right(), self.style)
+            }
+            // Quads without an area are drawn as a line between the two outermost points
+            None => EdgeIterator::with_line(quad.degenerate_line(), self.style),
+        })
+    }
+}
+
+/// Pixel iterator for each pixel in the quad
+///
+/// Quads are rasterized according to the same top-left fill rule as triangles.
+///
+/// The stroke is drawn on the inside of the quad and is `stroke_width` pixels wide.
+#[derive(Debug, Clone, Copy)]
+pub struct QuadIterator<C>(EdgeIterator<C>)
+where
+    C: PixelColor;
+
+impl<C> QuadIterator<C>
+where
+    C: PixelColor,
+{
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    fn clip(self, clip: &Rect) -> Self {
+        QuadIterator(self.0.clip(clip))
+    }
+}
+
+impl<C> Iterator for QuadIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Span iterator for the rows of a quad
+///
+/// The rows are split into stroke and fill segments like the rows of a triangle.
+#[derive(Debug, Clone, Copy)]
+pub struct QuadSpans<C>(EdgeSpans<C>)
+where
+    C: PixelColor;
+
+impl<C> Iterator for QuadSpans<C>
+where
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a, C: 'a, P> Drawable<C> for &Styled<Quad<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    fn draw<T: DrawTarget<C>>(self, display: &mut T) {
+        display.draw_hline_spans(self.clipped_spans(&display.display_area()));
+    }
+}
+
+impl<P: Vertex> Transform for Quad<P> {
+    /// Translate the quad from its current position to a new position by (x, y) pixels,
+    /// returning a new `Quad`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            p1: self.p1 + by,
+            p2: self.p2 + by,
+            p3: self.p3 + by,
+            p4: self.p4 + by,
+        }
+    }
+
+    /// Translate the quad from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.p1 += by;
+        self.p2 += by;
+        self.p3 += by;
+        self.p4 += by;
+
+        self
+    }
+}
+
+impl<P: Vertex> Rotate for Quad<P> {
+    type Output = Quad<P>;
+
+    /// Rotates the quad around `center`.
+    fn rotate(&self, center: Point, angle: Angle) -> Quad<P> {
+        Quad::new(
+            self.p1.rotate(center, angle),
+            self.p2.rotate(center, angle),
+            self.p3.rotate(center, angle),
+            self.p4.rotate(center, angle),
+        )
+    }
+}
+
+impl<P: Vertex> Scale for Quad<P> {
+    type Output = Quad<P>;
+
+    /// Scales the quad relative to `center`.
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Quad<P> {
+        Quad::new(
+            self.p1.scale(center, numerator, denominator),
+            self.p2.scale(center, numerator, denominator),
+            self.p3.scale(center, numerator, denominator),
+            self.p4.scale(center, numerator, denominator),
+        )
+    }
+}
+
+impl<P: Vertex> Mirror for Quad<P> {
+    type Output = Quad<P>;
+
+    /// Mirrors the quad across `axis`.
+    fn mirror(&self, axis: Axis) -> Quad<P> {
+        Quad::new(
+            self.p1.mirror(axis),
+            self.p2.mirror(axis),
+            self.p3.mirror(axis),
+            self.p4.mirror(axis),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_display::{assert_contains_drawn_points, MockDisplay};
+    use crate::pixelcolor::BinaryColor;
+    use crate::primitives::Rectangle;
+    use crate::span::Spans;
+    use crate::style::Pattern;
+
+    #[test]
+    fn unrotated_rectangle_matches_rectangle() {
+        let rect = Rectangle::new(Point::new(2, 3), Point::new(12, 9));
+
+        for stroke_width in 0..4 {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)
+                .fill_color(Some(BinaryColor::Off));
+
+            let mut expected = MockDisplay::new();
+            rect.into_styled(style).draw(&mut expected);
+
+            let mut display = MockDisplay::new();
+            rect.rotate(Point::new(5, 5), Angle::zero())
+                .into_styled(style)
+                .draw(&mut display);
+
+            assert_eq!(display, expected, "stroke width {}", stroke_width);
+        }
+    }
+
+    #[test]
+    fn quarter_turn() {
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Point::new(3, 1))
+            .rotate(Point::new(0, 0), Angle::from_degrees(90))
+            .translate(Point::new(2, 0))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " ##", //
+                " ##", //
+                " ##", //
+                " ##", //
+            ])
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let quad = Rectangle::new(Point::new(5, 5), Point::new(20, 12))
+            .rotate(Point::new(12, 8), Angle::from_degrees(30));
+        let styled = quad.into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert_contains_drawn_points(&styled);
+    }
+
+    #[test]
+    fn spans_are_row_segments() {
+        let quad = Rectangle::new(Point::new(5, 5), Point::new(20, 12))
+            .rotate(Point::new(12, 8), Angle::from_degrees(30));
+
+        for stroke_width in 0..5 {
+            for &pattern in [None, Some(Pattern::CHECKERBOARD)].iter() {
+                let styled = quad.into_styled(
+                    PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)
+                        .fill_color(Some(BinaryColor::Off))
+                        .fill_pattern(pattern),
+                );
+
+                assert!(styled.spans().eq(Spans::new(&styled)), "{:?}", styled);
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let quad = Quad::new(
+            Point::new(1, 1),
+            Point::new(3, 3),
+            Point::new(5, 5),
+            Point::new(2, 2),
+        );
+
+        let mut expected = MockDisplay::new();
+        Line::new(Point::new(1, 1), Point::new(5, 5))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut expected);
+
+        let mut display = MockDisplay::new();
+        quad.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display);
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn subpixel_integer_points() {
+        let quad = Rectangle::new(Point::new(5, 5), Point::new(20, 12))
+            .rotate(Point::new(12, 8), Angle::from_degrees(30));
+        let subpixel_quad = Quad::new(
+            SubpixelPoint::from(quad.p1),
+            SubpixelPoint::from(quad.p2),
+            SubpixelPoint::from(quad.p3),
+            SubpixelPoint::from(quad.p4),
+        );
+        let style =
+            PrimitiveStyle::with_stroke(BinaryColor::On, 2).fill_color(Some(BinaryColor::Off));
+
+        assert_eq!(subpixel_quad.bounding_box(), quad.bounding_box());
+        assert!(subpixel_quad
+            .into_styled(style)
+            .into_iter()
+            .eq(quad.into_styled(style).into_iter()));
+    }
+}
@@ -1,12 +1,14 @@
 //! The rectangle primitive. Also good for drawing squares.
 
 use super::super::drawable::{Drawable, Pixel};
-use super::super::transform::Transform;
-use crate::geometry::{Dimensions, Point, Size};
+use super::super::transform::{Axis, Mirror, Rotate, Scale, Transform};
+use crate::geometry::{
+    Angle, ContainsPoint, Dimensions, Point, Rect, Size, SubpixelPoint, SUBPIXEL_ONE,
+};
 use crate::pixelcolor::PixelColor;
-use crate::primitives::Primitive;
-use crate::style::Style;
-use crate::style::WithStyle;
+use crate::primitives::{Primitive, Quad};
+use crate::span::{Area, RowSpans, Span};
+use crate::style::{PrimitiveStyle, Styled};
 use crate::DrawTarget;
 
 /// Rectangle primitive
@@ -21,42 +23,38 @@ use crate::DrawTarget;
 /// use embedded_graphics::prelude::*;
 /// use embedded_graphics::primitives::Rectangle;
 /// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::style::PrimitiveStyle;
 /// # use embedded_graphics::mock_display::MockDisplay;
 /// # let mut display = MockDisplay::default();
 ///
-/// // Default rect from (10, 20) to (30, 40)
-/// Rectangle::new(Point::new(10, 20), Point::new(30, 40)).draw(&mut display);
+/// // Rect from (10, 20) to (30, 40) with a red stroke
+/// Rectangle::new(Point::new(10, 20), Point::new(30, 40))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
+///     .draw(&mut display);
 ///
 /// // Rectangle with styled stroke and fill from (50, 20) to (60, 35)
 /// Rectangle::new(Point::new(50, 20), Point::new(60, 35))
-///     .stroke_color(Some(Rgb565::RED))
-///     .stroke_width(3)
-///     .fill_color(Some(Rgb565::GREEN))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 3).fill_color(Some(Rgb565::GREEN)))
 ///     .draw(&mut display);
 ///
 /// // Rectangle with translation applied
 /// Rectangle::new(Point::new(50, 20), Point::new(60, 35))
 ///     .translate(Point::new(65, 35))
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
 ///     .draw(&mut display);
 /// ```
-#[derive(Debug, Clone, Copy)]
-pub struct Rectangle<C: PixelColor> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
     /// Top left point of the rect
     pub top_left: Point,
 
     /// Bottom right point of the rect
     pub bottom_right: Point,
-
-    /// Object style
-    pub style: Style<C>,
 }
 
-impl<C> Primitive for Rectangle<C> where C: PixelColor {}
+impl Primitive for Rectangle {}
 
-impl<C> Dimensions for Rectangle<C>
-where
-    C: PixelColor,
-{
+impl Dimensions for Rectangle {
     fn top_left(&self) -> Point {
         self.top_left
     }
@@ -68,52 +66,70 @@ where
     fn size(&self) -> Size {
         Size::from_bounding_box(self.top_left, self.bottom_right)
     }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::with_corners(self.top_left(), self.bottom_right())
+    }
+}
+
+impl ContainsPoint for Rectangle {
+    fn contains(&self, point: Point) -> bool {
+        point.x >= self.top_left.x
+            && point.x <= self.bottom_right.x
+            && point.y >= self.top_left.y
+            && point.y <= self.bottom_right.y
+    }
 }
 
-impl<C> Rectangle<C>
+impl<C> ContainsPoint for Styled<Rectangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
-    /// Create a new rectangle from the top left point to the bottom right point with a given style
+    fn contains(&self, point: Point) -> bool {
+        self.primitive.contains(point)
+    }
+}
+
+impl Rectangle {
+    /// Create a new rectangle from the top left point to the bottom right point
     pub fn new(top_left: Point, bottom_right: Point) -> Self {
         Rectangle {
             top_left,
             bottom_right,
-            style: Style::default(),
         }
     }
 }
 
-impl<C> WithStyle<C> for Rectangle<C>
+impl<C> Styled<Rectangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
-    fn style(mut self, style: Style<C>) -> Self {
-        self.style = style;
-
-        self
-    }
-
-    fn stroke_color(mut self, color: Option<C>) -> Self {
-        self.style.stroke_color = color;
-
-        self
+    /// Returns an iterator over the pixels of the rectangle that lie inside the clip rectangle.
+    ///
+    /// Pixels outside the clip rectangle are skipped without being visited, which makes drawing
+    /// large, mostly offscreen rectangles fast.
+    pub fn clipped_iter(&self, clip: &Rect) -> RectangleIterator<C> {
+        self.into_iter().clip(clip)
     }
 
-    fn stroke_width(mut self, width: u32) -> Self {
-        self.style.stroke_width = width;
-
-        self
+    /// Returns an iterator over the horizontal spans of the rectangle.
+    ///
+    /// Each span is a run of pixels with the same color in a single row, which can be drawn with
+    /// [`DrawTarget::draw_hline_spans`].
+    ///
+    /// [`DrawTarget::draw_hline_spans`]: ../../trait.DrawTarget.html#method.draw_hline_spans
+    pub fn spans(&self) -> RectangleSpans<C> {
+        RectangleSpans::new(self.into_iter())
     }
 
-    fn fill_color(mut self, color: Option<C>) -> Self {
-        self.style.fill_color = color;
-
-        self
+    /// Returns an iterator over the horizontal spans of the rectangle that lie inside the clip
+    /// rectangle.
+    pub fn clipped_spans(&self, clip: &Rect) -> RectangleSpans<C> {
+        RectangleSpans::new(self.clipped_iter(clip))
     }
 }
 
-impl<C> IntoIterator for Rectangle<C>
+impl<C> IntoIterator for Styled<Rectangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
@@ -125,7 +141,7 @@ where
     }
 }
 
-impl<'a, C> IntoIterator for &'a Rectangle<C>
+impl<'a, C> IntoIterator for &'a Styled<Rectangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
@@ -133,11 +149,19 @@ where
     type IntoIter = RectangleIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let Rectangle {
+            top_left,
+            bottom_right,
+        } = self.primitive;
+
         RectangleIterator {
-            top_left: self.top_left,
-            bottom_right: self.bottom_right,
+            top_left,
+            bottom_right,
+            x_start: top_left.x,
+            x_end: bottom_right.x,
+            y_end: bottom_right.y,
             style: self.style,
-            p: self.top_left,
+            p: top_left,
         }
     }
 }
@@ -150,10 +174,68 @@ where
 {
     top_left: Point,
     bottom_right: Point,
-    style: Style<C>,
+    /// First column to iterate over
+    x_start: i32,
+    /// Last column to iterate over
+    x_end: i32,
+    /// Last row to iterate over
+    y_end: i32,
+    style: PrimitiveStyle<C>,
     p: Point,
 }
 
+impl<C> RectangleIterator<C>
+where
+    C: PixelColor,
+{
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    fn clip(mut self, clip: &Rect) -> Self {
+        if let Some(clip_bottom_right) = clip.bottom_right() {
+            self.x_start = self.x_start.max(clip.top_left.x);
+            self.x_end = self.x_end.min(clip_bottom_right.x);
+            self.y_end = self.y_end.min(clip_bottom_right.y);
+            self.p = Point::new(self.x_start, self.p.y.max(clip.top_left.y));
+        } else {
+            self.x_end = self.x_start - 1;
+        }
+
+        self
+    }
+
+    /// Returns the spans of a row.
+    ///
+    /// The left and right border end at fixed columns, which are the only points where a row
+    /// changes from stroke to fill.
+    fn row(&self, y: i32) -> RowSpans<C> {
+        let width = i64::from(self.style.stroke_width_i32());
+        let top_left = self.top_left;
+        let bottom_right = self.bottom_right;
+
+        let is_border_row = i64::from(y) < i64::from(top_left.y) + width
+            || i64::from(y) > i64::from(bottom_right.y) - width;
+        let fill_start = i64::from(top_left.x) + width;
+        let right_start = i64::from(bottom_right.x) - width + 1;
+        let has_stroke = self.style.stroke_color.is_some();
+
+        RowSpans::new(
+            y,
+            (self.x_start, self.x_end),
+            &[fill_start, right_start],
+            |x| {
+                let x = i64::from(x);
+
+                if has_stroke && (is_border_row || x < fill_start || x >= right_start) {
+                    Area::Stroke
+                } else {
+                    Area::Fill
+                }
+            },
+            self.style,
+            top_left,
+        )
+    }
+}
+
 impl<C> Iterator for RectangleIterator<C>
 where
     C: PixelColor,
@@ -169,8 +251,8 @@ where
         loop {
             let mut out = None;
 
-            // Finished, i.e. we're below the rect
-            if self.p.y > self.bottom_right.y {
+            // Finished, i.e. we're below the rect or there's nothing to draw
+            if self.p.y > self.y_end || self.x_start > self.x_end {
                 break None;
             }
 
@@ -196,15 +278,15 @@ where
                 ));
             }
             // Fill
-            else if let Some(fill) = self.style.fill_color {
+            else if let Some(fill) = self.style.fill_color_at(self.p, tl) {
                 out = Some(Pixel(self.p, fill));
             }
 
             self.p.x += 1;
 
             // Reached end of row? Jump down one line
-            if self.p.x > self.bottom_right.x {
-                self.p.x = self.top_left.x;
+            if self.p.x > self.x_end {
+                self.p.x = self.x_start;
                 self.p.y += 1;
             }
 
@@ -215,31 +297,77 @@ where
     }
 }
 
-impl<'a, C: 'a> Drawable<C> for &Rectangle<C>
+/// Span iterator for the rows of a rectangle
+///
+/// Each row is split into the left border, the fill and the right border, without checking
+/// every pixel.
+#[derive(Debug, Clone, Copy)]
+pub struct RectangleSpans<C>
 where
     C: PixelColor,
 {
-    fn draw<T: DrawTarget<C>>(self, display: &mut T) {
-        display.draw_rectangle(self);
+    iter: RectangleIterator<C>,
+    row: RowSpans<C>,
+}
+
+impl<C> RectangleSpans<C>
+where
+    C: PixelColor,
+{
+    fn new(iter: RectangleIterator<C>) -> Self {
+        Self {
+            iter,
+            row: RowSpans::empty(iter.style),
+        }
     }
 }
 
-impl<C> Transform for Rectangle<C>
+impl<C> Iterator for RectangleSpans<C>
 where
     C: PixelColor,
 {
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.iter;
+
+        if iter.style.stroke_color.is_none() && iter.style.fill_color.is_none() {
+            return None;
+        }
+
+        loop {
+            if let Some(span) = self.row.next() {
+                break Some(span);
+            }
+
+            if iter.p.y > iter.y_end || iter.x_start > iter.x_end {
+                break None;
+            }
+
+            self.row = iter.row(iter.p.y);
+            iter.p.y += 1;
+        }
+    }
+}
+
+impl<'a, C: 'a> Drawable<C> for &Styled<Rectangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn draw<T: DrawTarget<C>>(self, display: &mut T) {
+        display.draw_rectangle(self);
+    }
+}
+
+impl Transform for Rectangle {
     /// Translate the rect from its current position to a new position by (x, y) pixels, returning
     /// a new `Rectangle`. For a mutating transform, see `translate_mut`.
     ///
     /// ```
     /// # use embedded_graphics::primitives::Rectangle;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::Rgb565;
-    /// #
-    /// # let style = Style::stroke_color(Rgb565::RED);
     /// #
-    /// let rect = Rectangle::new(Point::new(5, 10), Point::new(15, 20))
-    /// #    .style(style);
+    /// let rect = Rectangle::new(Point::new(5, 10), Point::new(15, 20));
     /// let moved = rect.translate(Point::new(10, 10));
     ///
     /// assert_eq!(moved.top_left, Point::new(15, 20));
@@ -249,7 +377,6 @@ where
         Self {
             top_left: self.top_left + by,
             bottom_right: self.bottom_right + by,
-            ..*self
         }
     }
 
@@ -258,12 +385,8 @@ where
     /// ```
     /// # use embedded_graphics::primitives::Rectangle;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::Rgb565;
-    /// #
-    /// # let style = Style::stroke_color(Rgb565::RED);
     /// #
-    /// let mut rect = Rectangle::new(Point::new(5, 10), Point::new(15, 20))
-    /// #    .style(style);
+    /// let mut rect = Rectangle::new(Point::new(5, 10), Point::new(15, 20));
     /// rect.translate_mut(Point::new(10, 10));
     ///
     /// assert_eq!(rect.top_left, Point::new(15, 20));
@@ -277,15 +400,97 @@ where
     }
 }
 
+impl Rotate for Rectangle {
+    type Output = Quad<SubpixelPoint>;
+
+    /// Rotates the rectangle around `center`, returning a [`Quad`].
+    ///
+    /// The corners of the quad are the outer corners of the rectangle's edge pixels, which lie
+    /// half a pixel outside of the pixel centers. A rotated rectangle covers the same pixels as
+    /// its rotated pixels, like a rotated [`Line`] or a mirrored rectangle.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Quad, Rectangle};
+    /// # use embedded_graphics::prelude::*;
+    /// # use embedded_graphics::geometry::{Angle, SubpixelPoint};
+    /// # use embedded_graphics::transform::Rotate;
+    /// #
+    /// let rect = Rectangle::new(Point::new(0, 0), Point::new(9, 4));
+    /// let rotated = rect.rotate(Point::zero(), Angle::from_degrees(90));
+    ///
+    /// // The corners are given in 1/256 pixels
+    /// assert_eq!(
+    ///     rotated,
+    ///     Quad::new(
+    ///         SubpixelPoint::new(128, -128),
+    ///         SubpixelPoint::new(128, 2432),
+    ///         SubpixelPoint::new(-1152, 2432),
+    ///         SubpixelPoint::new(-1152, -128)
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Quad`]: ../quad/struct.Quad.html
+    /// [`Line`]: ../line/struct.Line.html
+    fn rotate(&self, center: Point, angle: Angle) -> Quad<SubpixelPoint> {
+        let half_pixel = SubpixelPoint::new(SUBPIXEL_ONE / 2, SUBPIXEL_ONE / 2);
+        let top_left = SubpixelPoint::from(self.top_left) - half_pixel;
+        let bottom_right = SubpixelPoint::from(self.bottom_right) + half_pixel;
+
+        Quad::new(
+            top_left.rotate(center, angle),
+            SubpixelPoint::new(bottom_right.x, top_left.y).rotate(center, angle),
+            bottom_right.rotate(center, angle),
+            SubpixelPoint::new(top_left.x, bottom_right.y).rotate(center, angle),
+        )
+    }
+}
+
+impl Scale for Rectangle {
+    type Output = Rectangle;
+
+    /// Scales the rectangle relative to `center`.
+    ///
+    /// The outer edges of the rectangle are scaled, so a rectangle scaled by 2 covers twice as
+    /// many pixels in each direction.
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Rectangle {
+        let bottom_right = self.bottom_right + Point::new(1, 1);
+
+        Rectangle::new(
+            self.top_left.scale(center, numerator, denominator),
+            bottom_right.scale(center, numerator, denominator) - Point::new(1, 1),
+        )
+    }
+}
+
+impl Mirror for Rectangle {
+    type Output = Rectangle;
+
+    /// Mirrors the rectangle across `axis`.
+    fn mirror(&self, axis: Axis) -> Rectangle {
+        let a = self.top_left.mirror(axis);
+        let b = self.bottom_right.mirror(axis);
+
+        Rectangle::new(
+            Point::new(a.x.min(b.x), a.y.min(b.y)),
+            Point::new(a.x.max(b.x), a.y.max(b.y)),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_display::{assert_clipped_iter, assert_contains_drawn_points, MockDisplay};
     use crate::pixelcolor::BinaryColor;
     use crate::pixelcolor::{Rgb565, RgbColor};
+    use crate::primitives::Line;
+    use crate::span::Spans;
+    use crate::style::{Pattern, PatternAlignment};
 
     #[test]
     fn dimensions() {
-        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(5, 10), Point::new(15, 30));
+        let rect = Rectangle::new(Point::new(5, 10), Point::new(15, 30));
         let moved = rect.translate(Point::new(-10, -20));
 
         assert_eq!(rect.top_left(), Point::new(5, 10));
@@ -299,7 +504,7 @@ mod tests {
 
     #[test]
     fn it_can_be_translated() {
-        let rect: Rectangle<BinaryColor> = Rectangle::new(Point::new(5, 10), Point::new(15, 20));
+        let rect = Rectangle::new(Point::new(5, 10), Point::new(15, 20));
         let moved = rect.translate(Point::new(10, 15));
 
         assert_eq!(moved.top_left, Point::new(15, 25));
@@ -308,10 +513,9 @@ mod tests {
 
     #[test]
     fn it_draws_unfilled_rect() {
-        let mut rect: RectangleIterator<Rgb565> =
-            Rectangle::new(Point::new(2, 2), Point::new(4, 4))
-                .style(Style::stroke_color(Rgb565::RED))
-                .into_iter();
+        let mut rect = Rectangle::new(Point::new(2, 2), Point::new(4, 4))
+            .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
+            .into_iter();
 
         assert_eq!(rect.next(), Some(Pixel(Point::new(2, 2), Rgb565::RED)));
         assert_eq!(rect.next(), Some(Pixel(Point::new(3, 2), Rgb565::RED)));
@@ -327,16 +531,188 @@ mod tests {
 
     #[test]
     fn it_can_be_negative() {
-        let negative: RectangleIterator<Rgb565> =
-            Rectangle::new(Point::new(-2, -2), Point::new(2, 2))
-                .fill_color(Some(Rgb565::GREEN))
-                .into_iter();
+        let negative = Rectangle::new(Point::new(-2, -2), Point::new(2, 2))
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+            .into_iter();
 
-        let positive: RectangleIterator<Rgb565> =
-            Rectangle::new(Point::new(2, 2), Point::new(6, 6))
-                .fill_color(Some(Rgb565::GREEN))
-                .into_iter();
+        let positive = Rectangle::new(Point::new(2, 2), Point::new(6, 6))
+            .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+            .into_iter();
 
         assert!(negative.eq(positive.map(|Pixel(p, c)| Pixel(p - Point::new(4, 4), c))));
     }
+
+    #[test]
+    fn fill_pattern() {
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(1, 1), Point::new(5, 4))
+            .into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 1)
+                    .fill_color(Some(BinaryColor::Off))
+                    .fill_pattern(Some(Pattern::CHECKERBOARD)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&["       ", " ##### ", " #. .# ", " # . # ", " ##### ",])
+        );
+    }
+
+    #[test]
+    fn shape_aligned_fill_pattern() {
+        let pattern = Pattern::CHECKERBOARD.alignment(PatternAlignment::Shape);
+
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(1, 0), Point::new(3, 1))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On).fill_pattern(Some(pattern)))
+            .draw(&mut display);
+
+        assert_eq!(display, MockDisplay::from_pattern(&[" # #", "  # ",]));
+    }
+
+    #[test]
+    fn contains() {
+        let rect = Rectangle::new(Point::new(3, 5), Point::new(10, 8)).into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 2).fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert_contains_drawn_points(&rect);
+    }
+
+    #[test]
+    fn clipped_iter() {
+        let shapes = [
+            Rectangle::new(Point::new(2, 3), Point::new(20, 9)),
+            Rectangle::new(Point::new(-20, -5), Point::new(8, 30)),
+            Rectangle::new(Point::new(4, 4), Point::new(4, 4)),
+        ];
+
+        for shape in shapes.iter() {
+            let shape = shape.into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 2).fill_color(Some(BinaryColor::Off)),
+            );
+
+            assert_clipped_iter(&shape, |clip| shape.clipped_iter(clip));
+        }
+    }
+
+    #[test]
+    fn spans() {
+        let rect = Rectangle::new(Point::new(2, 3), Point::new(6, 5)).into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off)),
+        );
+
+        assert!(rect.spans().eq([
+            Span::new(3, 2, 6, BinaryColor::On),
+            Span::new(4, 2, 2, BinaryColor::On),
+            Span::new(4, 3, 5, BinaryColor::Off),
+            Span::new(4, 6, 6, BinaryColor::On),
+            Span::new(5, 2, 6, BinaryColor::On),
+        ]
+        .iter()
+        .cloned()));
+    }
+
+    #[test]
+    fn spans_are_row_segments() {
+        let clip = Rect::new(Point::new(4, 3), Size::new(5, 16));
+
+        for stroke_width in 0..5 {
+            for &pattern in [None, Some(Pattern::CHECKERBOARD)].iter() {
+                let rect = Rectangle::new(Point::new(2, 3), Point::new(10, 9)).into_styled(
+                    PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)
+                        .fill_color(Some(BinaryColor::Off))
+                        .fill_pattern(pattern),
+                );
+
+                assert!(rect.spans().eq(Spans::new(&rect)), "{:?}", rect);
+                assert!(
+                    rect.clipped_spans(&clip)
+                        .eq(Spans::new(rect.clipped_iter(&clip))),
+                    "{:?}",
+                    rect
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_matches_rotated_pixels() {
+        let center = Point::new(10, 10);
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        for &rect in [
+            Rectangle::new(Point::new(10, 10), Point::new(10, 10)),
+            Rectangle::new(Point::new(10, 10), Point::new(13, 10)),
+            Rectangle::new(Point::new(4, 6), Point::new(12, 8)),
+        ]
+        .iter()
+        {
+            for &degrees in [0, 90, 180, 270].iter() {
+                let angle = Angle::from_degrees(degrees);
+
+                let mut display = MockDisplay::new();
+                rect.rotate(center, angle)
+                    .into_styled(style)
+                    .draw(&mut display);
+
+                let mut expected = MockDisplay::new();
+                expected.draw_iter(
+                    rect.into_styled(style)
+                        .into_iter()
+                        .map(|Pixel(p, color)| Pixel(p.rotate(center, angle), color)),
+                );
+
+                assert_eq!(display, expected, "{:?} {}", rect, degrees);
+
+                // Lines with zero length aren't drawn
+                if rect.size().width > 1 {
+                    let mut lines = MockDisplay::new();
+                    for y in rect.top_left.y..=rect.bottom_right.y {
+                        Line::new(
+                            Point::new(rect.top_left.x, y),
+                            Point::new(rect.bottom_right.x, y),
+                        )
+                        .rotate(center, angle)
+                        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+                        .draw(&mut lines);
+                    }
+
+                    assert_eq!(display, lines, "{:?} {}", rect, degrees);
+                }
+            }
+
+            let mut display = MockDisplay::new();
+            rect.rotate(center, Angle::from_degrees(180))
+                .into_styled(style)
+                .draw(&mut display);
+
+            let mut expected = MockDisplay::new();
+            rect.mirror(Axis::Vertical(center.x))
+                .mirror(Axis::Horizontal(center.y))
+                .into_styled(style)
+                .draw(&mut expected);
+
+            assert_eq!(display, expected, "{:?}", rect);
+        }
+    }
+
+    #[test]
+    fn scale_and_mirror() {
+        let rect = Rectangle::new(Point::new(2, 3), Point::new(5, 4));
+
+        assert_eq!(
+            rect.scale(Point::new(2, 3), 2, 1),
+            Rectangle::new(Point::new(2, 3), Point::new(9, 6))
+        );
+        assert_eq!(
+            rect.mirror(Axis::Vertical(0)),
+            Rectangle::new(Point::new(-5, 3), Point::new(-2, 4))
+        );
+        assert_eq!(
+            rect.mirror(Axis::Horizontal(10)),
+            Rectangle::new(Point::new(2, 16), Point::new(5, 17))
+        );
+    }
 }
@@ -1,17 +1,21 @@
 //! The triangle primitive.
 
 use super::super::drawable::{Drawable, Pixel};
-use super::super::transform::Transform;
-use crate::geometry::{Dimensions, Point, Size};
+use super::super::transform::{Axis, Mirror, Rotate, Scale, Transform};
+use crate::geometry::{Angle, ContainsPoint, Dimensions, Point, Rect, Size, SubpixelPoint, Vertex};
 use crate::pixelcolor::PixelColor;
-use crate::primitives::line::{Line, LineIterator};
+use crate::primitives::edge::{self, Edge, EdgeIterator, EdgeSpans};
+use crate::primitives::line::Line;
 use crate::primitives::Primitive;
-use crate::style::Style;
-use crate::style::WithStyle;
+use crate::span::Span;
+use crate::style::{PrimitiveStyle, Styled};
 use crate::DrawTarget;
 
 /// Triangle primitive
 ///
+/// The vertices of a triangle are [`Point`]s by default. Triangles can also be created from
+/// [`SubpixelPoint`]s to position them with sub-pixel precision.
+///
 /// # Examples
 ///
 /// The [macro examples](../../macro.egtriangle.html) make for more concise code.
@@ -22,54 +26,59 @@ use crate::DrawTarget;
 /// use embedded_graphics::prelude::*;
 /// use embedded_graphics::primitives::Triangle;
 /// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::style::PrimitiveStyle;
 /// # use embedded_graphics::mock_display::MockDisplay;
 /// # let mut display = MockDisplay::default();
 ///
-/// // Default triangle with no styling
+/// // Filled triangle
 /// Triangle::new(Point::new(10, 20), Point::new(30, 40), Point::new(50, 60))
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
 ///     .draw(&mut display);
 ///
 /// // Triangle with styled stroke from (50, 20) to (60, 35)
 /// Triangle::new(Point::new(50, 20), Point::new(60, 35), Point::new(70, 80))
-///     .stroke_color(Some(Rgb565::RED))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
 ///     .draw(&mut display);
 ///
 /// // Triangle with translation applied
 /// Triangle::new(Point::new(50, 20), Point::new(60, 35), Point::new(70, 80))
 ///     .translate(Point::new(65, 35))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::BLUE, 1))
 ///     .draw(&mut display);
 /// ```
-#[derive(Debug, Clone, Copy)]
-pub struct Triangle<C: PixelColor> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Triangle<P = Point> {
     /// First point of the triangle
-    pub p1: Point,
+    pub p1: P,
 
     /// Second point of the triangle
-    pub p2: Point,
+    pub p2: P,
 
     /// Third point of the triangle
-    pub p3: Point,
-
-    /// Object style
-    pub style: Style<C>,
+    pub p3: P,
 }
 
-impl<C> Primitive for Triangle<C> where C: PixelColor {}
+impl<P: Vertex> Primitive for Triangle<P> {}
 
-impl<C> Dimensions for Triangle<C>
-where
-    C: PixelColor,
-{
+impl<P: Vertex> Dimensions for Triangle<P> {
+    /// Returns the top left corner of the bounding box.
+    ///
+    /// Sub-pixel vertices are rounded to the nearest pixel.
     fn top_left(&self) -> Point {
-        let &x = [self.p1.x, self.p2.x, self.p3.x].iter().min().unwrap();
-        let &y = [self.p1.y, self.p2.y, self.p3.y].iter().min().unwrap();
+        let points = self.rounded_points();
+        let x = points.iter().map(|p| p.x).min().unwrap();
+        let y = points.iter().map(|p| p.y).min().unwrap();
 
         Point::new(x, y)
     }
 
+    /// Returns the bottom right corner of the bounding box.
+    ///
+    /// Sub-pixel vertices are rounded to the nearest pixel.
     fn bottom_right(&self) -> Point {
-        let &x = [self.p1.x, self.p2.x, self.p3.x].iter().max().unwrap();
-        let &y = [self.p1.y, self.p2.y, self.p3.y].iter().max().unwrap();
+        let points = self.rounded_points();
+        let x = points.iter().map(|p| p.x).max().unwrap();
+        let y = points.iter().map(|p| p.y).max().unwrap();
 
         Point::new(x, y)
     }
@@ -77,71 +86,128 @@ where
     fn size(&self) -> Size {
         Size::from_bounding_box(self.top_left(), self.bottom_right())
     }
-}
 
-impl<C> Triangle<C>
-where
-    C: PixelColor,
-{
-    /// Create a new triangle with a given style
-    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
-        Triangle {
-            p1,
-            p2,
-            p3,
-            style: Style::default(),
-        }
+    fn bounding_box(&self) -> Rect {
+        Rect::with_corners(self.top_left(), self.bottom_right())
     }
 }
 
-impl<C> WithStyle<C> for Triangle<C>
-where
-    C: PixelColor,
-{
-    fn style(mut self, style: Style<C>) -> Self {
-        self.style = style;
+impl<P> Triangle<P> {
+    /// Create a new triangle
+    pub fn new(p1: P, p2: P, p3: P) -> Self {
+        Triangle { p1, p2, p3 }
+    }
+}
 
-        self
+impl<P: Vertex> Triangle<P> {
+    fn subpixel_points(&self) -> [SubpixelPoint; 3] {
+        [self.p1.into(), self.p2.into(), self.p3.into()]
     }
 
-    fn stroke_color(mut self, color: Option<C>) -> Self {
-        self.style.stroke_color = color;
+    fn rounded_points(&self) -> [Point; 3] {
+        let [p1, p2, p3] = self.subpixel_points();
 
-        self
+        [p1.round(), p2.round(), p3.round()]
     }
 
-    fn stroke_width(mut self, width: u32) -> Self {
-        self.style.stroke_width = width;
+    /// Returns the edges of the triangle, wound so that the inside is on the positive side.
+    ///
+    /// `None` is returned if the triangle has no area.
+    fn edges(&self) -> Option<[Edge; 3]> {
+        let [p1, p2, p3] = self.subpixel_points();
 
-        self
+        let area = Edge::new(p1, p2).value(p3);
+        if area == 0 {
+            return None;
+        }
+
+        let (p2, p3) = if area > 0 { (p2, p3) } else { (p3, p2) };
+
+        Some([Edge::new(p1, p2), Edge::new(p2, p3), Edge::new(p3, p1)])
     }
 
-    fn fill_color(mut self, color: Option<C>) -> Self {
-        self.style.fill_color = color;
+    /// Returns the line used to draw a triangle without an area.
+    ///
+    /// The line connects the two outermost points of the triangle.
+    fn degenerate_line(&self) -> Line<P> {
+        let points = [self.p1, self.p2, self.p3];
+        let (start, end) = edge::outermost_pair(&self.subpixel_points(), &[(0, 1), (0, 2), (1, 2)]);
 
-        self
+        Line::new(points[start], points[end])
     }
 }
 
-fn sort_two_yx(p1: Point, p2: Point) -> (Point, Point) {
-    if p1.y < p2.y || (p1.y == p2.y && p1.x < p2.x) {
-        (p1, p2)
-    } else {
-        (p2, p1)
+impl<P: Vertex> ContainsPoint for Triangle<P> {
+    /// Returns `true` if the point is inside the triangle according to the top-left fill rule.
+    ///
+    /// Triangles without an area contain the points on the line they are drawn as.
+    fn contains(&self, point: Point) -> bool {
+        match self.edges() {
+            Some(edges) => edges.iter().all(|edge| edge.contains(point)),
+            None => self.degenerate_line().contains(point),
+        }
+    }
+}
+
+impl<C, P> ContainsPoint for Styled<Triangle<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    /// Returns `true` if the point is inside the triangle according to the top-left fill rule.
+    ///
+    /// Stroked triangles also contain the points on their bottom and right edges. Triangles without an
+    /// area contain the points on the line they are drawn as, if the stroke width isn't zero.
+    fn contains(&self, point: Point) -> bool {
+        match self.edges() {
+            Some(edges) => edges.iter().all(|edge| edge.contains(point)),
+            None => self.style.stroke_width > 0 && self.primitive.contains(point),
+        }
     }
 }
 
-fn sort_yx(p1: Point, p2: Point, p3: Point) -> (Point, Point, Point) {
-    let (y1, y2) = sort_two_yx(p1, p2);
-    let (y1, y3) = sort_two_yx(p3, y1);
-    let (y2, y3) = sort_two_yx(y3, y2);
+impl<C, P> Styled<Triangle<P>, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+    P: Vertex,
+{
+    /// Returns an iterator over the pixels of the triangle that lie inside the clip rectangle.
+    ///
+    /// Rows and columns outside the clip rectangle are skipped without being visited, which makes
+    /// drawing large, mostly offscreen triangles fast.
+    pub fn clipped_iter(&self, clip: &Rect) -> TriangleIterator<C> {
+        self.into_iter().clip(clip)
+    }
+
+    /// Returns the edges of the triangle, which contain the points on them if it has a stroke.
+    fn edges(&self) -> Option<[Edge; 3]> {
+        let mut edges = self.primitive.edges()?;
+        edge::close_stroked(&mut edges, &self.style);
+
+        Some(edges)
+    }
+
+    /// Returns an iterator over the horizontal spans of the triangle.
+    ///
+    /// Each span is a run of pixels with the same color in a single row, which can be drawn with
+    /// [`DrawTarget::draw_hline_spans`].
+    ///
+    /// [`DrawTarget::draw_hline_spans`]: ../../trait.DrawTarget.html#method.draw_hline_spans
+    pub fn spans(&self) -> TriangleSpans<C> {
+        TriangleSpans(EdgeSpans::new(self.into_iter().0))
+    }
 
-    (y1, y2, y3)
+    /// Returns an iterator over the horizontal spans of the triangle that lie inside the clip
+    /// rectangle.
+    pub fn clipped_spans(&self, clip: &Rect) -> TriangleSpans<C> {
+        TriangleSpans(EdgeSpans::new(self.clipped_iter(clip).0))
+    }
 }
 
-impl<C> IntoIterator for Triangle<C>
+impl<C, P> IntoIterator for Styled<Triangle<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
     type Item = Pixel<C>;
     type IntoIter = TriangleIterator<C>;
@@ -151,128 +217,51 @@ where
     }
 }
 
-impl<'a, C> IntoIterator for &'a Triangle<C>
+impl<'a, C, P> IntoIterator for &'a Styled<Triangle<P>, PrimitiveStyle<C>>
 where
     C: PixelColor,
+    P: Vertex,
 {
     type Item = Pixel<C>;
     type IntoIter = TriangleIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let (v1, v2, v3) = sort_yx(self.p1, self.p2, self.p3);
-
-        let mut line_a = Line::new(v1, v2)
-            .stroke_color(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
-        let mut line_b = Line::new(v1, v3)
-            .stroke_color(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
-        let mut line_c = Line::new(v2, v3)
-            .stroke_color(self.style.stroke_color.or(self.style.fill_color))
-            .into_iter();
-        let next_ac = line_a.next().or_else(|| line_c.next()).map(|p| p.0);
-        let next_b = line_b.next().map(|p| p.0);
-
-        TriangleIterator {
-            line_a,
-            line_b,
-            line_c,
-            cur_ac: None,
-            cur_b: None,
-            next_ac,
-            next_b,
-            x: 0,
-            min_y: v1.y,
-            max_y: v3.y,
-            style: self.style,
-        }
+        let triangle = &self.primitive;
+
+        TriangleIterator(match self.edges() {
+            Some(edges) => EdgeIterator::new(
+                &edges,
+                triangle.top_left(),
+                triangle.bottom_right(),
+                self.style,
+            ),
+            // Triangles without an area are drawn as a line between the two outermost points
+            None => EdgeIterator::with_line(triangle.degenerate_line(), self.style),
+        })
     }
 }
 
-enum IterState {
-    Border(Point),
-    LeftRight(Point, Point),
-    None,
-}
-
-/// Pixel iterator for each pixel in the triangle border
+/// Pixel iterator for each pixel in the triangle
+///
+/// Triangles are rasterized according to the top-left fill rule: a pixel which lies exactly on
+/// an edge is only part of the triangle if the edge is a top or left edge. Triangles which share
+/// an edge, for example in a triangle mesh, will tile the plane without gaps or overlapping
+/// pixels.
+///
+/// The fill rule only applies to triangles without a stroke. The stroke is drawn on the inside of
+/// the triangle, is `stroke_width` pixels wide and includes the pixels on all edges.
 #[derive(Debug, Clone, Copy)]
-pub struct TriangleIterator<C: PixelColor>
+pub struct TriangleIterator<C>(EdgeIterator<C>)
 where
-    C: PixelColor,
-{
-    line_a: LineIterator<C>,
-    line_b: LineIterator<C>,
-    line_c: LineIterator<C>,
-    cur_ac: Option<Point>,
-    cur_b: Option<Point>,
-    next_ac: Option<Point>,
-    next_b: Option<Point>,
-    x: i32,
-    max_y: i32,
-    min_y: i32,
-    style: Style<C>,
-}
+    C: PixelColor;
 
 impl<C> TriangleIterator<C>
 where
     C: PixelColor,
 {
-    fn update_ac(&mut self) -> IterState {
-        if let Some(ac) = self.next_ac {
-            self.cur_ac = Some(ac);
-            self.next_ac = self
-                .line_a
-                .next()
-                .or_else(|| self.line_c.next())
-                .map(|p| p.0);
-            self.x = 0;
-            IterState::Border(ac)
-        } else {
-            IterState::None
-        }
-    }
-
-    fn update_b(&mut self) -> IterState {
-        if let Some(b) = self.next_b {
-            self.cur_b = Some(b);
-            self.next_b = self.line_b.next().map(|p| p.0);
-            self.x = 0;
-            IterState::Border(b)
-        } else {
-            IterState::None
-        }
-    }
-
-    fn points(&mut self) -> IterState {
-        match (self.cur_ac, self.cur_b) {
-            // Point of ac line or b line is missing
-            (None, _) => self.update_ac(),
-            (_, None) => self.update_b(),
-            // Both points are present
-            (Some(ac), Some(b)) => {
-                match (self.next_ac, self.next_b) {
-                    (Some(n_ac), Some(n_b)) => {
-                        // If y component differs, take new points from edge until both side have
-                        // the same y
-                        if n_ac.y < n_b.y {
-                            self.update_ac()
-                        } else if n_ac.y > n_b.y {
-                            self.update_b()
-                        } else {
-                            let (l, r) = sort_two_yx(ac, b);
-                            IterState::LeftRight(l, r)
-                        }
-                    }
-                    (None, Some(_)) => self.update_b(),
-                    (Some(_), None) => self.update_ac(),
-                    (None, None) => {
-                        let (l, r) = sort_two_yx(ac, b);
-                        IterState::LeftRight(l, r)
-                    }
-                }
-            }
-        }
+    /// Restricts the iterator to the pixels inside the clip rectangle.
+    fn clip(self, clip: &Rect) -> Self {
+        TriangleIterator(self.0.clip(clip))
     }
 }
 
@@ -283,45 +272,32 @@ where
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.style.stroke_color.is_none() && self.style.fill_color.is_none() {
-            return None;
-        }
+        self.0.next()
+    }
+}
 
-        loop {
-            match self.points() {
-                IterState::Border(point) => {
-                    // Draw edges of the triangle
-                    if let Some(color) = self.style.stroke_color.or_else(|| self.style.fill_color) {
-                        if point.x >= 0 && point.y >= 0 {
-                            return Some(Pixel(point, color));
-                        }
-                    }
-                }
-                IterState::LeftRight(l, r) => {
-                    // Fill the space between the left and right points
-                    if let Some(color) = self.style.fill_color {
-                        if l.x >= 0 && l.y >= 0 && r.x >= 0 && r.y >= 0 && l.x + self.x < r.x {
-                            let point = Point::new(l.x + self.x, l.y);
-                            self.x += 1;
-                            return Some(Pixel(point, color));
-                        } else if l.x + self.x >= r.x {
-                            // We reached the right edge, move on to next row
-                            self.cur_ac = None;
-                            self.cur_b = None;
-                        }
-                    } else {
-                        // We don't want to fill the triangle
-                        self.cur_ac = None;
-                        self.cur_b = None;
-                    }
-                }
-                IterState::None => return None,
-            }
-        }
+/// Span iterator for the rows of a triangle
+///
+/// The rows are restricted to the inside of the triangle and split into stroke and fill
+/// segments, without checking every pixel. Triangles without an area are drawn as a line, whose
+/// pixels are merged into spans.
+#[derive(Debug, Clone, Copy)]
+pub struct TriangleSpans<C>(EdgeSpans<C>)
+where
+    C: PixelColor;
+
+impl<C> Iterator for TriangleSpans<C>
+where
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
     }
 }
 
-impl<'a, C: 'a> Drawable<C> for &Triangle<C>
+impl<'a, C: 'a> Drawable<C> for &Styled<Triangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
@@ -330,22 +306,24 @@ where
     }
 }
 
-impl<C> Transform for Triangle<C>
+impl<'a, C: 'a> Drawable<C> for &Styled<Triangle<SubpixelPoint>, PrimitiveStyle<C>>
 where
     C: PixelColor,
 {
+    fn draw<T: DrawTarget<C>>(self, display: &mut T) {
+        display.draw_hline_spans(self.clipped_spans(&display.display_area()));
+    }
+}
+
+impl<P: Vertex> Transform for Triangle<P> {
     /// Translate the triangle from its current position to a new position by (x, y) pixels,
     /// returning a new `Triangle`. For a mutating transform, see `translate_mut`.
     ///
     /// ```
     /// # use embedded_graphics::primitives::Triangle;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::BinaryColor;
-    /// #
-    /// # let style = Style::stroke_color(BinaryColor::On);
     /// #
-    /// let tri = Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(8, 15))
-    /// #    .style(style);
+    /// let tri = Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(8, 15));
     /// let moved = tri.translate(Point::new(10, 10));
     ///
     /// assert_eq!(moved.p1, Point::new(15, 20));
@@ -357,7 +335,6 @@ where
             p1: self.p1 + by,
             p2: self.p2 + by,
             p3: self.p3 + by,
-            ..*self
         }
     }
 
@@ -366,12 +343,8 @@ where
     /// ```
     /// # use embedded_graphics::primitives::Triangle;
     /// # use embedded_graphics::prelude::*;
-    /// # use embedded_graphics::pixelcolor::BinaryColor;
     /// #
-    /// # let style = Style::stroke_color(BinaryColor::On);
-    /// #
-    /// let mut tri = Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(10, 15))
-    /// #    .style(style);
+    /// let mut tri = Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(10, 15));
     /// tri.translate_mut(Point::new(10, 10));
     ///
     /// assert_eq!(tri.p1, Point::new(15, 20));
@@ -387,15 +360,58 @@ where
     }
 }
 
+impl<P: Vertex> Rotate for Triangle<P> {
+    type Output = Triangle<P>;
+
+    /// Rotates the triangle around `center`.
+    fn rotate(&self, center: Point, angle: Angle) -> Triangle<P> {
+        Triangle::new(
+            self.p1.rotate(center, angle),
+            self.p2.rotate(center, angle),
+            self.p3.rotate(center, angle),
+        )
+    }
+}
+
+impl<P: Vertex> Scale for Triangle<P> {
+    type Output = Triangle<P>;
+
+    /// Scales the triangle relative to `center`.
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Triangle<P> {
+        Triangle::new(
+            self.p1.scale(center, numerator, denominator),
+            self.p2.scale(center, numerator, denominator),
+            self.p3.scale(center, numerator, denominator),
+        )
+    }
+}
+
+impl<P: Vertex> Mirror for Triangle<P> {
+    type Output = Triangle<P>;
+
+    /// Mirrors the triangle across `axis`.
+    ///
+    /// Mirroring reverses the winding of the triangle, which doesn't affect how it is drawn.
+    fn mirror(&self, axis: Axis) -> Triangle<P> {
+        Triangle::new(
+            self.p1.mirror(axis),
+            self.p2.mirror(axis),
+            self.p3.mirror(axis),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_display::{assert_clipped_iter, assert_contains_drawn_points, MockDisplay};
     use crate::pixelcolor::BinaryColor;
+    use crate::span::Spans;
+    use crate::style::Pattern;
 
     #[test]
     fn dimensions() {
-        let tri: Triangle<BinaryColor> =
-            Triangle::new(Point::new(5, 10), Point::new(15, 25), Point::new(5, 25));
+        let tri = Triangle::new(Point::new(5, 10), Point::new(15, 25), Point::new(5, 25));
         let moved = tri.translate(Point::new(-10, -11));
 
         assert_eq!(tri.p1, Point::new(5, 10));
@@ -411,8 +427,7 @@ mod tests {
 
     #[test]
     fn it_can_be_translated() {
-        let tri: Triangle<BinaryColor> =
-            Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(10, 15));
+        let tri = Triangle::new(Point::new(5, 10), Point::new(15, 20), Point::new(10, 15));
         let moved = tri.translate(Point::new(5, 10));
 
         assert_eq!(moved.p1, Point::new(10, 20));
@@ -422,51 +437,411 @@ mod tests {
 
     #[test]
     fn it_draws_unfilled_tri_line_y() {
-        let mut tri: TriangleIterator<BinaryColor> =
-            Triangle::new(Point::new(2, 2), Point::new(2, 4), Point::new(2, 4))
-                .style(Style::stroke_color(BinaryColor::On))
-                .into_iter();
+        let mut tri = Triangle::new(Point::new(2, 2), Point::new(2, 4), Point::new(2, 4))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .into_iter();
 
-        // Nodes are returned twice. first line a and b yield the same point.
-        // After that line a ends where line c starts.
-        assert_eq!(tri.next(), Some(Pixel(Point::new(2, 2), BinaryColor::On)));
         assert_eq!(tri.next(), Some(Pixel(Point::new(2, 2), BinaryColor::On)));
         assert_eq!(tri.next(), Some(Pixel(Point::new(2, 3), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(2, 3), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(2, 4), BinaryColor::On)));
         assert_eq!(tri.next(), Some(Pixel(Point::new(2, 4), BinaryColor::On)));
         assert_eq!(tri.next(), None);
     }
 
     #[test]
     fn it_draws_unfilled_tri_line_x() {
-        let mut tri: TriangleIterator<BinaryColor> =
-            Triangle::new(Point::new(2, 2), Point::new(4, 2), Point::new(4, 2))
-                .style(Style::stroke_color(BinaryColor::On))
-                .into_iter();
+        let mut tri = Triangle::new(Point::new(2, 2), Point::new(4, 2), Point::new(4, 2))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .into_iter();
 
         assert_eq!(tri.next(), Some(Pixel(Point::new(2, 2), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(2, 2), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(3, 2), BinaryColor::On)));
         assert_eq!(tri.next(), Some(Pixel(Point::new(3, 2), BinaryColor::On)));
         assert_eq!(tri.next(), Some(Pixel(Point::new(4, 2), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(4, 2), BinaryColor::On)));
         assert_eq!(tri.next(), None);
     }
 
     #[test]
-    #[ignore]
     fn it_can_be_negative() {
-        let mut tri: TriangleIterator<BinaryColor> =
-            Triangle::new(Point::new(-2, -2), Point::new(2, 0), Point::new(-2, 0))
-                .style(Style::stroke_color(BinaryColor::On))
-                .into_iter();
-
-        // Only the bottom of the triangle should be visible
-        assert_eq!(tri.next(), Some(Pixel(Point::new(0, 0), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(2, 0), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(1, 0), BinaryColor::On)));
-        assert_eq!(tri.next(), Some(Pixel(Point::new(2, 0), BinaryColor::On)));
-        assert_eq!(tri.next(), None);
+        let style =
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off));
+
+        let positive = Triangle::new(Point::new(2, 2), Point::new(9, 4), Point::new(4, 8))
+            .into_styled(style)
+            .into_iter();
+
+        let negative = Triangle::new(Point::new(-8, -8), Point::new(-1, -6), Point::new(-6, -2))
+            .into_styled(style)
+            .into_iter();
+
+        assert!(negative.eq(positive.map(|Pixel(p, c)| Pixel(p - Point::new(10, 10), c))));
+    }
+
+    #[test]
+    fn vertex_order_doesnt_matter() {
+        let (a, b, c) = (Point::new(3, 1), Point::new(12, 7), Point::new(1, 10));
+
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let reference = Triangle::new(a, b, c).into_styled(style);
+
+        for &(p1, p2, p3) in [(a, c, b), (b, a, c), (b, c, a), (c, a, b), (c, b, a)].iter() {
+            let triangle = Triangle::new(p1, p2, p3).into_styled(style);
+
+            assert!(triangle.into_iter().eq(reference.into_iter()));
+        }
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let mut display = MockDisplay::new();
+        Triangle::new(Point::new(0, 0), Point::new(8, 0), Point::new(0, 8))
+            .into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "#########",
+                "#.....## ",
+                "#....##  ",
+                "#...##   ",
+                "#..##    ",
+                "#.##     ",
+                "###      ",
+                "##       ",
+                "#        ",
+            ])
+        );
+    }
+
+    #[test]
+    fn stroke_includes_vertices() {
+        let (p1, p2, p3) = (Point::new(0, 0), Point::new(8, 0), Point::new(0, 8));
+        let triangle = Triangle::new(p1, p2, p3);
+
+        let mut display = MockDisplay::new();
+        triangle
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display);
+
+        for &vertex in [p1, p2, p3].iter() {
+            assert_eq!(display.get_pixel(vertex), Some(BinaryColor::On));
+        }
+        assert!(triangle
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .contains(p2));
+
+        // The fill without a stroke still follows the top-left fill rule
+        let mut display = MockDisplay::new();
+        triangle
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display);
+
+        assert_eq!(display.get_pixel(p1), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(p2), None);
+        assert_eq!(display.get_pixel(p3), None);
+    }
+
+    #[test]
+    fn stroke_width() {
+        let mut display = MockDisplay::new();
+        Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(0, 10))
+            .into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 2).fill_color(Some(BinaryColor::Off)),
+            )
+            .draw(&mut display);
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "###########",
+                "########## ",
+                "##....###  ",
+                "##...###   ",
+                "##..###    ",
+                "##.###     ",
+                "#####      ",
+                "####       ",
+                "###        ",
+                "##         ",
+                "#          ",
+            ])
+        );
+    }
+
+    /// Display which counts how often each pixel was drawn.
+    struct CountingDisplay([[u8; 64]; 64]);
+
+    impl CountingDisplay {
+        fn new() -> Self {
+            Self([[0; 64]; 64])
+        }
+
+        fn draw_triangles(&mut self, triangles: &[(Point, Point, Point)]) {
+            for &(p1, p2, p3) in triangles {
+                Triangle::new(p1, p2, p3)
+                    .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                    .draw(self);
+            }
+        }
+
+        fn max_count(&self) -> u8 {
+            self.0
+                .iter()
+                .flat_map(|row| row.iter())
+                .cloned()
+                .max()
+                .unwrap()
+        }
+
+        fn covered(&self) -> usize {
+            self.0
+                .iter()
+                .flat_map(|row| row.iter())
+                .filter(|&&count| count > 0)
+                .count()
+        }
+    }
+
+    impl DrawTarget<BinaryColor> for CountingDisplay {
+        fn draw_pixel(&mut self, pixel: Pixel<BinaryColor>) {
+            let Pixel(Point { x, y }, _) = pixel;
+            self.0[y as usize][x as usize] += 1;
+        }
+
+        fn size(&self) -> Size {
+            Size::new(64, 64)
+        }
+    }
+
+    #[test]
+    fn shared_edge_square() {
+        let (a, b, c, d) = (
+            Point::new(5, 5),
+            Point::new(25, 5),
+            Point::new(25, 25),
+            Point::new(5, 25),
+        );
+
+        for triangles in [[(a, b, c), (a, c, d)], [(a, b, d), (b, c, d)]].iter() {
+            let mut display = CountingDisplay::new();
+            display.draw_triangles(triangles);
+
+            assert_eq!(display.max_count(), 1);
+            // The bottom and right edges of the square aren't part of the triangles
+            assert_eq!(display.covered(), 20 * 20);
+        }
+    }
+
+    #[test]
+    fn shared_edge_no_overlap() {
+        let points = [
+            Point::new(3, 2),
+            Point::new(40, 9),
+            Point::new(21, 31),
+            Point::new(58, 44),
+            Point::new(7, 60),
+            Point::new(33, 61),
+        ];
+
+        // Every pair of triangles shares an edge with the previous one
+        let triangles = [
+            (points[0], points[1], points[2]),
+            (points[1], points[2], points[3]),
+            (points[2], points[3], points[4]),
+            (points[3], points[4], points[5]),
+        ];
+
+        let mut display = CountingDisplay::new();
+        display.draw_triangles(&triangles);
+
+        assert_eq!(display.max_count(), 1);
+    }
+
+    #[test]
+    fn fan_has_no_gaps_or_overlap() {
+        let center = Point::new(32, 32);
+        let rim = [
+            Point::new(62, 32),
+            Point::new(53, 53),
+            Point::new(32, 62),
+            Point::new(11, 53),
+            Point::new(2, 32),
+            Point::new(11, 11),
+            Point::new(32, 2),
+            Point::new(53, 11),
+        ];
+
+        let mut fan = CountingDisplay::new();
+        for i in 0..rim.len() {
+            fan.draw_triangles(&[(center, rim[i], rim[(i + 1) % rim.len()])]);
+        }
+        assert_eq!(fan.max_count(), 1);
+
+        // The same octagon triangulated from one of its corners must cover the same pixels
+        let mut corner_fan = CountingDisplay::new();
+        for i in 1..rim.len() - 1 {
+            corner_fan.draw_triangles(&[(rim[0], rim[i], rim[i + 1])]);
+        }
+        assert_eq!(corner_fan.max_count(), 1);
+
+        assert!(fan.0.iter().eq(corner_fan.0.iter()));
+    }
+
+    #[test]
+    fn contains() {
+        let triangles = [
+            ((2, 2), (20, 5), (8, 25)),
+            ((8, 25), (20, 5), (2, 2)),
+            ((0, 0), (10, 0), (0, 10)),
+            ((25, 3), (25, 20), (3, 20)),
+            ((2, 2), (12, 7), (22, 12)),
+        ];
+
+        for &(p1, p2, p3) in triangles.iter() {
+            let triangle = Triangle::new(Point::from(p1), Point::from(p2), Point::from(p3))
+                .into_styled(
+                    PrimitiveStyle::with_stroke(BinaryColor::On, 1)
+                        .fill_color(Some(BinaryColor::Off)),
+                );
+
+            assert_contains_drawn_points(&triangle);
+        }
+    }
+
+    #[test]
+    fn clipped_iter() {
+        let shapes = [
+            Triangle::new(Point::new(2, 2), Point::new(20, 5), Point::new(8, 25)),
+            Triangle::new(Point::new(-20, -5), Point::new(30, 8), Point::new(-2, 30)),
+            Triangle::new(Point::new(-5, 2), Point::new(10, 8), Point::new(25, 14)),
+        ];
+
+        for shape in shapes.iter() {
+            let shape = shape.into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 2).fill_color(Some(BinaryColor::Off)),
+            );
+
+            assert_clipped_iter(&shape, |clip| shape.clipped_iter(clip));
+        }
+    }
+
+    #[test]
+    fn spans_are_row_segments() {
+        let shapes = [
+            Triangle::new(Point::new(2, 2), Point::new(20, 5), Point::new(8, 25)),
+            Triangle::new(Point::new(1, 1), Point::new(20, 1), Point::new(1, 20)),
+            Triangle::new(Point::new(3, 3), Point::new(12, 3), Point::new(20, 3)),
+        ];
+        let clip = Rect::new(Point::new(4, 3), Size::new(12, 16));
+
+        for shape in shapes.iter() {
+            for stroke_width in 0..5 {
+                for &pattern in [None, Some(Pattern::CHECKERBOARD)].iter() {
+                    let shape = shape.into_styled(
+                        PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width)
+                            .fill_color(Some(BinaryColor::Off))
+                            .fill_pattern(pattern),
+                    );
+
+                    assert!(shape.spans().eq(Spans::new(&shape)), "{:?}", shape);
+                    assert!(
+                        shape
+                            .clipped_spans(&clip)
+                            .eq(Spans::new(shape.clipped_iter(&clip))),
+                        "{:?}",
+                        shape
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transforms() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(4, 0), Point::new(0, 2));
+
+        assert_eq!(
+            triangle.rotate(Point::zero(), Angle::from_degrees(90)),
+            Triangle::new(Point::new(0, 0), Point::new(0, 4), Point::new(-2, 0))
+        );
+        assert_eq!(
+            triangle.scale(Point::zero(), 3, 2),
+            Triangle::new(Point::new(0, 0), Point::new(6, 0), Point::new(0, 3))
+        );
+        assert_eq!(
+            triangle.mirror(Axis::Horizontal(1)),
+            Triangle::new(Point::new(0, 2), Point::new(4, 2), Point::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn subpixel_integer_points() {
+        let points = [Point::new(3, 2), Point::new(40, 9), Point::new(21, 31)];
+        let style =
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off));
+
+        let triangle = Triangle::new(points[0], points[1], points[2]);
+        let subpixel_triangle = Triangle::new(
+            SubpixelPoint::from(points[0]),
+            SubpixelPoint::from(points[1]),
+            SubpixelPoint::from(points[2]),
+        );
+
+        assert_eq!(subpixel_triangle.bounding_box(), triangle.bounding_box());
+        assert!(subpixel_triangle
+            .into_styled(style)
+            .into_iter()
+            .eq(triangle.into_styled(style).into_iter()));
+    }
+
+    #[test]
+    fn subpixel_shared_edge_no_overlap() {
+        // 3.5, 2.25
+        let a = SubpixelPoint::new(3 * 256 + 128, 2 * 256 + 64);
+        let b = SubpixelPoint::new(40 * 256 + 37, 9 * 256 + 200);
+        let c = SubpixelPoint::new(21 * 256 + 128, 31 * 256 + 128);
+        let d = SubpixelPoint::new(58 * 256 + 3, 44 * 256 + 250);
+
+        let mut display = CountingDisplay::new();
+        for &(p1, p2, p3) in [(a, b, c), (b, c, d)].iter() {
+            Triangle::new(p1, p2, p3)
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .draw(&mut display);
+        }
+
+        assert_eq!(display.max_count(), 1);
+    }
+
+    #[test]
+    fn subpixel_offset() {
+        let triangle = Triangle::new(
+            SubpixelPoint::new(0, 0),
+            SubpixelPoint::new(6 * 256, 0),
+            SubpixelPoint::new(0, 6 * 256),
+        );
+
+        let mut moved = triangle;
+        moved.p1 += SubpixelPoint::new(128, 128);
+        moved.p2 += SubpixelPoint::new(128, 128);
+        moved.p3 += SubpixelPoint::new(128, 128);
+
+        let mut display = MockDisplay::new();
+        moved
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display);
+
+        // The pixel centers on the hypotenuse belong to the neighboring triangle
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "      ", //
+                " #####", //
+                " #### ", //
+                " ###  ", //
+                " ##   ", //
+                " #    ", //
+            ])
+        );
     }
 }
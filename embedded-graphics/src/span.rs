@@ -0,0 +1,415 @@
+//! Scanline spans.
+//!
+//! Filled primitives and text are made of horizontal runs of pixels with the same color. A
+//! [`Span`] describes one of these runs, which allows display drivers to draw it with a single
+//! horizontal line or address window command instead of setting each pixel individually.
+//!
+//! Spans are created by the `spans` and `clipped_spans` methods on the primitives and by the
+//! [`Spans`] adapter, which merges the pixels of any pixel iterator into spans. They are drawn
+//! with [`DrawTarget::draw_hline_spans`].
+//!
+//! # Examples
+//!
+//! ## Draw a rectangle using spans
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! use embedded_graphics::primitives::Rectangle;
+//! use embedded_graphics::span::Span;
+//! use embedded_graphics::style::PrimitiveStyle;
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display = MockDisplay::default();
+//!
+//! let rect = Rectangle::new(Point::new(2, 2), Point::new(5, 3))
+//!     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+//!
+//! let mut spans = rect.spans();
+//! assert_eq!(spans.next(), Some(Span::new(2, 2, 5, BinaryColor::On)));
+//! assert_eq!(spans.next(), Some(Span::new(3, 2, 5, BinaryColor::On)));
+//! assert_eq!(spans.next(), None);
+//!
+//! display.draw_hline_spans(rect.spans());
+//! ```
+//!
+//! [`Span`]: struct.Span.html
+//! [`Spans`]: struct.Spans.html
+//! [`DrawTarget::draw_hline_spans`]: ../trait.DrawTarget.html#method.draw_hline_spans
+
+use crate::drawable::Pixel;
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::style::PrimitiveStyle;
+
+/// A horizontal run of pixels with the same color.
+///
+/// The span covers all pixels in row `y` from `x_start` to `x_end`, both inclusive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Span<C: PixelColor> {
+    /// Row of the span
+    pub y: i32,
+
+    /// First column of the span
+    pub x_start: i32,
+
+    /// Last column of the span
+    pub x_end: i32,
+
+    /// Color of all pixels in the span
+    pub color: C,
+}
+
+impl<C> Span<C>
+where
+    C: PixelColor,
+{
+    /// Creates a new span.
+    pub fn new(y: i32, x_start: i32, x_end: i32, color: C) -> Self {
+        Self {
+            y,
+            x_start,
+            x_end,
+            color,
+        }
+    }
+
+    /// Returns an iterator over the pixels in the span.
+    pub fn pixels(&self) -> SpanPixels<C> {
+        SpanPixels {
+            span: *self,
+            x: self.x_start,
+        }
+    }
+}
+
+/// Pixel iterator for each pixel in a span
+#[derive(Copy, Clone, Debug)]
+pub struct SpanPixels<C: PixelColor> {
+    span: Span<C>,
+    x: i32,
+}
+
+impl<C> Iterator for SpanPixels<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x > self.span.x_end {
+            return None;
+        }
+
+        let point = Point::new(self.x, self.span.y);
+        self.x += 1;
+
+        Some(Pixel(point, self.span.color))
+    }
+}
+
+/// Span iterator which merges the pixels of a pixel iterator into spans
+///
+/// Consecutive pixels are merged into the same span if they are in the same row, directly next to
+/// each other from left to right and have the same color. The primitive iterators return their
+/// pixels row by row, which makes every horizontal run of a primitive a single span.
+#[derive(Copy, Clone, Debug)]
+pub struct Spans<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    pixels: I,
+    /// First pixel of the next span
+    pending: Option<Pixel<C>>,
+}
+
+impl<I, C> Spans<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    /// Creates a span iterator from a pixel iterator.
+    pub fn new<T>(pixels: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I, Item = Pixel<C>>,
+    {
+        let mut pixels = pixels.into_iter();
+        let pending = pixels.next();
+
+        Self { pixels, pending }
+    }
+}
+
+impl<I, C> Iterator for Spans<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Pixel(start, color) = self.pending.take()?;
+        let mut span = Span::new(start.y, start.x, start.x, color);
+
+        for pixel in &mut self.pixels {
+            let Pixel(point, next_color) = pixel;
+
+            if point.y != span.y || point.x != span.x_end + 1 || next_color != color {
+                self.pending = Some(pixel);
+                break;
+            }
+
+            span.x_end += 1;
+        }
+
+        Some(span)
+    }
+}
+
+/// Maximum number of breakpoints in a row of a primitive.
+const MAX_BREAKPOINTS: usize = 8;
+
+/// Part of a styled primitive a pixel belongs to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Area {
+    Outside,
+    Stroke,
+    Fill,
+}
+
+/// Span iterator for a single row of a styled primitive.
+///
+/// The row is divided into segments at the breakpoints, which primitives calculate from their
+/// scanline bounds. Each segment is classified once, which creates the spans without visiting
+/// every pixel. Only fills with a pattern are split into smaller spans.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct RowSpans<C>
+where
+    C: PixelColor,
+{
+    y: i32,
+    /// First column and area of each segment
+    segments: [(i32, Area); MAX_BREAKPOINTS + 1],
+    len: usize,
+    /// Index of the current segment
+    index: usize,
+    /// Next column in the current segment
+    x: i64,
+    /// Last column of the row
+    x_end: i32,
+    style: PrimitiveStyle<C>,
+    /// Origin of the fill pattern
+    origin: Point,
+}
+
+impl<C> RowSpans<C>
+where
+    C: PixelColor,
+{
+    /// Creates an iterator for the columns `x_start..=x_end` of row `y`.
+    ///
+    /// The area of a pixel may only change at one of the breakpoints, which is the first column
+    /// of a new segment. Breakpoints outside the row are ignored.
+    pub(crate) fn new<F>(
+        y: i32,
+        (x_start, x_end): (i32, i32),
+        breakpoints: &[i64],
+        area: F,
+        style: PrimitiveStyle<C>,
+        origin: Point,
+    ) -> Self
+    where
+        F: Fn(i32) -> Area,
+    {
+        let mut row = Self::empty(style);
+        row.y = y;
+        row.x = i64::from(x_start);
+        row.x_end = x_end;
+        row.origin = origin;
+
+        if x_start > x_end {
+            return row;
+        }
+
+        let mut starts = [x_start; MAX_BREAKPOINTS + 1];
+        let mut len = 1;
+        for &x in breakpoints {
+            if x > i64::from(x_start) && x <= i64::from(x_end) {
+                starts[len] = x as i32;
+                len += 1;
+            }
+        }
+        starts[..len].sort_unstable();
+
+        for &start in &starts[..len] {
+            let area = area(start);
+
+            if row.len == 0 || row.segments[row.len - 1].1 != area {
+                row.segments[row.len] = (start, area);
+                row.len += 1;
+            }
+        }
+
+        row
+    }
+
+    /// Creates an iterator without any spans.
+    pub(crate) fn empty(style: PrimitiveStyle<C>) -> Self {
+        Self {
+            y: 0,
+            segments: [(0, Area::Outside); MAX_BREAKPOINTS + 1],
+            len: 0,
+            index: 0,
+            x: 0,
+            x_end: 0,
+            style,
+            origin: Point::zero(),
+        }
+    }
+
+    /// Returns the last column of the current segment.
+    fn segment_end(&self) -> i64 {
+        if self.index + 1 < self.len {
+            i64::from(self.segments[self.index + 1].0) - 1
+        } else {
+            i64::from(self.x_end)
+        }
+    }
+
+    fn is_set(&self, x: i64) -> bool {
+        self.style
+            .fill_pattern
+            .is_none_or(|pattern| pattern.is_set(Point::new(x as i32, self.y), self.origin))
+    }
+}
+
+impl<C> Iterator for RowSpans<C>
+where
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.len {
+            let area = self.segments[self.index].1;
+            let end = self.segment_end();
+            let mut start = self.x;
+
+            let color = match area {
+                Area::Outside => None,
+                Area::Stroke => self.style.stroke_color,
+                Area::Fill => self.style.fill_color,
+            };
+
+            if area == Area::Fill && self.style.fill_pattern.is_some() {
+                // Split the segment into the runs of set pattern pixels
+                while start <= end && !self.is_set(start) {
+                    start += 1;
+                }
+
+                self.x = start;
+                while self.x <= end && self.is_set(self.x) {
+                    self.x += 1;
+                }
+            } else {
+                self.x = end + 1;
+            }
+
+            let span_end = self.x - 1;
+
+            if self.x > end {
+                self.index += 1;
+
+                if self.index < self.len {
+                    self.x = i64::from(self.segments[self.index].0);
+                }
+            }
+
+            if let Some(color) = color {
+                if start <= span_end {
+                    return Some(Span::new(self.y, start as i32, span_end as i32, color));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::BinaryColor;
+    use crate::style::Pattern;
+
+    #[test]
+    fn merges_adjacent_pixels() {
+        let pixels = [
+            Pixel(Point::new(1, 0), BinaryColor::On),
+            Pixel(Point::new(2, 0), BinaryColor::On),
+            Pixel(Point::new(3, 0), BinaryColor::Off),
+            Pixel(Point::new(5, 0), BinaryColor::Off),
+            Pixel(Point::new(6, 1), BinaryColor::Off),
+            Pixel(Point::new(7, 1), BinaryColor::Off),
+        ];
+
+        let spans = Spans::new(pixels.iter().cloned());
+
+        assert!(spans.eq([
+            Span::new(0, 1, 2, BinaryColor::On),
+            Span::new(0, 3, 3, BinaryColor::Off),
+            Span::new(0, 5, 5, BinaryColor::Off),
+            Span::new(1, 6, 7, BinaryColor::Off),
+        ]
+        .iter()
+        .cloned()));
+    }
+
+    #[test]
+    fn row_segments() {
+        let style =
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off));
+        let area = |x| match x {
+            2..=3 | 9 => Area::Stroke,
+            4..=8 => Area::Fill,
+            _ => Area::Outside,
+        };
+
+        let row = RowSpans::new(5, (0, 9), &[2, 4, 9, 20, -1], area, style, Point::zero());
+        assert!(row.eq([
+            Span::new(5, 2, 3, BinaryColor::On),
+            Span::new(5, 4, 8, BinaryColor::Off),
+            Span::new(5, 9, 9, BinaryColor::On),
+        ]
+        .iter()
+        .cloned()));
+
+        let style = style.fill_pattern(Some(Pattern::CHECKERBOARD));
+        let row = RowSpans::new(5, (3, 8), &[2, 4, 9], area, style, Point::zero());
+        assert!(row.eq([
+            Span::new(5, 3, 3, BinaryColor::On),
+            Span::new(5, 5, 5, BinaryColor::Off),
+            Span::new(5, 7, 7, BinaryColor::Off),
+        ]
+        .iter()
+        .cloned()));
+
+        assert_eq!(
+            RowSpans::new(5, (3, 2), &[], area, style, Point::zero()).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn span_pixels() {
+        let span = Span::new(4, -1, 1, BinaryColor::On);
+
+        assert!(span.pixels().eq([
+            Pixel(Point::new(-1, 4), BinaryColor::On),
+            Pixel(Point::new(0, 4), BinaryColor::On),
+            Pixel(Point::new(1, 4), BinaryColor::On),
+        ]
+        .iter()
+        .cloned()));
+    }
+}
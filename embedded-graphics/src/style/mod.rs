@@ -0,0 +1,21 @@
+//! Styling structs to customise the look of objects.
+//!
+//! Primitives and text only describe geometry. A style is attached to them with
+//! [`Primitive::into_styled`] or the `text_*` and `eg*` macros, which returns a [`Styled`] object
+//! that can be drawn. Primitives are styled with a [`PrimitiveStyle`], text is styled with a
+//! [`TextStyle`].
+//!
+//! [`Primitive::into_styled`]: ../primitives/trait.Primitive.html#method.into_styled
+//! [`Styled`]: struct.Styled.html
+//! [`PrimitiveStyle`]: struct.PrimitiveStyle.html
+//! [`TextStyle`]: struct.TextStyle.html
+
+mod pattern;
+mod primitive_style;
+mod styled;
+mod text_style;
+
+pub use self::pattern::{Pattern, PatternAlignment};
+pub use self::primitive_style::PrimitiveStyle;
+pub use self::styled::Styled;
+pub use self::text_style::TextStyle;
@@ -0,0 +1,228 @@
+use crate::geometry::{Point, Size};
+
+/// Coordinate space used to position a [`Pattern`].
+///
+/// [`Pattern`]: struct.Pattern.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PatternAlignment {
+    /// The pattern is aligned to the origin of the display.
+    ///
+    /// Adjacent shapes filled with the same pattern line up seamlessly.
+    Screen,
+
+    /// The pattern is aligned to the top left corner of the bounding box of the filled shape.
+    ///
+    /// The pattern moves with the shape when it is translated.
+    Shape,
+}
+
+/// Bitmap pattern used to fill the inside of primitives.
+///
+/// A pattern is a 1 bit per pixel image of up to 8x8 pixels which is tiled across the fill area
+/// of a primitive. Pixels with a set bit are drawn using the fill color, all other pixels are
+/// left transparent. This is mostly useful on [`BinaryColor`] displays to create shades of gray or
+/// to tell areas apart which would otherwise be drawn in the same color.
+///
+/// Each byte in the pattern data represents one row, the most significant bit being the leftmost
+/// pixel. Patterns smaller than 8x8 pixels only use the upper rows and the most significant bits
+/// of each row.
+///
+/// # Examples
+///
+/// ## Fill a rectangle with a builtin pattern
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::egrectangle;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::style::Pattern;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// egrectangle!(
+///     (0, 0),
+///     (31, 15),
+///     stroke_color = Some(BinaryColor::On),
+///     fill_color = Some(BinaryColor::On),
+///     fill_pattern = Some(Pattern::CHECKERBOARD)
+/// )
+/// .draw(&mut display);
+/// ```
+///
+/// ## Use a custom 4x4 pattern that moves with the shape
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::primitives::Circle;
+/// use embedded_graphics::style::{Pattern, PatternAlignment, PrimitiveStyle};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let bricks = Pattern::with_size(
+///     [
+///         0b1111_0000,
+///         0b1000_0000,
+///         0b1111_0000,
+///         0b0010_0000,
+///         0, 0, 0, 0,
+///     ],
+///     Size::new(4, 4),
+/// )
+/// .alignment(PatternAlignment::Shape);
+///
+/// Circle::new(Point::new(20, 20), 15)
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On).fill_pattern(Some(bricks)))
+///     .draw(&mut display);
+/// ```
+///
+/// [`BinaryColor`]: ../pixelcolor/enum.BinaryColor.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    rows: [u8; 8],
+    width: u8,
+    height: u8,
+    alignment: PatternAlignment,
+}
+
+impl Pattern {
+    /// 50% checkerboard pattern.
+    pub const CHECKERBOARD: Pattern =
+        Pattern::new([0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55]);
+
+    /// Diagonal lines running from the bottom left to the top right.
+    pub const DIAGONAL_HATCH: Pattern =
+        Pattern::new([0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80]);
+
+    /// Two sets of crossing diagonal lines.
+    pub const CROSS_HATCH: Pattern = Pattern::new([0x81, 0x42, 0x24, 0x18, 0x18, 0x24, 0x42, 0x81]);
+
+    /// Sparse, evenly spaced dots.
+    pub const DOTS: Pattern = Pattern::new([0x88, 0x00, 0x22, 0x00, 0x88, 0x00, 0x22, 0x00]);
+
+    /// Creates a screen aligned 8x8 pixel pattern.
+    pub const fn new(rows: [u8; 8]) -> Self {
+        Self {
+            rows,
+            width: 8,
+            height: 8,
+            alignment: PatternAlignment::Screen,
+        }
+    }
+
+    /// Creates a screen aligned pattern with the given size.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `size` is zero or larger than 8 in either dimension.
+    pub fn with_size(rows: [u8; 8], size: Size) -> Self {
+        assert!(
+            size.width > 0 && size.width <= 8,
+            "pattern width must be between 1 and 8"
+        );
+        assert!(
+            size.height > 0 && size.height <= 8,
+            "pattern height must be between 1 and 8"
+        );
+
+        Self {
+            rows,
+            width: size.width as u8,
+            height: size.height as u8,
+            alignment: PatternAlignment::Screen,
+        }
+    }
+
+    /// Sets the alignment of the pattern.
+    pub fn alignment(mut self, alignment: PatternAlignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Returns the size of the pattern.
+    pub fn size(&self) -> Size {
+        Size::new(u32::from(self.width), u32::from(self.height))
+    }
+
+    /// Returns `true` if the pattern bit for a point is set.
+    ///
+    /// `origin` is the top left corner of the bounding box of the shape being filled, which is
+    /// only used for patterns with [`PatternAlignment::Shape`].
+    ///
+    /// [`PatternAlignment::Shape`]: enum.PatternAlignment.html#variant.Shape
+    pub fn is_set(&self, point: Point, origin: Point) -> bool {
+        let point = match self.alignment {
+            PatternAlignment::Screen => point,
+            PatternAlignment::Shape => point - origin,
+        };
+
+        let x = point.x.rem_euclid(i32::from(self.width));
+        let y = point.y.rem_euclid(i32::from(self.height));
+
+        self.rows[y as usize] & (0x80 >> x) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkerboard() {
+        let p = Pattern::CHECKERBOARD;
+
+        assert!(p.is_set(Point::new(0, 0), Point::zero()));
+        assert!(!p.is_set(Point::new(1, 0), Point::zero()));
+        assert!(!p.is_set(Point::new(0, 1), Point::zero()));
+        assert!(p.is_set(Point::new(1, 1), Point::zero()));
+        assert!(p.is_set(Point::new(8, 8), Point::zero()));
+    }
+
+    #[test]
+    fn negative_coordinates_wrap() {
+        let p = Pattern::DIAGONAL_HATCH;
+
+        assert!(p.is_set(Point::new(-1, 0), Point::zero()));
+        assert!(p.is_set(Point::new(-2, 1), Point::zero()));
+        assert!(!p.is_set(Point::new(-1, 1), Point::zero()));
+    }
+
+    #[test]
+    fn custom_size() {
+        let p = Pattern::with_size([0b1000_0000, 0, 0, 0, 0, 0, 0, 0xFF], Size::new(3, 2));
+
+        assert_eq!(p.size(), Size::new(3, 2));
+        assert!(p.is_set(Point::new(0, 0), Point::zero()));
+        assert!(p.is_set(Point::new(3, 2), Point::zero()));
+        assert!(p.is_set(Point::new(6, 4), Point::zero()));
+        assert!(!p.is_set(Point::new(1, 0), Point::zero()));
+
+        // The unused eighth row must never show up
+        assert!(!p.is_set(Point::new(0, 7), Point::zero()));
+    }
+
+    #[test]
+    fn shape_alignment() {
+        let screen = Pattern::DOTS;
+        let shape = Pattern::DOTS.alignment(PatternAlignment::Shape);
+        let origin = Point::new(3, 5);
+
+        assert!(!screen.is_set(origin, origin));
+        assert!(shape.is_set(origin, origin));
+        assert!(shape.is_set(origin + Point::new(4, 0), origin));
+        assert!(shape.is_set(origin + Point::new(2, 2), origin));
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern width must be between 1 and 8")]
+    fn too_wide() {
+        Pattern::with_size([0; 8], Size::new(9, 8));
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern height must be between 1 and 8")]
+    fn zero_height() {
+        Pattern::with_size([0; 8], Size::new(8, 0));
+    }
+}
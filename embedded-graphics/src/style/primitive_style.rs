@@ -0,0 +1,176 @@
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::style::Pattern;
+use core::convert::TryFrom;
+
+/// Style properties for primitives
+///
+/// # Examples
+///
+/// ## Draw a circle with a thick stroke and a fill
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::primitives::Circle;
+/// use embedded_graphics::style::PrimitiveStyle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = PrimitiveStyle::with_stroke(Rgb565::RED, 3).fill_color(Some(Rgb565::GREEN));
+///
+/// Circle::new(Point::new(20, 20), 15)
+///     .into_styled(style)
+///     .draw(&mut display);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PrimitiveStyle<C: PixelColor> {
+    /// Fill colour of the primitive
+    pub fill_color: Option<C>,
+
+    /// Stroke (border/line) color of the primitive
+    pub stroke_color: Option<C>,
+
+    /// Stroke width
+    pub stroke_width: u32,
+
+    /// Fill pattern
+    ///
+    /// If set, only the pixels of the fill area that correspond to a set bit in the pattern are
+    /// drawn using the fill color.
+    pub fill_pattern: Option<Pattern>,
+}
+
+impl<C> PrimitiveStyle<C>
+where
+    C: PixelColor,
+{
+    /// Creates a style with a stroke of the given color and width and no fill.
+    pub fn with_stroke(stroke_color: C, stroke_width: u32) -> Self {
+        Self {
+            stroke_color: Some(stroke_color),
+            stroke_width,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a style with a fill of the given color and no stroke.
+    pub fn with_fill(fill_color: C) -> Self {
+        Self {
+            fill_color: Some(fill_color),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the stroke color.
+    pub fn stroke_color(mut self, color: Option<C>) -> Self {
+        self.stroke_color = color;
+
+        self
+    }
+
+    /// Sets the stroke width.
+    ///
+    /// A stroke with a width of zero will not be rendered
+    pub fn stroke_width(mut self, width: u32) -> Self {
+        self.stroke_width = width;
+
+        self
+    }
+
+    /// Sets the fill color.
+    pub fn fill_color(mut self, color: Option<C>) -> Self {
+        self.fill_color = color;
+
+        self
+    }
+
+    /// Sets the fill pattern.
+    pub fn fill_pattern(mut self, pattern: Option<Pattern>) -> Self {
+        self.fill_pattern = pattern;
+
+        self
+    }
+
+    /// Returns the stroke width as an `i32`.
+    ///
+    /// If the stroke width is too large to fit into an `i32` the maximum value
+    /// for an `i32` is returned instead.
+    pub(crate) fn stroke_width_i32(&self) -> i32 {
+        i32::try_from(self.stroke_width).unwrap_or(i32::max_value())
+    }
+
+    /// Returns the fill color for a point, taking the fill pattern into account.
+    ///
+    /// `origin` is the top left corner of the bounding box of the filled object.
+    pub(crate) fn fill_color_at(&self, point: Point, origin: Point) -> Option<C> {
+        match self.fill_pattern {
+            Some(pattern) if !pattern.is_set(point, origin) => None,
+            _ => self.fill_color,
+        }
+    }
+}
+
+impl<C> Default for PrimitiveStyle<C>
+where
+    C: PixelColor,
+{
+    fn default() -> Self {
+        Self {
+            fill_color: None,
+            stroke_color: None,
+            stroke_width: 1,
+            fill_pattern: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::BinaryColor;
+
+    #[test]
+    fn stroke_width_i32() {
+        let mut style: PrimitiveStyle<BinaryColor> = PrimitiveStyle::default();
+        style.stroke_width = 1;
+        assert_eq!(style.stroke_width_i32(), 1);
+
+        style.stroke_width = 0x7FFFFFFF;
+        assert_eq!(style.stroke_width_i32(), 0x7FFFFFFF);
+
+        style.stroke_width = 0x80000000;
+        assert_eq!(style.stroke_width_i32(), 0x7FFFFFFF);
+
+        style.stroke_width = 0xFFFFFFFF;
+        assert_eq!(style.stroke_width_i32(), 0x7FFFFFFF);
+    }
+
+    #[test]
+    fn fill_color_at() {
+        let mut style = PrimitiveStyle::with_fill(BinaryColor::On);
+        assert_eq!(
+            style.fill_color_at(Point::new(1, 0), Point::zero()),
+            Some(BinaryColor::On)
+        );
+
+        style.fill_pattern = Some(Pattern::CHECKERBOARD);
+        assert_eq!(
+            style.fill_color_at(Point::new(0, 0), Point::zero()),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(style.fill_color_at(Point::new(1, 0), Point::zero()), None);
+    }
+
+    #[test]
+    fn constructors() {
+        let stroke = PrimitiveStyle::with_stroke(BinaryColor::On, 3);
+        assert_eq!(stroke.stroke_color, Some(BinaryColor::On));
+        assert_eq!(stroke.stroke_width, 3);
+        assert_eq!(stroke.fill_color, None);
+
+        let fill = PrimitiveStyle::with_fill(BinaryColor::Off);
+        assert_eq!(fill.stroke_color, None);
+        assert_eq!(fill.fill_color, Some(BinaryColor::Off));
+    }
+}
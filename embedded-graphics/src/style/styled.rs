@@ -0,0 +1,140 @@
+use crate::geometry::{Angle, Dimensions, Point, Rect, Size};
+use crate::pixelcolor::PixelColor;
+use crate::style::PrimitiveStyle;
+use crate::transform::{Axis, Mirror, Rotate, Scale, Transform};
+
+/// A styled primitive or text
+///
+/// `Styled` combines an object which only describes geometry, like a [`Circle`] or a string of
+/// text, with a style that defines how it is drawn. It is usually created by calling
+/// [`into_styled`] on a primitive or by using one of the `eg*` or `text_*` macros. The same
+/// geometry can be drawn with several styles by styling a copy of it for each style.
+///
+/// # Examples
+///
+/// ## Draw the same rectangle with two different styles
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::primitives::Rectangle;
+/// use embedded_graphics::style::PrimitiveStyle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let rect = Rectangle::new(Point::new(2, 2), Point::new(12, 8));
+///
+/// rect.into_styled(PrimitiveStyle::with_fill(BinaryColor::Off))
+///     .draw(&mut display);
+/// rect.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut display);
+/// ```
+///
+/// [`Circle`]: ../primitives/circle/struct.Circle.html
+/// [`into_styled`]: ../primitives/trait.Primitive.html#method.into_styled
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Styled<T, S> {
+    /// Primitive or text
+    pub primitive: T,
+
+    /// Style
+    pub style: S,
+}
+
+impl<T, S> Styled<T, S> {
+    /// Creates a styled object.
+    pub fn new(primitive: T, style: S) -> Self {
+        Self { primitive, style }
+    }
+}
+
+/// Styled primitives have the same dimensions as the primitive.
+///
+/// The dimensions of styled text are implemented by the text types, because the text style can
+/// change the size of the text.
+impl<T, C> Dimensions for Styled<T, PrimitiveStyle<C>>
+where
+    T: Dimensions,
+    C: PixelColor,
+{
+    fn top_left(&self) -> Point {
+        self.primitive.top_left()
+    }
+
+    fn bottom_right(&self) -> Point {
+        self.primitive.bottom_right()
+    }
+
+    fn size(&self) -> Size {
+        self.primitive.size()
+    }
+
+    fn bounding_box(&self) -> Rect {
+        self.primitive.bounding_box()
+    }
+}
+
+impl<T, S> Transform for Styled<T, S>
+where
+    T: Transform,
+    S: Clone,
+{
+    /// Translates the styled object by (x, y) pixels, returning a new object with the same style.
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            primitive: self.primitive.translate(by),
+            style: self.style.clone(),
+        }
+    }
+
+    /// Translates the styled object by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.primitive.translate_mut(by);
+
+        self
+    }
+}
+
+impl<T, S> Rotate for Styled<T, S>
+where
+    T: Rotate,
+    S: Clone,
+{
+    type Output = Styled<T::Output, S>;
+
+    /// Rotates the primitive around `center`, keeping the style.
+    fn rotate(&self, center: Point, angle: Angle) -> Self::Output {
+        Styled::new(self.primitive.rotate(center, angle), self.style.clone())
+    }
+}
+
+impl<T, S> Scale for Styled<T, S>
+where
+    T: Scale,
+    S: Clone,
+{
+    type Output = Styled<T::Output, S>;
+
+    /// Scales the primitive relative to `center`, keeping the style.
+    ///
+    /// The stroke width isn't scaled.
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Self::Output {
+        Styled::new(
+            self.primitive.scale(center, numerator, denominator),
+            self.style.clone(),
+        )
+    }
+}
+
+impl<T, S> Mirror for Styled<T, S>
+where
+    T: Mirror,
+    S: Clone,
+{
+    type Output = Styled<T::Output, S>;
+
+    /// Mirrors the primitive across `axis`, keeping the style.
+    fn mirror(&self, axis: Axis) -> Self::Output {
+        Styled::new(self.primitive.mirror(axis), self.style.clone())
+    }
+}
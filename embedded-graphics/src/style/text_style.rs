@@ -0,0 +1,176 @@
+use crate::geometry::Point;
+use crate::pixelcolor::PixelColor;
+use crate::style::Pattern;
+
+/// Style properties for text
+///
+/// # Examples
+///
+/// ## Draw yellow text on a blue background
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::Font6x8;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::style::TextStyle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = TextStyle::with_text_color(Rgb565::YELLOW).background_color(Some(Rgb565::BLUE));
+///
+/// Font6x8::render_str("Hello Rust!")
+///     .into_styled(style)
+///     .draw(&mut display);
+/// ```
+///
+/// ## Draw underlined bold text
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::{Font, Font8x16};
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::style::TextStyle;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = TextStyle::with_text_color(Rgb565::WHITE)
+///     .bold(true)
+///     .underline(true);
+///
+/// Font8x16::render_str("Warning")
+///     .into_styled(style)
+///     .draw(&mut display);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextStyle<C: PixelColor> {
+    /// Color of the glyphs
+    ///
+    /// If no text color is set the glyphs are drawn using `BinaryColor::On`.
+    pub text_color: Option<C>,
+
+    /// Background color of the text
+    pub background_color: Option<C>,
+
+    /// Background pattern
+    ///
+    /// If set, only the pixels of the background that correspond to a set bit in the pattern are
+    /// drawn using the background color.
+    pub background_pattern: Option<Pattern>,
+
+    /// Draw glyphs in faux bold
+    ///
+    /// Faux bold text is created by drawing each glyph a second time, offset by one pixel to the
+    /// right. This makes each glyph one pixel wider, which is included in the size of the styled
+    /// text. The advance widths aren't changed. The offset is scaled with the horizontal scale
+    /// factor of the text.
+    pub bold: bool,
+
+    /// Draw a line below the baseline
+    ///
+    /// The position of the line is defined by the font, see
+    /// [`GlyphSource::underline_position`].
+    ///
+    /// [`GlyphSource::underline_position`]: ../fonts/trait.GlyphSource.html#method.underline_position
+    pub underline: bool,
+
+    /// Draw a line through the text
+    ///
+    /// The position of the line is defined by the font, see
+    /// [`GlyphSource::strikethrough_position`].
+    ///
+    /// [`GlyphSource::strikethrough_position`]: ../fonts/trait.GlyphSource.html#method.strikethrough_position
+    pub strikethrough: bool,
+}
+
+impl<C> TextStyle<C>
+where
+    C: PixelColor,
+{
+    /// Creates a style with the given text color and a transparent background.
+    pub fn with_text_color(text_color: C) -> Self {
+        Self {
+            text_color: Some(text_color),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the text color.
+    pub fn text_color(mut self, color: Option<C>) -> Self {
+        self.text_color = color;
+
+        self
+    }
+
+    /// Sets the background color.
+    pub fn background_color(mut self, color: Option<C>) -> Self {
+        self.background_color = color;
+
+        self
+    }
+
+    /// Sets the background pattern.
+    pub fn background_pattern(mut self, pattern: Option<Pattern>) -> Self {
+        self.background_pattern = pattern;
+
+        self
+    }
+
+    /// Enables or disables faux bold text.
+    ///
+    /// ```rust
+    /// use embedded_graphics::fonts::{Font, Font6x8};
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use embedded_graphics::prelude::*;
+    /// use embedded_graphics::style::TextStyle;
+    ///
+    /// let text = Font6x8::render_str("Bold");
+    /// let style = TextStyle::with_text_color(BinaryColor::On);
+    /// assert_eq!(text.into_styled(style).size(), Size::new(24, 8));
+    /// assert_eq!(text.into_styled(style.bold(true)).size(), Size::new(25, 8));
+    /// ```
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+
+        self
+    }
+
+    /// Enables or disables the underline.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+
+        self
+    }
+
+    /// Enables or disables the strikethrough line.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+
+        self
+    }
+
+    /// Returns the background color for a point, taking the background pattern into account.
+    ///
+    /// `origin` is the top left corner of the text.
+    pub(crate) fn background_color_at(&self, point: Point, origin: Point) -> Option<C> {
+        match self.background_pattern {
+            Some(pattern) if !pattern.is_set(point, origin) => None,
+            _ => self.background_color,
+        }
+    }
+}
+
+impl<C> Default for TextStyle<C>
+where
+    C: PixelColor,
+{
+    fn default() -> Self {
+        Self {
+            text_color: None,
+            background_color: None,
+            background_pattern: None,
+            bold: false,
+            underline: false,
+            strikethrough: false,
+        }
+    }
+}
@@ -1,6 +1,55 @@
 //! Transformations for graphics objects
+//!
+//! All objects can be moved with the [`Transform`] trait. Vector primitives like lines, triangles
+//! and circles can additionally be rotated, scaled and mirrored with the [`Rotate`], [`Scale`]
+//! and [`Mirror`] traits. These transforms are applied to the points that define the primitive,
+//! which keeps the result exact and avoids resampling artifacts.
+//!
+//! Some transforms change the type of the object. A rotated [`Rectangle`] is no longer aligned to
+//! the axes and is returned as a [`Quad`].
+//!
+//! Whole drawings can be transformed with an [`Affine`] matrix, either by wrapping a pixel
+//! iterator in [`TransformedPixels`] or by drawing to a [`TransformedDisplay`]. These transforms
+//! move each pixel separately, so rotations and scale factors larger than one can leave gaps
+//! between the pixels. Prefer the primitive transforms above for vector graphics.
+//!
+//! # Examples
+//!
+//! ## Draw the hand of a clock
+//!
+//! ```rust
+//! use embedded_graphics::prelude::*;
+//! use embedded_graphics::geometry::Angle;
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! use embedded_graphics::primitives::Line;
+//! use embedded_graphics::transform::Rotate;
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display = MockDisplay::default();
+//!
+//! let center = Point::new(32, 32);
+//! let minutes = 15;
+//!
+//! // The hand points up at 0 minutes and is rotated clockwise
+//! Line::new(center, center - Point::new(0, 20))
+//!     .rotate(center, Angle::from_degrees(minutes * 6))
+//!     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+//!     .draw(&mut display);
+//! ```
+//!
+//! [`Transform`]: trait.Transform.html
+//! [`Rotate`]: trait.Rotate.html
+//! [`Scale`]: trait.Scale.html
+//! [`Mirror`]: trait.Mirror.html
+//! [`Rectangle`]: ../primitives/rectangle/struct.Rectangle.html
+//! [`Quad`]: ../primitives/quad/struct.Quad.html
+//! [`Affine`]: ../geometry/struct.Affine.html
+//! [`TransformedPixels`]: struct.TransformedPixels.html
+//! [`TransformedDisplay`]: struct.TransformedDisplay.html
 
-use crate::geometry::Point;
+use crate::drawable::Pixel;
+use crate::geometry::{Affine, Angle, Point, Rect, Size, SubpixelPoint, SUBPIXEL_BITS};
+use crate::pixelcolor::PixelColor;
+use crate::DrawTarget;
 
 /// Transform operations
 pub trait Transform {
@@ -11,3 +60,470 @@ pub trait Transform {
     /// in place
     fn translate_mut(&mut self, by: Point) -> &mut Self;
 }
+
+/// Rotation around a point
+pub trait Rotate {
+    /// The type of the rotated object
+    type Output;
+
+    /// Rotates the object around `center`, returning a new object.
+    ///
+    /// Positive angles rotate clockwise on screen. Rotated points are rounded to the nearest
+    /// pixel and rotations by multiples of 90 degrees are exact.
+    fn rotate(&self, center: Point, angle: Angle) -> Self::Output;
+}
+
+/// Scaling relative to a point
+pub trait Scale {
+    /// The type of the scaled object
+    type Output;
+
+    /// Scales the object by `numerator / denominator` relative to `center`, returning a new
+    /// object.
+    ///
+    /// The distance of each point to `center` is multiplied by the scale factor and rounded to
+    /// the nearest pixel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Self::Output;
+}
+
+/// Mirroring across an axis
+pub trait Mirror {
+    /// The type of the mirrored object
+    type Output;
+
+    /// Mirrors the object across `axis`, returning a new object.
+    fn mirror(&self, axis: Axis) -> Self::Output;
+}
+
+/// Axis to mirror an object across
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Axis {
+    /// Vertical axis through the given X coordinate, which swaps left and right.
+    Vertical(i32),
+
+    /// Horizontal axis through the given Y coordinate, which swaps top and bottom.
+    Horizontal(i32),
+}
+
+/// Divides `value` by `divisor`, rounding to the nearest integer with ties away from zero.
+fn div_round(value: i64, divisor: i64) -> i64 {
+    let half = divisor / 2;
+
+    if value >= 0 {
+        (value + half) / divisor
+    } else {
+        (value - half) / divisor
+    }
+}
+
+/// Rotates the vector `(x, y)` by `angle`, rounding the result to whole units.
+fn rotate_vector(x: i32, y: i32, angle: Angle) -> (i32, i32) {
+    let (sin, cos) = angle.sin_cos();
+    let (sin, cos) = (i64::from(sin), i64::from(cos));
+    let (x, y) = (i64::from(x), i64::from(y));
+
+    (
+        div_round(x * cos - y * sin, 1 << 16) as i32,
+        div_round(x * sin + y * cos, 1 << 16) as i32,
+    )
+}
+
+/// Scales the vector `(x, y)` by `numerator / denominator`, rounding the result to whole units.
+fn scale_vector(x: i32, y: i32, numerator: u32, denominator: u32) -> (i32, i32) {
+    assert!(denominator != 0, "scale denominator must not be zero");
+
+    let numerator = i64::from(numerator);
+    let denominator = i64::from(denominator);
+
+    (
+        div_round(i64::from(x) * numerator, denominator) as i32,
+        div_round(i64::from(y) * numerator, denominator) as i32,
+    )
+}
+
+impl Rotate for Point {
+    type Output = Point;
+
+    fn rotate(&self, center: Point, angle: Angle) -> Point {
+        let delta = *self - center;
+        let (x, y) = rotate_vector(delta.x, delta.y, angle);
+
+        center + Point::new(x, y)
+    }
+}
+
+impl Scale for Point {
+    type Output = Point;
+
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> Point {
+        let delta = *self - center;
+        let (x, y) = scale_vector(delta.x, delta.y, numerator, denominator);
+
+        center + Point::new(x, y)
+    }
+}
+
+impl Mirror for Point {
+    type Output = Point;
+
+    fn mirror(&self, axis: Axis) -> Point {
+        match axis {
+            Axis::Vertical(x) => Point::new(2 * x - self.x, self.y),
+            Axis::Horizontal(y) => Point::new(self.x, 2 * y - self.y),
+        }
+    }
+}
+
+impl Rotate for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    /// Rotates the point around `center`, rounding the result to the nearest sub-pixel step.
+    fn rotate(&self, center: Point, angle: Angle) -> SubpixelPoint {
+        let delta = *self - center;
+        let (x, y) = rotate_vector(delta.x, delta.y, angle);
+
+        SubpixelPoint::new(x, y) + center
+    }
+}
+
+impl Scale for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    /// Scales the point relative to `center`, rounding the result to the nearest sub-pixel step.
+    fn scale(&self, center: Point, numerator: u32, denominator: u32) -> SubpixelPoint {
+        let delta = *self - center;
+        let (x, y) = scale_vector(delta.x, delta.y, numerator, denominator);
+
+        SubpixelPoint::new(x, y) + center
+    }
+}
+
+impl Mirror for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn mirror(&self, axis: Axis) -> SubpixelPoint {
+        match axis {
+            Axis::Vertical(x) => SubpixelPoint::new(2 * (x << SUBPIXEL_BITS) - self.x, self.y),
+            Axis::Horizontal(y) => SubpixelPoint::new(self.x, 2 * (y << SUBPIXEL_BITS) - self.y),
+        }
+    }
+}
+
+/// Pixel iterator which applies an [`Affine`] transform to each pixel.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::geometry::Affine;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::primitives::Circle;
+/// use embedded_graphics::transform::{Axis, TransformedPixels};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let circle = Circle::new(Point::new(10, 10), 5)
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+///
+/// // Draw the circle mirrored across the vertical line at X = 32
+/// let mut mirrored = TransformedPixels::new(circle, Affine::mirror(Axis::Vertical(32)));
+/// mirrored.draw(&mut display);
+/// ```
+///
+/// [`Affine`]: ../geometry/struct.Affine.html
+#[derive(Debug, Clone)]
+pub struct TransformedPixels<I> {
+    pixels: I,
+    affine: Affine,
+}
+
+impl<I> TransformedPixels<I> {
+    /// Creates an iterator which transforms the pixels of `pixels`.
+    pub fn new<P>(pixels: P, affine: Affine) -> Self
+    where
+        P: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            pixels: pixels.into_iter(),
+            affine,
+        }
+    }
+}
+
+impl<I, C> Iterator for TransformedPixels<I>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pixels
+            .next()
+            .map(|Pixel(point, color)| Pixel(self.affine.apply(point), color))
+    }
+}
+
+/// Display wrapper which applies an [`Affine`] transform to everything drawn on it.
+///
+/// Each pixel is transformed before it is passed on to the wrapped display. Primitives are drawn
+/// with the default `DrawTarget` methods, because accelerated methods of the wrapped display
+/// can't be used for transformed shapes.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::fonts::Font6x8;
+/// use embedded_graphics::geometry::Affine;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::primitives::Rectangle;
+/// use embedded_graphics::transform::TransformedDisplay;
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Draw everything at twice the size, moved 10 pixels down
+/// let affine = Affine::scale(2, 1).then(&Affine::translate(Point::new(0, 10)));
+/// let mut scaled = TransformedDisplay::new(&mut display, affine);
+///
+/// Rectangle::new(Point::new(0, 0), Point::new(5, 5))
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut scaled);
+/// Font6x8::render_str("Hi")
+///     .translate(Point::new(8, 0))
+///     .into_styled(TextStyle::with_text_color(BinaryColor::On))
+///     .draw(&mut scaled);
+/// ```
+///
+/// [`Affine`]: ../geometry/struct.Affine.html
+#[derive(Debug)]
+pub struct TransformedDisplay<'a, T> {
+    display: &'a mut T,
+    affine: Affine,
+}
+
+impl<'a, T> TransformedDisplay<'a, T> {
+    /// Creates a display wrapper which transforms all pixels drawn to `display`.
+    pub fn new(display: &'a mut T, affine: Affine) -> Self {
+        Self { display, affine }
+    }
+}
+
+impl<'a, T, C> DrawTarget<C> for TransformedDisplay<'a, T>
+where
+    T: DrawTarget<C>,
+    C: PixelColor,
+{
+    fn draw_pixel(&mut self, pixel: Pixel<C>) {
+        let Pixel(point, color) = pixel;
+
+        self.display
+            .draw_pixel(Pixel(self.affine.apply(point), color));
+    }
+
+    fn size(&self) -> Size {
+        self.display_area().size
+    }
+
+    /// Returns the area which is mapped onto the wrapped display.
+    ///
+    /// The area is the bounding box of the transformed display area, with one pixel of padding to
+    /// account for rounding. The area is saturated to the coordinate range of `Point`. If the
+    /// wrapped display has an unknown size or the transform can't be inverted the area of the
+    /// wrapped display is returned.
+    fn display_area(&self) -> Rect {
+        let area = self.display.display_area();
+
+        if area == Rect::unbounded() {
+            return area;
+        }
+
+        let inverse = match self.affine.inverse() {
+            Some(inverse) => inverse,
+            None => return area,
+        };
+
+        let clamp_coord = |v: i64| {
+            v.max(i64::from(i32::min_value()))
+                .min(i64::from(i32::max_value())) as i32
+        };
+
+        let (x_start, x_end) = area.x_range();
+        let (y_start, y_end) = area.y_range();
+        let (x_start, x_end) = (clamp_coord(x_start), clamp_coord(x_end));
+        let (y_start, y_end) = (clamp_coord(y_start), clamp_coord(y_end));
+
+        let corners = [
+            inverse.apply(Point::new(x_start, y_start)),
+            inverse.apply(Point::new(x_end, y_start)),
+            inverse.apply(Point::new(x_end, y_end)),
+            inverse.apply(Point::new(x_start, y_end)),
+        ];
+
+        let min_x = corners.iter().map(|p| i64::from(p.x)).min().unwrap();
+        let min_y = corners.iter().map(|p| i64::from(p.y)).min().unwrap();
+        let max_x = corners.iter().map(|p| i64::from(p.x)).max().unwrap();
+        let max_y = corners.iter().map(|p| i64::from(p.y)).max().unwrap();
+
+        Rect::from_ranges((min_x - 1, max_x + 2), (min_y - 1, max_y + 2))
+    }
+
+    /// Clears the wrapped display.
+    fn clear(&mut self, color: C) {
+        self.display.clear(color);
+    }
+
+    fn flush(&mut self) {
+        self.display.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate_point() {
+        let center = Point::new(10, 10);
+        let point = Point::new(15, 10);
+
+        assert_eq!(point.rotate(center, Angle::zero()), point);
+        assert_eq!(
+            point.rotate(center, Angle::from_degrees(90)),
+            Point::new(10, 15)
+        );
+        assert_eq!(
+            point.rotate(center, Angle::from_degrees(180)),
+            Point::new(5, 10)
+        );
+        assert_eq!(
+            point.rotate(center, Angle::from_degrees(-90)),
+            Point::new(10, 5)
+        );
+        assert_eq!(
+            Point::new(110, 0).rotate(Point::new(10, 0), Angle::from_degrees(45)),
+            Point::new(81, 71)
+        );
+    }
+
+    #[test]
+    fn scale_point() {
+        let center = Point::new(10, 10);
+
+        assert_eq!(Point::new(12, 7).scale(center, 2, 1), Point::new(14, 4));
+        assert_eq!(Point::new(13, 7).scale(center, 1, 2), Point::new(12, 8));
+        assert_eq!(Point::new(12, 7).scale(center, 0, 1), center);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scale_zero_denominator() {
+        Point::new(1, 2).scale(Point::zero(), 1, 0);
+    }
+
+    #[test]
+    fn mirror_point() {
+        let point = Point::new(3, 8);
+
+        assert_eq!(point.mirror(Axis::Vertical(5)), Point::new(7, 8));
+        assert_eq!(point.mirror(Axis::Horizontal(5)), Point::new(3, 2));
+        assert_eq!(
+            point.mirror(Axis::Vertical(5)).mirror(Axis::Vertical(5)),
+            point
+        );
+    }
+
+    #[test]
+    fn transformed_display() {
+        use crate::mock_display::MockDisplay;
+        use crate::pixelcolor::BinaryColor;
+        use crate::primitives::{Line, Primitive};
+        use crate::style::PrimitiveStyle;
+        use crate::Drawable;
+
+        let mut display = MockDisplay::new();
+        let affine = Affine::rotate(Angle::from_degrees(90));
+
+        // The line is outside of the display before it is rotated, which mustn't clip it
+        Line::new(Point::new(0, 0), Point::new(3, -3))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut TransformedDisplay::new(&mut display, affine));
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "#   ", //
+                " #  ", //
+                "  # ", //
+                "   #", //
+            ])
+        );
+    }
+
+    #[test]
+    fn transformed_display_area() {
+        use crate::mock_display::MockDisplay;
+        use crate::pixelcolor::BinaryColor;
+
+        struct Unbounded;
+
+        impl DrawTarget<BinaryColor> for Unbounded {
+            fn draw_pixel(&mut self, _pixel: Pixel<BinaryColor>) {}
+
+            fn size(&self) -> Size {
+                Size::zero()
+            }
+        }
+
+        let affine = Affine::translate(Point::new(10, 0));
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        assert_eq!(
+            TransformedDisplay::new(&mut display, affine).display_area(),
+            Rect::new(Point::new(-11, -1), Size::new(67, 67))
+        );
+
+        let mut display = Unbounded;
+        assert_eq!(
+            TransformedDisplay::new(&mut display, affine).display_area(),
+            Rect::unbounded()
+        );
+
+        // Displays which reach the end of the coordinate range are saturated
+        let affine = Affine::translate(Point::new(i32::min_value(), 0));
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        assert_eq!(
+            TransformedDisplay::new(&mut display, affine).display_area(),
+            Rect::new(Point::new(i32::max_value() - 1, -1), Size::new(3, 67))
+        );
+    }
+
+    #[test]
+    fn subpixel_point_transforms() {
+        let center = Point::new(10, 10);
+        let point = SubpixelPoint::new(15 * 256 + 64, 10 * 256);
+
+        assert_eq!(
+            point.rotate(center, Angle::from_degrees(90)),
+            SubpixelPoint::new(10 * 256, 15 * 256 + 64)
+        );
+        assert_eq!(
+            point.scale(center, 1, 2),
+            SubpixelPoint::new(12 * 256 + 160, 10 * 256)
+        );
+        assert_eq!(
+            point.mirror(Axis::Vertical(10)),
+            SubpixelPoint::new(4 * 256 + 192, 10 * 256)
+        );
+        assert_eq!(
+            SubpixelPoint::from(Point::new(110, 0))
+                .rotate(Point::new(10, 0), Angle::from_degrees(45))
+                .round(),
+            Point::new(81, 71)
+        );
+    }
+}
@@ -0,0 +1,65 @@
+extern crate embedded_graphics;
+
+use embedded_graphics::drawable::{Drawable, Pixel};
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::primitives::{Circle, Line, Primitive, Rectangle, Triangle};
+use embedded_graphics::style::PrimitiveStyle;
+use embedded_graphics::DrawTarget;
+
+/// Display which doesn't know its size and records all drawn pixels
+struct UnsizedDisplay {
+    pixels: Vec<Point>,
+}
+
+impl DrawTarget<BinaryColor> for UnsizedDisplay {
+    fn draw_pixel(&mut self, Pixel(point, _): Pixel<BinaryColor>) {
+        self.pixels.push(point);
+    }
+
+    fn size(&self) -> Size {
+        Size::zero()
+    }
+}
+
+#[test]
+fn primitives_are_not_clipped() {
+    let style = PrimitiveStyle::with_fill(BinaryColor::On);
+    let mut display = UnsizedDisplay { pixels: Vec::new() };
+
+    Line::new(Point::new(-5, -5), Point::new(5, 5))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(&mut display);
+    assert_eq!(display.pixels.len(), 11);
+
+    display.pixels.clear();
+    Rectangle::new(Point::new(-2, -3), Point::new(2, 3))
+        .into_styled(style)
+        .draw(&mut display);
+    assert_eq!(display.pixels.len(), 5 * 7);
+
+    display.pixels.clear();
+    Circle::new(Point::new(-10, 10), 3)
+        .into_styled(style)
+        .draw(&mut display);
+    assert_eq!(
+        display.pixels.len(),
+        Circle::new(Point::new(-10, 10), 3)
+            .into_styled(style)
+            .into_iter()
+            .count()
+    );
+
+    display.pixels.clear();
+    Triangle::new(Point::new(-8, 0), Point::new(0, -8), Point::new(0, 0))
+        .into_styled(style)
+        .draw(&mut display);
+    assert_eq!(
+        display.pixels.len(),
+        Triangle::new(Point::new(-8, 0), Point::new(0, -8), Point::new(0, 0))
+            .into_styled(style)
+            .into_iter()
+            .count()
+    );
+    assert!(!display.pixels.is_empty());
+}
@@ -0,0 +1,192 @@
+//! BDF font parser
+//!
+//! Parses fonts in the [Glyph Bitmap Distribution Format], which is used by X11 and many bitmap
+//! font editors. Glyphs without an encoding are skipped.
+//!
+//! [Glyph Bitmap Distribution Format]: https://www.adobe.com/content/dam/acom/en/devnet/font/pdfs/5005.BDF_Spec.pdf
+
+use crate::{Error, Font, Glyph};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// Bounding box of a glyph or font, as given in the `BBX` and `FONTBOUNDINGBOX` lines
+#[derive(Debug, Copy, Clone, Default)]
+struct BoundingBox {
+    width: u32,
+    height: u32,
+    x_offset: i32,
+    y_offset: i32,
+}
+
+/// A glyph which is being parsed
+#[derive(Debug, Default)]
+struct PartialGlyph {
+    encoding: Option<i64>,
+    advance: Option<u32>,
+    bounding_box: Option<BoundingBox>,
+    bitmap: Vec<u8>,
+}
+
+/// Parses a BDF font.
+pub fn parse(input: &str) -> Result<Font, Error> {
+    let mut font = Font {
+        name: String::new(),
+        ascent: 0,
+        descent: 0,
+        default_char: None,
+        glyphs: Vec::new(),
+    };
+
+    let mut font_bounding_box = BoundingBox::default();
+    let mut font_advance = None;
+    let mut ascent = None;
+    let mut descent = None;
+    let mut default_char = None;
+
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let mut in_properties = false;
+    let mut glyph: Option<PartialGlyph> = None;
+
+    while let Some((line_number, line)) = lines.next() {
+        let mut words = line.split_whitespace();
+        let keyword = words.next().unwrap_or_default();
+        let args: Vec<&str> = words.collect();
+        let rest = line[keyword.len()..].trim();
+
+        if in_properties {
+            match keyword {
+                "ENDPROPERTIES" => in_properties = false,
+                "FONT_ASCENT" => ascent = Some(number(line_number, &args, 0)?),
+                "FONT_DESCENT" => descent = Some(number(line_number, &args, 0)?),
+                "DEFAULT_CHAR" => default_char = Some(number(line_number, &args, 0)?),
+                _ => {}
+            }
+
+            continue;
+        }
+
+        if let Some(current) = glyph.as_mut() {
+            match keyword {
+                "ENCODING" => current.encoding = Some(number(line_number, &args, 0)?),
+                "DWIDTH" => current.advance = Some(number(line_number, &args, 0)?),
+                "BBX" => current.bounding_box = Some(bounding_box(line_number, &args)?),
+                "BITMAP" => {
+                    let bounding_box = current.bounding_box.unwrap_or(font_bounding_box);
+
+                    for _ in 0..bounding_box.height {
+                        let (line_number, line) = lines.next().ok_or(Error::UnexpectedEnd)?;
+
+                        current
+                            .bitmap
+                            .extend(bitmap_row(line_number, line, bounding_box.width)?);
+                    }
+                }
+                "ENDCHAR" => {
+                    let current = glyph.take().unwrap();
+                    let bounding_box = current.bounding_box.unwrap_or(font_bounding_box);
+
+                    let c = current
+                        .encoding
+                        .and_then(|encoding| u32::try_from(encoding).ok())
+                        .and_then(std::char::from_u32);
+
+                    if let Some(c) = c {
+                        let advance = current
+                            .advance
+                            .or(font_advance)
+                            .ok_or_else(|| Error::parse(line_number, "missing DWIDTH"))?;
+
+                        let mut bitmap = current.bitmap;
+                        bitmap.resize((bounding_box.width * bounding_box.height) as usize, 0);
+
+                        font.glyphs.push(Glyph {
+                            c,
+                            width: bounding_box.width,
+                            height: bounding_box.height,
+                            x_offset: bounding_box.x_offset,
+                            y_offset: bounding_box.y_offset,
+                            advance,
+                            bitmap,
+                        });
+                    }
+                }
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match keyword {
+            "FONT" => font.name = rest.to_string(),
+            "FONTBOUNDINGBOX" => font_bounding_box = bounding_box(line_number, &args)?,
+            "DWIDTH" => font_advance = Some(number(line_number, &args, 0)?),
+            "STARTPROPERTIES" => in_properties = true,
+            "STARTCHAR" => glyph = Some(PartialGlyph::default()),
+            "ENDFONT" => break,
+            _ => {}
+        }
+    }
+
+    if in_properties || glyph.is_some() {
+        return Err(Error::UnexpectedEnd);
+    }
+
+    font.ascent = ascent.unwrap_or_else(|| {
+        (font_bounding_box.height as i32 + font_bounding_box.y_offset).max(0) as u32
+    });
+    font.descent = descent.unwrap_or_else(|| (-font_bounding_box.y_offset).max(0) as u32);
+    font.default_char = default_char
+        .and_then(|encoding: i64| u32::try_from(encoding).ok())
+        .and_then(std::char::from_u32);
+
+    Ok(font)
+}
+
+/// Parses the argument at `index`.
+fn number<T: FromStr>(line_number: usize, args: &[&str], index: usize) -> Result<T, Error> {
+    args.get(index)
+        .and_then(|arg| arg.parse().ok())
+        .ok_or_else(|| Error::parse(line_number, "invalid or missing number"))
+}
+
+fn bounding_box(line_number: usize, args: &[&str]) -> Result<BoundingBox, Error> {
+    Ok(BoundingBox {
+        width: number(line_number, args, 0)?,
+        height: number(line_number, args, 1)?,
+        x_offset: number(line_number, args, 2)?,
+        y_offset: number(line_number, args, 3)?,
+    })
+}
+
+/// Parses a hex encoded bitmap row into coverage values.
+fn bitmap_row(line_number: usize, line: &str, width: u32) -> Result<Vec<u8>, Error> {
+    let bytes = line
+        .as_bytes()
+        .chunks(2)
+        .map(|digits| {
+            std::str::from_utf8(digits)
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| Error::parse(line_number, "invalid bitmap row"))?;
+
+    if bytes.len() * 8 < width as usize {
+        return Err(Error::parse(line_number, "bitmap row is too short"));
+    }
+
+    Ok((0..width as usize)
+        .map(|x| {
+            if bytes[x / 8] & (0x80 >> (x % 8)) != 0 {
+                255
+            } else {
+                0
+            }
+        })
+        .collect())
+}
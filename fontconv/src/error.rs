@@ -0,0 +1,56 @@
+use std::error;
+use std::fmt;
+
+/// Errors which can occur while converting a font
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line of the font file couldn't be parsed
+    Parse {
+        /// Line number, starting at 1
+        line: usize,
+
+        /// Description of the error
+        message: String,
+    },
+
+    /// The font file ended before the font was complete
+    UnexpectedEnd,
+
+    /// The font file isn't a valid TrueType or OpenType font
+    InvalidFont,
+
+    /// The font doesn't contain any glyphs
+    NoGlyphs,
+
+    /// A glyph is wider than the 255 pixels supported by proportional fonts
+    GlyphTooWide(char),
+
+    /// The number of bits per pixel isn't 1, 2, 4 or 8
+    UnsupportedBitsPerPixel(u32),
+}
+
+impl Error {
+    pub(crate) fn parse(line: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            Error::UnexpectedEnd => write!(f, "unexpected end of file"),
+            Error::InvalidFont => write!(f, "invalid TrueType or OpenType font"),
+            Error::NoGlyphs => write!(f, "the font doesn't contain any glyphs"),
+            Error::GlyphTooWide(c) => write!(f, "glyph {:?} is wider than 255 pixels", c),
+            Error::UnsupportedBitsPerPixel(bpp) => {
+                write!(f, "unsupported number of bits per pixel: {}", bpp)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
@@ -0,0 +1,588 @@
+//! Converts fonts into bitmap fonts for embedded-graphics.
+//!
+//! Fonts are loaded into a [`Font`], which can be reduced to the characters that are actually
+//! used. [`Font::to_bitmap_font`] arranges the glyphs in the layout expected by
+//! `embedded_graphics::fonts::font_builder::FontBuilderConf`, and [`BitmapFont::to_rust`] generates
+//! the Rust source of the font definition. Anti-aliased fonts with 2, 4 or 8 bits per pixel are
+//! created by [`Font::to_bitmap_font_bpp`].
+//!
+//! The conversion can be run from the `eg-fontconv` command line tool or from a build script.
+//!
+//! # Examples
+//!
+//! ## Convert a BDF font in a build script
+//!
+//! ```rust,no_run
+//! use embedded_graphics_fontconv::bdf;
+//! use std::{env, fs, path::Path};
+//!
+//! let bdf = fs::read_to_string("fonts/terminus-12.bdf").unwrap();
+//!
+//! let mut font = bdf::parse(&bdf).unwrap();
+//! // Only keep printable ASCII characters to save flash
+//! font.retain_chars(|c| c >= ' ' && c <= '~');
+//!
+//! let rust = font.to_bitmap_font().unwrap().to_rust("Terminus12");
+//!
+//! let out_dir = env::var("OUT_DIR").unwrap();
+//! fs::write(Path::new(&out_dir).join("terminus12.rs"), rust).unwrap();
+//! ```
+//!
+//! The generated file is then included in the crate which uses the font:
+//!
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/terminus12.rs"));
+//!
+//! Terminus12::render_str("Hello BDF!")
+//!     .into_styled(TextStyle::with_text_color(BinaryColor::On))
+//!     .draw(&mut display);
+//! ```
+//!
+//! ## Rasterise a TrueType font in a build script
+//!
+//! TrueType and OpenType fonts are rasterised at a fixed size, which means that no rasteriser is
+//! required on the target.
+//!
+//! ```rust,no_run
+//! use embedded_graphics_fontconv::ttf;
+//! use std::{env, fs, path::Path};
+//!
+//! let ttf = fs::read("fonts/brand.ttf").unwrap();
+//!
+//! // Rasterise the printable ASCII characters with a height of 14 pixels
+//! let mut font = ttf::rasterize(&ttf, 14.0, ' '..='~').unwrap();
+//! font.name = "Brand".to_string();
+//!
+//! // Keep the anti-aliasing with 4 bits per pixel
+//! let rust = font.to_bitmap_font_bpp(4).unwrap().to_rust("Brand14");
+//!
+//! let out_dir = env::var("OUT_DIR").unwrap();
+//! fs::write(Path::new(&out_dir).join("brand14.rs"), rust).unwrap();
+//! ```
+//!
+//! [`Font`]: struct.Font.html
+//! [`Font::to_bitmap_font`]: struct.Font.html#method.to_bitmap_font
+//! [`Font::to_bitmap_font_bpp`]: struct.Font.html#method.to_bitmap_font_bpp
+//! [`BitmapFont::to_rust`]: struct.BitmapFont.html#method.to_rust
+
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![deny(trivial_casts)]
+#![deny(trivial_numeric_casts)]
+#![deny(unsafe_code)]
+#![deny(unstable_features)]
+#![deny(unused_import_braces)]
+#![deny(unused_qualifications)]
+
+pub mod bdf;
+mod error;
+pub mod ttf;
+
+pub use crate::error::Error;
+use std::fmt::Write;
+
+/// Number of glyphs in each row of the generated font image
+///
+/// The image width must be divisible by 8, which is true for any glyph width if 16 glyphs are
+/// stored in each row.
+const GLYPHS_PER_ROW: u32 = 16;
+
+/// Minimum coverage of a pixel which is set in a 1bpp font image
+const THRESHOLD: u8 = 128;
+
+/// Maximum line width used by rustfmt
+const MAX_WIDTH: usize = 100;
+
+/// Maximum width of an array which rustfmt puts on a single line
+const ARRAY_WIDTH: usize = 60;
+
+/// Maximum width of function call arguments which rustfmt puts on a single line
+const FN_CALL_WIDTH: usize = 60;
+
+/// A glyph of a font
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyph {
+    /// Character represented by the glyph
+    pub c: char,
+
+    /// Width of the bitmap in pixels
+    pub width: u32,
+
+    /// Height of the bitmap in pixels
+    pub height: u32,
+
+    /// Horizontal distance from the pen position to the left edge of the bitmap
+    pub x_offset: i32,
+
+    /// Vertical distance from the baseline to the bottom edge of the bitmap, positive upwards
+    pub y_offset: i32,
+
+    /// Horizontal distance from the pen position of this glyph to the pen position of the next
+    /// glyph
+    pub advance: u32,
+
+    /// Coverage of the pixels, row by row
+    ///
+    /// `0` is transparent and `255` is fully covered.
+    pub bitmap: Vec<u8>,
+}
+
+impl Glyph {
+    /// Returns the coverage of a pixel of the bitmap.
+    pub fn pixel(&self, x: u32, y: u32) -> u8 {
+        self.bitmap[(x + y * self.width) as usize]
+    }
+}
+
+/// A font which can be converted into a bitmap font
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Font {
+    /// Name of the font
+    pub name: String,
+
+    /// Distance from the baseline to the top of the font in pixels
+    pub ascent: u32,
+
+    /// Distance from the baseline to the bottom of the font in pixels
+    pub descent: u32,
+
+    /// Character which is drawn for characters that aren't part of the font
+    pub default_char: Option<char>,
+
+    /// Glyphs of the font
+    pub glyphs: Vec<Glyph>,
+}
+
+impl Font {
+    /// Returns the glyph for a character.
+    pub fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.glyphs.iter().find(|glyph| glyph.c == c)
+    }
+
+    /// Removes all glyphs for which `f` returns `false`.
+    ///
+    /// The default character is always kept.
+    pub fn retain_chars(&mut self, mut f: impl FnMut(char) -> bool) {
+        let default_char = self.default_char;
+
+        self.glyphs
+            .retain(|glyph| Some(glyph.c) == default_char || f(glyph.c));
+    }
+
+    /// Arranges the glyphs in the layout used by `FontBuilderConf`.
+    ///
+    /// All glyphs are stored in cells of the same size. Left bearings and the vertical position of
+    /// each glyph are preserved by placing the glyph inside its cell. If all glyphs have the same
+    /// advance width the font is monospace, otherwise a table of glyph widths is generated.
+    ///
+    /// The font image uses one bit per pixel. Pixels with a coverage of at least 50% are set.
+    pub fn to_bitmap_font(&self) -> Result<BitmapFont, Error> {
+        self.to_bitmap_font_bpp(1)
+    }
+
+    /// Arranges the glyphs in the layout used by `FontBuilderConf` with the given number of bits
+    /// per pixel.
+    ///
+    /// `bits_per_pixel` must be 1, 2, 4 or 8. With more than one bit per pixel the coverage of the
+    /// glyph pixels is quantised to the available levels, which creates an anti-aliased font. The
+    /// layout is the same as for [`to_bitmap_font`].
+    ///
+    /// [`to_bitmap_font`]: #method.to_bitmap_font
+    pub fn to_bitmap_font_bpp(&self, bits_per_pixel: u32) -> Result<BitmapFont, Error> {
+        if ![1, 2, 4, 8].contains(&bits_per_pixel) {
+            return Err(Error::UnsupportedBitsPerPixel(bits_per_pixel));
+        }
+
+        let mut glyphs: Vec<&Glyph> = self.glyphs.iter().collect();
+        glyphs.sort_by_key(|glyph| glyph.c);
+        glyphs.dedup_by_key(|glyph| glyph.c);
+
+        if glyphs.is_empty() {
+            return Err(Error::NoGlyphs);
+        }
+
+        // Glyphs with a negative left bearing shift all glyphs to the right
+        let min_x = glyphs
+            .iter()
+            .map(|glyph| glyph.x_offset)
+            .min()
+            .unwrap()
+            .min(0);
+
+        let ascent = glyphs
+            .iter()
+            .map(|glyph| glyph.y_offset + glyph.height as i32)
+            .max()
+            .unwrap()
+            .max(self.ascent as i32)
+            .max(0) as u32;
+        let descent = glyphs
+            .iter()
+            .map(|glyph| -glyph.y_offset)
+            .max()
+            .unwrap()
+            .max(self.descent as i32)
+            .max(0) as u32;
+
+        let ink_width = |glyph: &Glyph| (glyph.x_offset - min_x) as u32 + glyph.width;
+
+        let max_ink_width = glyphs.iter().map(|glyph| ink_width(glyph)).max().unwrap();
+        let advance = glyphs[0].advance;
+        let monospace = glyphs.iter().all(|glyph| glyph.advance == advance) && advance > 0;
+
+        let char_width = if monospace {
+            advance.max(max_ink_width)
+        } else {
+            max_ink_width.max(1)
+        };
+        let char_height = (ascent + descent).max(1);
+
+        let glyph_widths = if monospace {
+            Vec::new()
+        } else {
+            glyphs
+                .iter()
+                .map(|glyph| {
+                    let width = ink_width(glyph);
+
+                    if width > 255 || glyph.advance > 255 {
+                        Err(Error::GlyphTooWide(glyph.c))
+                    } else {
+                        Ok((width as u8, glyph.advance as u8))
+                    }
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        let image_width = GLYPHS_PER_ROW * char_width;
+        let rows = (glyphs.len() as u32).div_ceil(GLYPHS_PER_ROW);
+        let max_value = (1 << bits_per_pixel) - 1;
+        let mut image = vec![0; (image_width * bits_per_pixel / 8 * rows * char_height) as usize];
+
+        for (index, glyph) in glyphs.iter().enumerate() {
+            let index = index as u32;
+            let cell_x = (index % GLYPHS_PER_ROW) * char_width + (glyph.x_offset - min_x) as u32;
+            let cell_y = (index / GLYPHS_PER_ROW) * char_height;
+            let top = ascent as i32 - (glyph.y_offset + glyph.height as i32);
+
+            for y in 0..glyph.height {
+                for x in 0..glyph.width {
+                    let coverage = glyph.pixel(x, y);
+                    let value = if bits_per_pixel == 1 {
+                        u32::from(coverage >= THRESHOLD)
+                    } else {
+                        (u32::from(coverage) * max_value + 127) / 255
+                    };
+
+                    let pixel = cell_x + x + (cell_y + (top + y as i32) as u32) * image_width;
+                    let bit = pixel * bits_per_pixel;
+
+                    image[(bit / 8) as usize] |= (value << (8 - bits_per_pixel - bit % 8)) as u8;
+                }
+            }
+        }
+
+        let mut ranges: Vec<(char, char, u32)> = Vec::new();
+        for (index, glyph) in glyphs.iter().enumerate() {
+            match ranges.last_mut() {
+                Some(range) if range.1 as u32 + 1 == glyph.c as u32 => range.1 = glyph.c,
+                _ => ranges.push((glyph.c, glyph.c, index as u32)),
+            }
+        }
+
+        let index_of = |c: char| glyphs.iter().position(|glyph| glyph.c == c);
+        let fallback = self
+            .default_char
+            .and_then(index_of)
+            .or_else(|| index_of('?'))
+            .unwrap_or(0) as u32;
+
+        Ok(BitmapFont {
+            name: self.name.clone(),
+            char_width,
+            char_height,
+            image_width,
+            baseline: ascent.saturating_sub(1),
+            bits_per_pixel,
+            image,
+            glyph_widths,
+            ranges,
+            fallback,
+        })
+    }
+}
+
+/// A font in the layout used by `FontBuilderConf`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitmapFont {
+    /// Name of the font
+    pub name: String,
+
+    /// Width of the glyph cells in pixels
+    pub char_width: u32,
+
+    /// Height of the glyph cells in pixels
+    pub char_height: u32,
+
+    /// Width of the font image in pixels
+    pub image_width: u32,
+
+    /// Row of the baseline, counted from the top of the glyph cells
+    pub baseline: u32,
+
+    /// Number of bits per pixel in the font image
+    pub bits_per_pixel: u32,
+
+    /// Font image
+    ///
+    /// The pixels are stored with `bits_per_pixel` bits, starting at the most significant bits of
+    /// each byte.
+    pub image: Vec<u8>,
+
+    /// Bitmap width and advance width of each glyph
+    ///
+    /// The table is empty for monospace fonts.
+    pub glyph_widths: Vec<(u8, u8)>,
+
+    /// Ranges of characters and the glyph index of the first character of each range
+    pub ranges: Vec<(char, char, u32)>,
+
+    /// Glyph index which is used for characters that aren't part of the font
+    pub fallback: u32,
+}
+
+impl BitmapFont {
+    /// Returns the number of glyphs in the font.
+    pub fn glyph_count(&self) -> u32 {
+        self.ranges
+            .iter()
+            .map(|&(start, end, _)| end as u32 - start as u32 + 1)
+            .sum()
+    }
+
+    /// Returns `true` if a pixel of a glyph cell is set.
+    ///
+    /// Pixels of anti-aliased fonts are set if their coverage is at least 50%.
+    pub fn pixel(&self, glyph: u32, x: u32, y: u32) -> bool {
+        self.coverage(glyph, x, y) >= THRESHOLD
+    }
+
+    /// Returns the coverage of a pixel of a glyph cell.
+    ///
+    /// The stored value of the pixel is scaled to the range from `0` to `255`.
+    pub fn coverage(&self, glyph: u32, x: u32, y: u32) -> u8 {
+        let cell_x = (glyph % GLYPHS_PER_ROW) * self.char_width;
+        let cell_y = (glyph / GLYPHS_PER_ROW) * self.char_height;
+        let pixel = cell_x + x + (cell_y + y) * self.image_width;
+        let bit = pixel * self.bits_per_pixel;
+
+        let max_value = (1 << self.bits_per_pixel) - 1;
+        let value = (u32::from(self.image[(bit / 8) as usize])
+            >> (8 - self.bits_per_pixel - bit % 8))
+            & max_value;
+
+        (value * 255 / max_value) as u8
+    }
+
+    /// Generates the Rust source of the font definition.
+    ///
+    /// The source defines `<type_name>Conf`, which implements `FontBuilderConf`, and the font type
+    /// `<type_name>`. Both are declared in a private module, which is named after the snake case
+    /// type name, and re-exported. This allows the source to be included into any module with
+    /// `include!`, even if the module includes more than one font.
+    pub fn to_rust(&self, type_name: &str) -> String {
+        let mut out = String::new();
+        let module_name = snake_case(type_name);
+
+        writeln!(
+            out,
+            "// Generated by embedded-graphics-fontconv. Do not edit."
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "pub use self::{}::*;", module_name).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "mod {} {{", module_name).unwrap();
+        writeln!(
+            out,
+            "    use ::embedded_graphics::fonts::{{font_builder, glyph_map}};"
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "    /// Config for the {} font", self.name).unwrap();
+        writeln!(out, "    #[derive(Debug, Copy, Clone)]").unwrap();
+        writeln!(out, "    pub enum {}Conf {{}}", type_name).unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "    impl font_builder::FontBuilderConf for {}Conf {{",
+            type_name
+        )
+        .unwrap();
+
+        let bytes: Vec<String> = self.image.iter().map(|b| format!("0x{:02x}", b)).collect();
+        write_array(&mut out, 2, "const FONT_IMAGE: &'static [u8]", &bytes, true);
+        writeln!(
+            out,
+            "        const CHAR_HEIGHT: u32 = {};",
+            self.char_height
+        )
+        .unwrap();
+        writeln!(out, "        const CHAR_WIDTH: u32 = {};", self.char_width).unwrap();
+        writeln!(
+            out,
+            "        const FONT_IMAGE_WIDTH: u32 = {};",
+            self.image_width
+        )
+        .unwrap();
+        writeln!(out, "        const BASELINE: u32 = {};", self.baseline).unwrap();
+        if self.bits_per_pixel != 1 {
+            writeln!(
+                out,
+                "        const BITS_PER_PIXEL: u32 = {};",
+                self.bits_per_pixel
+            )
+            .unwrap();
+        }
+
+        if !self.glyph_widths.is_empty() {
+            let glyph_widths: Vec<String> = self
+                .glyph_widths
+                .iter()
+                .map(|(width, advance)| {
+                    format!("font_builder::GlyphWidth::new({}, {})", width, advance)
+                })
+                .collect();
+            write_array(
+                &mut out,
+                2,
+                "const GLYPH_WIDTHS: &'static [font_builder::GlyphWidth]",
+                &glyph_widths,
+                false,
+            );
+        }
+
+        writeln!(out).unwrap();
+        writeln!(out, "        fn char_offset(c: char) -> u32 {{").unwrap();
+        let ranges: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|(start, end, index)| {
+                format!(
+                    "glyph_map::GlyphRange::new({:?}, {:?}, {})",
+                    start, end, index
+                )
+            })
+            .collect();
+        let declaration = "const GLYPH_MAP: glyph_map::GlyphMap =";
+        let arguments = format!("&[{}], {}", ranges.join(", "), self.fallback);
+        if ranges.join(", ").len() <= ARRAY_WIDTH && arguments.len() <= FN_CALL_WIDTH {
+            let call = format!("glyph_map::GlyphMap::new({});", arguments);
+            write_statement(&mut out, 3, declaration, &call);
+        } else {
+            writeln!(out, "            {} glyph_map::GlyphMap::new(", declaration).unwrap();
+            writeln!(out, "                &[").unwrap();
+            for range in ranges.iter() {
+                writeln!(out, "                    {},", range).unwrap();
+            }
+            writeln!(out, "                ],").unwrap();
+            writeln!(out, "                {},", self.fallback).unwrap();
+            writeln!(out, "            );").unwrap();
+        }
+        writeln!(out).unwrap();
+        writeln!(out, "            GLYPH_MAP.index(c)").unwrap();
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "    /// The {} font", self.name).unwrap();
+        write_statement(
+            &mut out,
+            1,
+            &format!("pub type {}<'a> =", type_name),
+            &format!("font_builder::FontBuilder<'a, {}Conf>;", type_name),
+        );
+        writeln!(out, "}}").unwrap();
+
+        out
+    }
+}
+
+/// Converts a type name in camel case into snake case.
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut previous_lowercase = false;
+
+    for c in name.chars() {
+        if c.is_uppercase() && previous_lowercase {
+            out.push('_');
+        }
+        previous_lowercase = c.is_lowercase() || c.is_ascii_digit();
+        out.extend(c.to_lowercase());
+    }
+
+    out
+}
+
+/// Writes a statement, which is broken after the declaration if it doesn't fit on a single line.
+///
+/// The generated source is formatted like rustfmt would format it, to make sure that fonts which
+/// are checked into a repository don't fail `cargo fmt -- --check`.
+fn write_statement(out: &mut String, indent_level: usize, declaration: &str, value: &str) {
+    let indent = "    ".repeat(indent_level);
+
+    if indent.len() + declaration.len() + value.len() + 1 <= MAX_WIDTH {
+        writeln!(out, "{}{} {}", indent, declaration, value).unwrap();
+    } else {
+        writeln!(out, "{}{}", indent, declaration).unwrap();
+        writeln!(out, "{}    {}", indent, value).unwrap();
+    }
+}
+
+/// Writes a constant array in the format used by rustfmt.
+///
+/// Short arrays are written on a single line. Longer arrays either put all items into as few lines
+/// as possible, if `compact` is `true`, or use one line per item.
+fn write_array(
+    out: &mut String,
+    indent_level: usize,
+    declaration: &str,
+    items: &[String],
+    compact: bool,
+) {
+    let indent = "    ".repeat(indent_level);
+    let declaration = format!("{} =", declaration);
+
+    let single_line = items.join(", ");
+    if single_line.len() <= ARRAY_WIDTH {
+        let value = format!("&[{}];", single_line);
+        if indent.len() + value.len() + 4 <= MAX_WIDTH {
+            write_statement(out, indent_level, &declaration, &value);
+            return;
+        }
+    }
+
+    writeln!(out, "{}{} &[", indent, declaration).unwrap();
+    if compact {
+        let mut line = String::new();
+        for item in items {
+            if !line.is_empty() && indent.len() + 4 + line.len() + item.len() + 2 > MAX_WIDTH {
+                writeln!(out, "{}    {}", indent, line).unwrap();
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(item);
+            line.push(',');
+        }
+        if !line.is_empty() {
+            writeln!(out, "{}    {}", indent, line).unwrap();
+        }
+    } else {
+        for item in items {
+            writeln!(out, "{}    {},", indent, item).unwrap();
+        }
+    }
+    writeln!(out, "{}];", indent).unwrap();
+}
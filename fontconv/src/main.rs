@@ -0,0 +1,132 @@
+use embedded_graphics_fontconv::{bdf, ttf, Font};
+use std::{env, fs, path::Path, process};
+
+const USAGE: &str = "\
+Converts fonts into font definitions for embedded-graphics
+
+USAGE:
+    eg-fontconv bdf <INPUT> <TYPE_NAME> [OPTIONS]
+    eg-fontconv ttf <INPUT> <TYPE_NAME> --size <PIXELS> [OPTIONS]
+
+OPTIONS:
+    --chars <RANGES>    Only include the given characters. RANGES is a comma separated list of
+                        hexadecimal code points or code point ranges, e.g. `20-7e,a1-ff,20ac`.
+                        TrueType and OpenType fonts default to `20-7e`.
+    --size <PIXELS>     Height of TrueType and OpenType fonts in pixels
+    --bpp <BITS>        Bits per pixel of the font image: 1, 2, 4 or 8. Values above 1 create an
+                        anti-aliased font. Defaults to 1.
+    --output <FILE>     Write the font definition to FILE instead of stdout
+";
+
+/// Parses a list of code point ranges like `20-7e,a1-ff,20ac`.
+fn parse_ranges(ranges: &str) -> Result<Vec<(u32, u32)>, String> {
+    ranges
+        .split(',')
+        .map(|range| {
+            let mut bounds = range.splitn(2, '-').map(|bound| {
+                u32::from_str_radix(bound.trim(), 16)
+                    .map_err(|_| format!("invalid code point range: {}", range))
+            });
+
+            let start = bounds.next().unwrap()?;
+            let end = bounds.next().unwrap_or(Ok(start))?;
+
+            Ok((start, end))
+        })
+        .collect()
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (format, input, type_name) = match args {
+        [format, input, type_name, ..] => (format, input, type_name),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let mut chars = None;
+    let mut size = None;
+    let mut bits_per_pixel = 1;
+    let mut output = None;
+
+    let mut options = args[3..].iter();
+    while let Some(option) = options.next() {
+        let value = options
+            .next()
+            .ok_or_else(|| format!("missing value for {}", option))?;
+
+        match option.as_str() {
+            "--chars" => chars = Some(parse_ranges(value)?),
+            "--size" => {
+                size = Some(
+                    value
+                        .parse::<f32>()
+                        .map_err(|_| format!("invalid size: {}", value))?,
+                )
+            }
+            "--bpp" => {
+                bits_per_pixel = value
+                    .parse::<u32>()
+                    .map_err(|_| format!("invalid number of bits per pixel: {}", value))?
+            }
+            "--output" => output = Some(value),
+            _ => return Err(format!("unknown option {}\n\n{}", option, USAGE)),
+        }
+    }
+
+    let mut font: Font = match format.as_str() {
+        "bdf" => {
+            let data = fs::read_to_string(input).map_err(|e| format!("{}: {}", input, e))?;
+
+            bdf::parse(&data).map_err(|e| format!("{}: {}", input, e))?
+        }
+        "ttf" => {
+            let size = size.ok_or_else(|| format!("missing --size option\n\n{}", USAGE))?;
+            let data = fs::read(input).map_err(|e| format!("{}: {}", input, e))?;
+
+            let ranges = chars.take().unwrap_or_else(|| vec![(0x20, 0x7e)]);
+            let chars = ranges
+                .iter()
+                .flat_map(|&(start, end)| start..=end)
+                .filter_map(std::char::from_u32);
+
+            let mut font =
+                ttf::rasterize(&data, size, chars).map_err(|e| format!("{}: {}", input, e))?;
+            font.name = Path::new(input)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+
+            font
+        }
+        _ => return Err(format!("unknown font format {}\n\n{}", format, USAGE)),
+    };
+
+    if let Some(chars) = chars {
+        font.retain_chars(|c| {
+            chars
+                .iter()
+                .any(|&(start, end)| c as u32 >= start && c as u32 <= end)
+        });
+    }
+
+    let rust = font
+        .to_bitmap_font_bpp(bits_per_pixel)
+        .map_err(|e| format!("{}: {}", input, e))?
+        .to_rust(type_name);
+
+    match output {
+        Some(output) => fs::write(output, rust).map_err(|e| format!("{}: {}", output, e)),
+        None => {
+            print!("{}", rust);
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(message) = run(&args) {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,98 @@
+//! TrueType and OpenType font rasteriser
+//!
+//! Rasterises the outlines of a TrueType or OpenType font at a fixed pixel size. The coverage of
+//! each pixel is stored in the glyph bitmaps, which are converted to 1bpp by
+//! [`Font::to_bitmap_font`] or to an anti-aliased font by [`Font::to_bitmap_font_bpp`].
+//!
+//! The rasterised font doesn't have a name, which is used in the documentation of the generated
+//! font definition. It can be set by assigning [`Font::name`].
+//!
+//! [`Font::to_bitmap_font`]: ../struct.Font.html#method.to_bitmap_font
+//! [`Font::to_bitmap_font_bpp`]: ../struct.Font.html#method.to_bitmap_font_bpp
+//! [`Font::name`]: ../struct.Font.html#structfield.name
+
+use crate::{Error, Font, Glyph};
+use ab_glyph::{Font as _, FontRef, GlyphId, PxScale, ScaleFont};
+
+/// Rasterises the given characters of a TrueType or OpenType font.
+///
+/// `size` is the height of the font in pixels, measured from the descent to the ascent. Characters
+/// which aren't included in the font are skipped. The `?` glyph is used as the default character
+/// if it is included in `chars`.
+pub fn rasterize(
+    data: &[u8],
+    size: f32,
+    chars: impl IntoIterator<Item = char>,
+) -> Result<Font, Error> {
+    let font = FontRef::try_from_slice(data).map_err(|_| Error::InvalidFont)?;
+    let scale = PxScale::from(size);
+    let scaled = font.as_scaled(scale);
+
+    let glyphs: Vec<Glyph> = chars
+        .into_iter()
+        .filter_map(|c| {
+            let id = font.glyph_id(c);
+
+            if id == GlyphId(0) {
+                None
+            } else {
+                Some(rasterize_glyph(&font, scale, c, id))
+            }
+        })
+        .collect();
+
+    let default_char = glyphs
+        .iter()
+        .find(|glyph| glyph.c == '?')
+        .map(|glyph| glyph.c);
+
+    Ok(Font {
+        name: String::new(),
+        ascent: scaled.ascent().round().max(0.0) as u32,
+        descent: (-scaled.descent()).round().max(0.0) as u32,
+        default_char,
+        glyphs,
+    })
+}
+
+fn rasterize_glyph(font: &FontRef, scale: PxScale, c: char, id: GlyphId) -> Glyph {
+    let advance = font.as_scaled(scale).h_advance(id).round().max(0.0) as u32;
+
+    let outlined = match font.outline_glyph(id.with_scale(scale)) {
+        Some(outlined) => outlined,
+        None => {
+            // Glyphs without an outline, like the space character
+            return Glyph {
+                c,
+                width: 0,
+                height: 0,
+                x_offset: 0,
+                y_offset: 0,
+                advance,
+                bitmap: Vec::new(),
+            };
+        }
+    };
+
+    // The y axis of the pixel bounds points down, with the baseline at `y = 0`
+    let bounds = outlined.px_bounds();
+    let width = bounds.width() as u32;
+    let height = bounds.height() as u32;
+
+    let mut bitmap = vec![0; (width * height) as usize];
+    outlined.draw(|x, y, coverage| {
+        if x < width && y < height {
+            bitmap[(x + y * width) as usize] = (coverage.min(1.0) * 255.0).round() as u8;
+        }
+    });
+
+    Glyph {
+        c,
+        width,
+        height,
+        x_offset: bounds.min.x as i32,
+        y_offset: -bounds.max.y as i32,
+        advance,
+        bitmap,
+    }
+}
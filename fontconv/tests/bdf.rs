@@ -0,0 +1,135 @@
+use embedded_graphics::fonts::Font;
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::style::TextStyle;
+use embedded_graphics_fontconv::{bdf, Error};
+
+include!("test_font.rs");
+
+const TEST_BDF: &str = include_str!("test.bdf");
+
+#[test]
+fn parse_metrics() {
+    let font = bdf::parse(TEST_BDF).unwrap();
+
+    assert_eq!(
+        font.name,
+        "-test-test-medium-r-normal--8-80-75-75-p-50-iso10646-1"
+    );
+    assert_eq!(font.ascent, 6);
+    assert_eq!(font.descent, 2);
+    assert_eq!(font.default_char, Some('?'));
+
+    // The unencoded glyph is skipped
+    let chars: Vec<char> = font.glyphs.iter().map(|glyph| glyph.c).collect();
+    assert_eq!(chars, ['?', 'A', 'i', 'j']);
+
+    let j = font.glyph('j').unwrap();
+    assert_eq!((j.width, j.height), (3, 7));
+    assert_eq!((j.x_offset, j.y_offset), (-1, -2));
+    assert_eq!(j.advance, 3);
+    assert_eq!(j.pixel(2, 0), 255);
+    assert_eq!(j.pixel(1, 0), 0);
+    assert_eq!(j.pixel(0, 6), 255);
+}
+
+#[test]
+fn bitmap_font_layout() {
+    let font = bdf::parse(TEST_BDF).unwrap().to_bitmap_font().unwrap();
+
+    assert_eq!(font.char_width, 6);
+    assert_eq!(font.char_height, 8);
+    assert_eq!(font.baseline, 5);
+    assert_eq!(font.glyph_count(), 4);
+    assert_eq!(font.glyph_widths, [(5, 5), (6, 6), (2, 2), (3, 3)]);
+    assert_eq!(font.ranges, [('?', '?', 0), ('A', 'A', 1), ('i', 'j', 2)]);
+    assert_eq!(font.fallback, 0);
+
+    // The glyphs are shifted to the right by the negative left bearing of "j"
+    assert!(!font.pixel(1, 0, 5));
+    assert!(font.pixel(1, 1, 5));
+    assert!(font.pixel(3, 0, 7));
+    assert!(font.pixel(3, 2, 1));
+}
+
+#[test]
+fn retain_chars() {
+    let mut font = bdf::parse(TEST_BDF).unwrap();
+    font.retain_chars(|c| c == 'i');
+
+    // The default char is always kept
+    let chars: Vec<char> = font.glyphs.iter().map(|glyph| glyph.c).collect();
+    assert_eq!(chars, ['?', 'i']);
+
+    let font = font.to_bitmap_font().unwrap();
+    assert_eq!(font.ranges, [('?', '?', 0), ('i', 'i', 1)]);
+}
+
+#[test]
+fn monospace() {
+    let mut font = bdf::parse(TEST_BDF).unwrap();
+    for glyph in font.glyphs.iter_mut() {
+        glyph.advance = 7;
+    }
+
+    let font = font.to_bitmap_font().unwrap();
+    assert_eq!(font.char_width, 7);
+    assert!(font.glyph_widths.is_empty());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        bdf::parse("STARTFONT 2.1\nFONTBOUNDINGBOX 5 x 0 0\nENDFONT\n"),
+        Err(Error::Parse {
+            line: 2,
+            message: "invalid or missing number".to_string()
+        })
+    );
+    assert_eq!(
+        bdf::parse("STARTFONT 2.1\nSTARTCHAR A\nENCODING 65\n"),
+        Err(Error::UnexpectedEnd)
+    );
+    assert_eq!(
+        bdf::parse("STARTFONT 2.1\nENDFONT\n")
+            .unwrap()
+            .to_bitmap_font(),
+        Err(Error::NoGlyphs)
+    );
+}
+
+#[test]
+fn generated_font_is_up_to_date() {
+    let rust = bdf::parse(TEST_BDF)
+        .unwrap()
+        .to_bitmap_font()
+        .unwrap()
+        .to_rust("TestFont");
+
+    assert_eq!(rust, include_str!("test_font.rs"));
+}
+
+#[test]
+fn draw_generated_font() {
+    let text = TestFont::render_str("Aij?");
+    assert_eq!(text.size(), Size::new(16, 8));
+
+    let mut display = MockDisplay::new();
+    text.into_styled(TextStyle::with_text_color(BinaryColor::On))
+        .draw(&mut display);
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "   #         ## ",
+            "  # #  #  # #  #",
+            " #   #         #",
+            " ##### #  #   # ",
+            " #   # #  #     ",
+            " #   # #  #   # ",
+            "          #     ",
+            "        ##      ",
+        ])
+    );
+}
@@ -0,0 +1,49 @@
+// Generated by embedded-graphics-fontconv. Do not edit.
+
+pub use self::test_font::*;
+
+mod test_font {
+    use ::embedded_graphics::fonts::{font_builder, glyph_map};
+
+    /// Config for the -test-test-medium-r-normal--8-80-75-75-p-50-iso10646-1 font
+    #[derive(Debug, Copy, Clone)]
+    pub enum TestFontConf {}
+
+    impl font_builder::FontBuilderConf for TestFontConf {
+        const FONT_IMAGE: &'static [u8] = &[
+            0x30, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48, 0xa4,
+            0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0xf4, 0x08, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x14, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x11, 0x14, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        const CHAR_HEIGHT: u32 = 8;
+        const CHAR_WIDTH: u32 = 6;
+        const FONT_IMAGE_WIDTH: u32 = 96;
+        const BASELINE: u32 = 5;
+        const GLYPH_WIDTHS: &'static [font_builder::GlyphWidth] = &[
+            font_builder::GlyphWidth::new(5, 5),
+            font_builder::GlyphWidth::new(6, 6),
+            font_builder::GlyphWidth::new(2, 2),
+            font_builder::GlyphWidth::new(3, 3),
+        ];
+
+        fn char_offset(c: char) -> u32 {
+            const GLYPH_MAP: glyph_map::GlyphMap = glyph_map::GlyphMap::new(
+                &[
+                    glyph_map::GlyphRange::new('?', '?', 0),
+                    glyph_map::GlyphRange::new('A', 'A', 1),
+                    glyph_map::GlyphRange::new('i', 'j', 2),
+                ],
+                0,
+            );
+
+            GLYPH_MAP.index(c)
+        }
+    }
+
+    /// The -test-test-medium-r-normal--8-80-75-75-p-50-iso10646-1 font
+    pub type TestFont<'a> = font_builder::FontBuilder<'a, TestFontConf>;
+}
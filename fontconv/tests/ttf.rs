@@ -0,0 +1,280 @@
+use embedded_graphics_fontconv::{ttf, Error};
+
+/// Closed polygon with all points on the curve
+type Contour = &'static [(i16, i16)];
+
+/// Glyph outlines of the test font as `(char, advance, contours)`
+///
+/// The font has 16 units per em, an ascent of 12 and a descent of 4. One unit is one pixel if the
+/// font is rasterised with a size of 16 pixels.
+const GLYPHS: &[(char, u16, &[Contour])] = &[
+    (' ', 3, &[]),
+    ('-', 4, &[&[(0, 3), (0, 4), (3, 4), (3, 3)]]),
+    ('?', 5, &[&[(0, 0), (0, 8), (4, 8), (4, 0)]]),
+    ('I', 4, &[&[(1, 0), (1, 8), (3, 8), (3, 0)]]),
+    ('L', 6, &[&[(1, 0), (1, 8), (2, 8), (2, 1), (5, 1), (5, 0)]]),
+    ('j', 2, &[&[(-1, -2), (-1, 6), (1, 6), (1, -2)]]),
+];
+
+fn push_u16(data: &mut Vec<u8>, value: u16) {
+    data.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_i16(data: &mut Vec<u8>, value: i16) {
+    data.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_u32(data: &mut Vec<u8>, value: u32) {
+    data.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Builds a minimal TrueType font which contains the glyphs in `GLYPHS`.
+fn test_font() -> Vec<u8> {
+    let glyph_count = GLYPHS.len() as u16 + 1;
+
+    let mut head = Vec::new();
+    push_u32(&mut head, 0x0001_0000); // version
+    push_u32(&mut head, 0x0001_0000); // font revision
+    push_u32(&mut head, 0); // checksum adjustment
+    push_u32(&mut head, 0x5F0F_3CF5); // magic number
+    push_u16(&mut head, 0); // flags
+    push_u16(&mut head, 16); // units per em
+    head.extend_from_slice(&[0; 16]); // created and modified
+    for value in &[-1, -2, 5, 8] {
+        push_i16(&mut head, *value); // bounding box
+    }
+    push_u16(&mut head, 0); // mac style
+    push_u16(&mut head, 8); // lowest readable size
+    push_i16(&mut head, 2); // font direction hint
+    push_i16(&mut head, 0); // short loca offsets
+    push_i16(&mut head, 0); // glyph data format
+
+    let mut hhea = Vec::new();
+    push_u32(&mut hhea, 0x0001_0000); // version
+    for value in &[12, -4, 0, 6, -1, 0, 5, 1, 0, 0, 0, 0, 0, 0, 0] {
+        push_i16(&mut hhea, *value);
+    }
+    push_u16(&mut hhea, glyph_count); // number of horizontal metrics
+
+    let mut maxp = Vec::new();
+    push_u32(&mut maxp, 0x0000_5000); // version 0.5
+    push_u16(&mut maxp, glyph_count);
+
+    let mut cmap = Vec::new();
+    push_u16(&mut cmap, 0); // version
+    push_u16(&mut cmap, 1); // number of subtables
+    push_u16(&mut cmap, 0); // Unicode platform
+    push_u16(&mut cmap, 4); // full Unicode repertoire
+    push_u32(&mut cmap, 12); // subtable offset
+    push_u16(&mut cmap, 12); // format
+    push_u16(&mut cmap, 0); // reserved
+    push_u32(&mut cmap, 16 + 12 * GLYPHS.len() as u32); // length
+    push_u32(&mut cmap, 0); // language
+    push_u32(&mut cmap, GLYPHS.len() as u32); // number of groups
+    for (index, (c, _, _)) in GLYPHS.iter().enumerate() {
+        push_u32(&mut cmap, *c as u32);
+        push_u32(&mut cmap, *c as u32);
+        push_u32(&mut cmap, index as u32 + 1);
+    }
+
+    let mut hmtx = Vec::new();
+    push_u16(&mut hmtx, 4); // .notdef
+    push_i16(&mut hmtx, 0);
+
+    let mut glyf = Vec::new();
+    let mut loca = Vec::new();
+    push_u16(&mut loca, 0); // .notdef has no outline
+    push_u16(&mut loca, 0);
+
+    for (_, advance, contours) in GLYPHS {
+        let points = contours.iter().flat_map(|contour| contour.iter());
+        let x_min = points.clone().map(|p| p.0).min().unwrap_or(0);
+        let y_min = points.clone().map(|p| p.1).min().unwrap_or(0);
+        let x_max = points.clone().map(|p| p.0).max().unwrap_or(0);
+        let y_max = points.clone().map(|p| p.1).max().unwrap_or(0);
+
+        push_u16(&mut hmtx, *advance);
+        push_i16(&mut hmtx, x_min);
+
+        if !contours.is_empty() {
+            push_i16(&mut glyf, contours.len() as i16);
+            for value in &[x_min, y_min, x_max, y_max] {
+                push_i16(&mut glyf, *value);
+            }
+
+            let mut end_point = 0;
+            for contour in contours.iter() {
+                end_point += contour.len() as u16;
+                push_u16(&mut glyf, end_point - 1);
+            }
+            push_u16(&mut glyf, 0); // instruction length
+
+            // All points are on the curve and use 16 bit coordinates
+            glyf.extend(points.clone().map(|_| 0x01));
+
+            let mut previous = (0, 0);
+            for point in points.clone() {
+                push_i16(&mut glyf, point.0 - previous.0);
+                previous.0 = point.0;
+            }
+            for point in points {
+                push_i16(&mut glyf, point.1 - previous.1);
+                previous.1 = point.1;
+            }
+
+            if glyf.len() % 2 != 0 {
+                glyf.push(0);
+            }
+        }
+
+        push_u16(&mut loca, glyf.len() as u16 / 2);
+    }
+
+    let tables: [(&[u8; 4], Vec<u8>); 7] = [
+        (b"cmap", cmap),
+        (b"glyf", glyf),
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", hmtx),
+        (b"loca", loca),
+        (b"maxp", maxp),
+    ];
+
+    let mut data = Vec::new();
+    push_u32(&mut data, 0x0001_0000); // TrueType outlines
+    push_u16(&mut data, tables.len() as u16);
+    push_u16(&mut data, 64); // search range
+    push_u16(&mut data, 2); // entry selector
+    push_u16(&mut data, 48); // range shift
+
+    let mut offset = 12 + 16 * tables.len() as u32;
+    for (tag, table) in tables.iter() {
+        data.extend_from_slice(*tag);
+        push_u32(&mut data, 0); // checksum
+        push_u32(&mut data, offset);
+        push_u32(&mut data, table.len() as u32);
+
+        offset += (table.len() as u32).div_ceil(4) * 4;
+    }
+
+    for (_, table) in tables.iter() {
+        data.extend_from_slice(table);
+        data.resize(data.len().div_ceil(4) * 4, 0);
+    }
+
+    data
+}
+
+#[test]
+fn rasterize_metrics() {
+    let font = ttf::rasterize(&test_font(), 16.0, " -?ILj".chars()).unwrap();
+
+    assert_eq!(font.ascent, 12);
+    assert_eq!(font.descent, 4);
+    assert_eq!(font.default_char, Some('?'));
+
+    let space = font.glyph(' ').unwrap();
+    assert_eq!((space.width, space.height), (0, 0));
+    assert_eq!(space.advance, 3);
+
+    let l = font.glyph('L').unwrap();
+    assert_eq!((l.width, l.height), (4, 8));
+    assert_eq!((l.x_offset, l.y_offset), (1, 0));
+    assert_eq!(l.advance, 6);
+    assert_eq!(l.pixel(0, 0), 255);
+    assert_eq!(l.pixel(1, 0), 0);
+    assert_eq!(l.pixel(3, 7), 255);
+    assert_eq!(l.pixel(3, 6), 0);
+
+    let j = font.glyph('j').unwrap();
+    assert_eq!((j.width, j.height), (2, 8));
+    assert_eq!((j.x_offset, j.y_offset), (-1, -2));
+}
+
+#[test]
+fn missing_chars_are_skipped() {
+    let font = ttf::rasterize(&test_font(), 16.0, "IAé".chars()).unwrap();
+
+    let chars: Vec<char> = font.glyphs.iter().map(|glyph| glyph.c).collect();
+    assert_eq!(chars, ['I']);
+    assert_eq!(font.default_char, None);
+}
+
+#[test]
+fn coverage() {
+    // At half size the edges of "I" are at x = 0.5 and 1.5
+    let font = ttf::rasterize(&test_font(), 8.0, "I".chars()).unwrap();
+    let i = font.glyph('I').unwrap();
+
+    assert_eq!((i.width, i.height), (2, 4));
+    assert!(i.pixel(0, 0) > 0 && i.pixel(0, 0) < 255);
+    assert_eq!(i.pixel(0, 0), i.pixel(1, 3));
+}
+
+#[test]
+fn bitmap_font() {
+    let font = ttf::rasterize(&test_font(), 16.0, " -?ILj".chars())
+        .unwrap()
+        .to_bitmap_font()
+        .unwrap();
+
+    assert_eq!(font.char_height, 16);
+    assert_eq!(font.baseline, 11);
+    assert_eq!(font.char_width, 6);
+    assert_eq!(font.fallback, 2);
+    assert_eq!(
+        font.glyph_widths,
+        [(1, 3), (4, 4), (5, 5), (4, 4), (6, 6), (2, 2)]
+    );
+
+    // The bottom row of "L" is on the baseline, shifted right by the left bearing of "j"
+    assert!(font.pixel(4, 2, 11));
+    assert!(font.pixel(4, 5, 11));
+    assert!(!font.pixel(4, 5, 10));
+    assert!(!font.pixel(4, 2, 12));
+}
+
+#[test]
+fn invalid_font() {
+    assert_eq!(
+        ttf::rasterize(&[0; 16], 16.0, "A".chars()),
+        Err(Error::InvalidFont)
+    );
+}
+
+#[test]
+fn antialiased_bitmap_font() {
+    let font = ttf::rasterize(&test_font(), 8.0, "I".chars()).unwrap();
+    let i = font.glyph('I').unwrap();
+    let bitmap_font = font.to_bitmap_font_bpp(4).unwrap();
+
+    assert_eq!(bitmap_font.bits_per_pixel, 4);
+    // 16 cells of 2x8 pixels in a row, with 4 bits per pixel
+    assert_eq!(bitmap_font.image.len(), 16 * 2 * 8 / 2);
+
+    // The glyph is placed below the ascent of 6 pixels
+    for y in 0..i.height {
+        for x in 0..i.width {
+            let quantised = bitmap_font.coverage(0, x, y + 2);
+
+            assert!((i32::from(quantised) - i32::from(i.pixel(x, y))).abs() <= 8);
+        }
+    }
+    assert!(bitmap_font.coverage(0, 0, 2) > 0 && bitmap_font.coverage(0, 0, 2) < 255);
+
+    let rust = bitmap_font.to_rust("Half");
+    assert!(rust.contains("const BITS_PER_PIXEL: u32 = 4;"));
+
+    let rust = font.to_bitmap_font().unwrap().to_rust("Half");
+    assert!(!rust.contains("BITS_PER_PIXEL"));
+}
+
+#[test]
+fn unsupported_bits_per_pixel() {
+    let font = ttf::rasterize(&test_font(), 16.0, "I".chars()).unwrap();
+
+    assert_eq!(
+        font.to_bitmap_font_bpp(3),
+        Err(Error::UnsupportedBitsPerPixel(3))
+    );
+}
@@ -7,7 +7,6 @@
 //! whole thing is updated with your computer's local time every 50ms.
 
 use chrono::{Local, Timelike};
-use core::f32::consts::{FRAC_PI_2, PI};
 use embedded_graphics::egcircle;
 use embedded_graphics::fonts::Font12x16;
 use embedded_graphics::pixelcolor::BinaryColor;
@@ -26,17 +25,15 @@ const CENTER: Point = Point::new(DISP_SIZE / 2, DISP_SIZE / 2);
 /// The radius of the clock face
 const SIZE: u32 = 120;
 
-/// Start at the top of the circle
-const START: f32 = -FRAC_PI_2;
-
-/// Convert a polar coordinate (angle/distance) into an (X, Y) coordinate centered around `CENTER`
-fn polar(angle: f32, radius: f32) -> Point {
-    CENTER + Point::new((angle.cos() * radius) as i32, (angle.sin() * radius) as i32)
+/// Returns a line pointing up from `CENTER`, which is rotated clockwise by `angle` around the
+/// center of the clock face
+fn hand(length: u32, angle: Angle) -> Line {
+    Line::new(CENTER, CENTER - Point::new(0, length as i32)).rotate(CENTER, angle)
 }
 
 /// Draw a circle and 12 tics as a simple clock face
 fn draw_face() -> impl Iterator<Item = Pixel<BinaryColor>> {
-    let tic_len = 10.0;
+    let tic_len = 10;
 
     // Use the circle macro to create the outer face
     let face = egcircle!(
@@ -48,18 +45,14 @@ fn draw_face() -> impl Iterator<Item = Pixel<BinaryColor>> {
 
     // Create 12 `Line`s starting from the outer edge and drawing inwards by `tic_len` pixels
     let tics = (0..12).into_iter().map(move |index| {
-        // Start angle around the circle, in radians
-        let angle = START + (PI * 2.0 / 12.0) * index as f32;
-
-        // Start point on circumference
-        let start = polar(angle, SIZE as f32);
-
-        // End point; start point offset by `tic_len` pixels towards the circle center
-        let end = polar(angle, SIZE as f32 - tic_len);
-
-        Line::new(start, end)
-            .stroke_color(Some(BinaryColor::On))
-            .into_iter()
+        // A vertical tic at 12 o'clock, rotated into place around the clock center
+        Line::new(
+            CENTER - Point::new(0, SIZE as i32),
+            CENTER - Point::new(0, SIZE as i32 - tic_len),
+        )
+        .rotate(CENTER, Angle::from_degrees(index * 30))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .into_iter()
     });
 
     // Create a single iterator of pixels, first iterating over the circle, then over the 12 lines
@@ -69,49 +62,38 @@ fn draw_face() -> impl Iterator<Item = Pixel<BinaryColor>> {
 
 /// Draw the seconds hand given a seconds value (0 - 59)
 fn draw_seconds_hand(seconds: u32) -> impl Iterator<Item = Pixel<BinaryColor>> {
-    // Convert seconds into a position around the circle in radians
-    let seconds_radians = ((seconds as f32 / 60.0) * 2.0 * PI) + START;
-
-    let end = polar(seconds_radians, SIZE as f32);
+    // Each second moves the hand by 6 degrees
+    let angle = Angle::from_degrees(seconds as i32 * 6);
 
     // Basic line hand
-    let hand = Line::new(CENTER, end).stroke_color(Some(BinaryColor::On));
-
-    // Decoration position
-    let decoration_position = polar(seconds_radians, SIZE as f32 - 20.0);
+    let hand = hand(SIZE, angle).into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
 
     // Add a fancy circle near the end of the hand
-    let decoration = Circle::new(decoration_position, 5)
-        .fill_color(Some(BinaryColor::Off))
-        .stroke_color(Some(BinaryColor::On));
+    let decoration = Circle::new(CENTER - Point::new(0, SIZE as i32 - 20), 5)
+        .rotate(CENTER, angle)
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1).fill_color(Some(BinaryColor::Off)),
+        );
 
     hand.into_iter().chain(decoration)
 }
 
-/// Draw the hour hand (0-11)
-fn draw_hour_hand(hour: u32) -> Line<BinaryColor> {
-    // Convert hour into a position around the circle in radians
-    let hour_radians = ((hour as f32 / 12.0) * 2.0 * PI) + START;
-
-    let hand_len = SIZE as f32 - 60.0;
-
-    let end = polar(hour_radians, hand_len);
+/// Draw the hour hand (0-11), which moves smoothly between the hours with the minutes (0-59)
+fn draw_hour_hand(hour: u32, minute: u32) -> Styled<Line, PrimitiveStyle<BinaryColor>> {
+    // Each hour moves the hand by 30 degrees and each minute by another half degree
+    let angle = Angle::from_degrees(hour as i32 * 30) + Angle::from_degrees(minute as i32) / 2;
 
     // Basic line hand
-    Line::new(CENTER, end).stroke_color(Some(BinaryColor::On))
+    hand(SIZE - 60, angle).into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
 }
 
 /// Draw the minute hand (0-59)
-fn draw_minute_hand(minute: u32) -> Line<BinaryColor> {
-    // Convert minute into a position around the circle in radians
-    let minute_radians = ((minute as f32 / 60.0) * 2.0 * PI) + START;
-
-    let hand_len = SIZE as f32 - 30.0;
-
-    let end = polar(minute_radians, hand_len);
+fn draw_minute_hand(minute: u32) -> Styled<Line, PrimitiveStyle<BinaryColor>> {
+    // Each minute moves the hand by 6 degrees
+    let angle = Angle::from_degrees(minute as i32 * 6);
 
     // Basic line hand
-    Line::new(CENTER, end).stroke_color(Some(BinaryColor::On))
+    hand(SIZE - 30, angle).into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
 }
 
 /// Draw digital clock just above center with black text on a white background
@@ -120,13 +102,13 @@ fn draw_minute_hand(minute: u32) -> Line<BinaryColor> {
 /// function can't be returned.
 fn draw_digital_clock<'a>(time_str: &'a str) -> impl Iterator<Item = Pixel<BinaryColor>> + 'a {
     let text = Font12x16::render_str(&time_str)
-        .stroke_color(Some(BinaryColor::Off))
-        .translate(CENTER - Size::new(48, 48));
+        .translate(CENTER - Size::new(48, 48))
+        .into_styled(TextStyle::with_text_color(BinaryColor::Off));
 
     // Add a background around the time digits. Note that there is no bottom-right padding as this
     // is added by the font renderer itself
     let background = Rectangle::new(text.top_left() - Size::new(3, 3), text.bottom_right())
-        .fill_color(Some(BinaryColor::On));
+        .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
 
     // Draw the white background first, then the black text. Order matters here
     background.into_iter().chain(text)
@@ -151,7 +133,7 @@ fn main() {
         display.clear(BinaryColor::Off);
 
         draw_face().draw(&mut display);
-        draw_hour_hand(time.hour()).draw(&mut display);
+        draw_hour_hand(time.hour(), time.minute()).draw(&mut display);
         draw_minute_hand(time.minute()).draw(&mut display);
         draw_seconds_hand(time.second()).draw(&mut display);
 
@@ -161,7 +143,7 @@ fn main() {
         // Draw a small circle over the hands in the center of the clock face. This has to happen
         // after the hands are drawn so they're covered up
         Circle::new(CENTER, 4)
-            .fill_color(Some(BinaryColor::On))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
             .draw(&mut display);
 
         window.update(&display);
@@ -9,53 +9,59 @@ fn main() {
     let mut display = SimulatorDisplay::new(Size::new(304, 128));
 
     Circle::new(Point::new(CIRCLE_SIZE, CIRCLE_SIZE), CIRCLE_SIZE as u32)
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .draw(&mut display);
 
     Circle::new(Point::new(CIRCLE_SIZE, CIRCLE_SIZE), CIRCLE_SIZE as u32)
         .translate(Point::new(16, 16))
-        .stroke_color(Some(BinaryColor::Off))
-        .fill_color(Some(BinaryColor::On))
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::Off, 1).fill_color(Some(BinaryColor::On)),
+        )
         .draw(&mut display);
 
     Circle::new(Point::new(CIRCLE_SIZE, CIRCLE_SIZE), CIRCLE_SIZE as u32)
         .translate(Point::new(CIRCLE_SIZE, CIRCLE_SIZE))
-        .stroke_color(Some(BinaryColor::Off))
-        .fill_color(Some(BinaryColor::Off))
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::Off, 1).fill_color(Some(BinaryColor::Off)),
+        )
         .draw(&mut display);
 
     Rectangle::new(Point::new(0, 0), Point::new(64, 64))
         .translate(Point::new(96, 0))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .draw(&mut display);
 
     Rectangle::new(Point::new(0, 0), Point::new(64, 64))
         .translate(Point::new(96 + 16, 16))
-        .stroke_color(Some(BinaryColor::Off))
-        .fill_color(Some(BinaryColor::On))
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::Off, 1).fill_color(Some(BinaryColor::On)),
+        )
         .draw(&mut display);
 
     Rectangle::new(Point::new(0, 0), Point::new(64, 64))
         .translate(Point::new(96 + 32, 32))
-        .stroke_color(Some(BinaryColor::Off))
-        .fill_color(Some(BinaryColor::Off))
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::Off, 1).fill_color(Some(BinaryColor::Off)),
+        )
         .draw(&mut display);
 
     Triangle::new(Point::new(32, 0), Point::new(0, 64), Point::new(64, 64))
         .translate(Point::new(96 * 2, 0))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .draw(&mut display);
 
     Triangle::new(Point::new(32, 0), Point::new(0, 64), Point::new(64, 64))
         .translate(Point::new(96 * 2 + 16, 16))
-        .stroke_color(Some(BinaryColor::Off))
-        .fill_color(Some(BinaryColor::On))
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::Off, 1).fill_color(Some(BinaryColor::On)),
+        )
         .draw(&mut display);
 
     Triangle::new(Point::new(32, 0), Point::new(0, 64), Point::new(64, 64))
         .translate(Point::new(96 * 2 + 32, 32))
-        .stroke_color(Some(BinaryColor::Off))
-        .fill_color(Some(BinaryColor::Off))
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::Off, 1).fill_color(Some(BinaryColor::Off)),
+        )
         .draw(&mut display);
 
     let mut window = WindowBuilder::new(&display)
@@ -0,0 +1,72 @@
+//! # Example: Flood fill
+//!
+//! Click inside one of the shapes to fill the enclosed area with the next color. The left mouse
+//! button uses a four connected fill, other buttons use an eight connected fill which leaks
+//! through diagonal gaps.
+extern crate embedded_graphics;
+extern crate embedded_graphics_simulator;
+
+use embedded_graphics::flood_fill::{Connectivity, FloodFill, SeedSpan};
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, Line, Rectangle, Triangle};
+use embedded_graphics_simulator::{SimulatorDisplay, SimulatorEvent, WindowBuilder};
+use sdl2::mouse::MouseButton;
+
+const OUTLINE_COLOR: Rgb888 = Rgb888::WHITE;
+const FILL_COLORS: [Rgb888; 4] = [Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE, Rgb888::YELLOW];
+
+fn main() {
+    let mut display = SimulatorDisplay::new(Size::new(256, 128));
+    let mut window = WindowBuilder::new(&display)
+        .title("Click to flood fill")
+        .scale(2)
+        .build();
+
+    let outline_style = PrimitiveStyle::with_stroke(OUTLINE_COLOR, 1);
+
+    Circle::new(Point::new(40, 40), 30)
+        .into_styled(outline_style)
+        .draw(&mut display);
+    Rectangle::new(Point::new(80, 10), Point::new(150, 70))
+        .into_styled(outline_style)
+        .draw(&mut display);
+    Line::new(Point::new(80, 10), Point::new(150, 70))
+        .into_styled(outline_style)
+        .draw(&mut display);
+    Triangle::new(
+        Point::new(170, 70),
+        Point::new(210, 10),
+        Point::new(250, 70),
+    )
+    .into_styled(outline_style)
+    .draw(&mut display);
+
+    let mut stack = [SeedSpan::default(); 128];
+    let mut next_color = FILL_COLORS.iter().cycle();
+
+    'running: loop {
+        window.update(&display);
+
+        for event in window.events() {
+            match event {
+                SimulatorEvent::Quit => break 'running,
+                SimulatorEvent::MouseButtonUp { point, mouse_btn } => {
+                    let connectivity = match mouse_btn {
+                        MouseButton::Left => Connectivity::Four,
+                        _ => Connectivity::Eight,
+                    };
+
+                    let result = FloodFill::new(point, *next_color.next().unwrap())
+                        .connectivity(connectivity)
+                        .fill(&mut display, &mut stack);
+
+                    if let Err(error) = result {
+                        eprintln!("Flood fill failed: {:?}", error);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
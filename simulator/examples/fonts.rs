@@ -10,20 +10,22 @@ fn main() {
     // Show smallest font with black font on white background (default value for fonts)
     Font6x8::render_str("Hello World! - default style 6x8")
         .translate(Point::new(15, 15))
+        .into_styled(TextStyle::default())
         .draw(&mut display);
 
     // Show smallest font with white font on black background
     Font6x8::render_str("Hello World! - inverse 6x8")
-        .stroke_color(Some(BinaryColor::Off))
-        .fill_color(Some(BinaryColor::On))
         .translate(Point::new(15, 30))
+        .into_styled(
+            TextStyle::with_text_color(BinaryColor::Off).background_color(Some(BinaryColor::On)),
+        )
         .draw(&mut display);
 
     // Show smallest font with white font on black background using a macro
     text_6x8!(
         "Hello world! - inverse 6x8 with macro",
-        stroke_color = Some(BinaryColor::Off),
-        fill_color = Some(BinaryColor::On)
+        text_color = Some(BinaryColor::Off),
+        background_color = Some(BinaryColor::On)
     )
     .translate(Point::new(15, 40))
     .draw(&mut display);
@@ -31,11 +33,13 @@ fn main() {
     // Show 6x12 Font
     Font6x12::render_str("Hello 6x12!")
         .translate(Point::new(15, 55))
+        .into_styled(TextStyle::default())
         .draw(&mut display);
 
     // Show 8x16 Font
     Font8x16::render_str("Hello 8x16!")
         .translate(Point::new(15, 80))
+        .into_styled(TextStyle::default())
         .draw(&mut display);
 
     // Show 12x16 Font using a macro
@@ -7,22 +7,24 @@ use embedded_graphics_simulator::{BinaryColorTheme, SimulatorDisplay, WindowBuil
 fn main() {
     let mut display = SimulatorDisplay::new(Size::new(129, 129));
 
+    let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
     // Outline
     Circle::new(Point::new(64, 64), 64)
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(style)
         .draw(&mut display);
 
     // Clock hands
     Line::new(Point::new(64, 64), Point::new(0, 64))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(style)
         .draw(&mut display);
     Line::new(Point::new(64, 64), Point::new(80, 80))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(style)
         .draw(&mut display);
 
     Font6x8::render_str("Hello World!")
-        .stroke_color(Some(BinaryColor::On))
         .translate(Point::new(5, 50))
+        .into_styled(TextStyle::with_text_color(BinaryColor::On))
         .draw(&mut display);
 
     let mut window = WindowBuilder::new(&display)
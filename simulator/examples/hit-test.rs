@@ -0,0 +1,82 @@
+//! # Example: Hit testing
+//!
+//! Click on one of the shapes to highlight it. The `ContainsPoint` trait is used to find out
+//! which shape was clicked.
+extern crate embedded_graphics;
+extern crate embedded_graphics_simulator;
+
+use embedded_graphics::fonts::Font6x8;
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, Line, Rectangle, Triangle};
+use embedded_graphics_simulator::{SimulatorDisplay, SimulatorEvent, WindowBuilder};
+
+const NORMAL_COLOR: Rgb888 = Rgb888::BLUE;
+const SELECTED_COLOR: Rgb888 = Rgb888::RED;
+
+fn fill_color(selected: bool) -> Rgb888 {
+    if selected {
+        SELECTED_COLOR
+    } else {
+        NORMAL_COLOR
+    }
+}
+
+fn main() {
+    let mut display = SimulatorDisplay::new(Size::new(256, 128));
+    let mut window = WindowBuilder::new(&display)
+        .title("Click on a shape")
+        .scale(2)
+        .build();
+
+    let circle = Circle::new(Point::new(40, 50), 30);
+    let rectangle = Rectangle::new(Point::new(80, 20), Point::new(140, 80));
+    let triangle = Triangle::new(
+        Point::new(160, 80),
+        Point::new(200, 20),
+        Point::new(240, 80),
+    );
+    let line = Line::new(Point::new(10, 120), Point::new(240, 95));
+    let text = Font6x8::render_str("Click me").translate(Point::new(100, 105));
+
+    let mut clicked = None;
+
+    'running: loop {
+        display.clear(Rgb888::BLACK);
+
+        let shape_style = |selected| {
+            PrimitiveStyle::with_stroke(Rgb888::WHITE, 1).fill_color(Some(fill_color(selected)))
+        };
+
+        circle
+            .into_styled(shape_style(clicked.map_or(false, |p| circle.contains(p))))
+            .draw(&mut display);
+        rectangle
+            .into_styled(shape_style(
+                clicked.map_or(false, |p| rectangle.contains(p)),
+            ))
+            .draw(&mut display);
+        triangle
+            .into_styled(shape_style(clicked.map_or(false, |p| triangle.contains(p))))
+            .draw(&mut display);
+        line.into_styled(PrimitiveStyle::with_stroke(
+            fill_color(clicked.map_or(false, |p| line.contains(p))),
+            1,
+        ))
+        .draw(&mut display);
+        text.into_styled(TextStyle::with_text_color(fill_color(
+            clicked.map_or(false, |p| text.contains(p)),
+        )))
+        .draw(&mut display);
+
+        window.update(&display);
+
+        for event in window.events() {
+            match event {
+                SimulatorEvent::Quit => break 'running,
+                SimulatorEvent::MouseButtonUp { point, .. } => clicked = Some(point),
+                _ => {}
+            }
+        }
+    }
+}
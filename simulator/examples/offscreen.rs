@@ -1,6 +1,6 @@
 use embedded_graphics::pixelcolor::BinaryColor;
 use embedded_graphics::prelude::*;
-use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::primitives::{Circle, Line, Rectangle};
 use embedded_graphics_simulator::{SimulatorDisplay, WindowBuilder};
 
 fn main() {
@@ -8,10 +8,23 @@ fn main() {
 
     // Outline
     Rectangle::new(Point::new(0, 0), Point::new(16, 16))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .translate(Point::new(-8, -8))
         .draw(&mut display);
 
+    // Huge primitives which are mostly outside the display. Only the visible pixels are
+    // iterated over, so these are as fast to draw as small primitives.
+    Circle::new(Point::new(10_016, 16), 10_000)
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(&mut display);
+
+    Line::new(
+        Point::new(-1_000_000, 1_000_024),
+        Point::new(1_000_000, -999_976),
+    )
+    .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    .draw(&mut display);
+
     let mut window = WindowBuilder::new(&display)
         .title("Offscreen")
         .scale(4)
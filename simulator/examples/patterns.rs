@@ -0,0 +1,75 @@
+//! # Example: Fill patterns
+//!
+//! Monochrome displays can't show shades of gray, but stippled fill patterns can be used to tell
+//! filled areas apart. This example draws the builtin patterns and a custom pattern which is
+//! aligned to the shape it fills.
+
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, Rectangle, Triangle};
+use embedded_graphics::style::{Pattern, PatternAlignment};
+use embedded_graphics_simulator::{SimulatorDisplay, WindowBuilder};
+
+const PAD: i32 = 8;
+
+fn main() {
+    let mut display = SimulatorDisplay::new(Size::new(256, 112));
+
+    let patterns = [
+        Pattern::CHECKERBOARD,
+        Pattern::DIAGONAL_HATCH,
+        Pattern::CROSS_HATCH,
+        Pattern::DOTS,
+    ];
+
+    for (i, pattern) in patterns.iter().enumerate() {
+        let offset = Point::new(PAD + i as i32 * (48 + PAD * 2), PAD);
+
+        Rectangle::new(Point::new(0, 0), Point::new(48, 40))
+            .translate(offset)
+            .into_styled(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 1)
+                    .fill_color(Some(BinaryColor::On))
+                    .fill_pattern(Some(*pattern)),
+            )
+            .draw(&mut display);
+    }
+
+    let bricks = Pattern::with_size(
+        [
+            0b1111_0000,
+            0b1000_0000,
+            0b1111_0000,
+            0b0010_0000,
+            0,
+            0,
+            0,
+            0,
+        ],
+        Size::new(4, 4),
+    )
+    .alignment(PatternAlignment::Shape);
+
+    Circle::new(Point::new(PAD + 24, 84), 20)
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1)
+                .fill_color(Some(BinaryColor::On))
+                .fill_pattern(Some(bricks)),
+        )
+        .draw(&mut display);
+
+    Triangle::new(Point::new(24, 0), Point::new(0, 48), Point::new(48, 48))
+        .translate(Point::new(PAD + 64, 60))
+        .into_styled(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1)
+                .fill_color(Some(BinaryColor::On))
+                .fill_pattern(Some(Pattern::CROSS_HATCH)),
+        )
+        .draw(&mut display);
+
+    let mut window = WindowBuilder::new(&display)
+        .title("Fill patterns")
+        .scale(3)
+        .build();
+    window.show_static(&display);
+}
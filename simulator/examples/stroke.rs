@@ -9,56 +9,29 @@ fn main() {
     let mut display = SimulatorDisplay::new(Size::new(320, 256));
 
     let triangle = Triangle::new(Point::new(0, 64), Point::new(64, 0), Point::new(64, 64))
-        .translate(Point::new(0, 0))
-        .stroke_color(Some(BinaryColor::On));
+        .translate(Point::new(0, 0));
 
-    let rect = Rectangle::new(Point::new(0, 0), Point::new(64, 64))
-        .translate(Point::new(64 + PADDING, 0))
-        .stroke_color(Some(BinaryColor::On));
+    let rect =
+        Rectangle::new(Point::new(0, 0), Point::new(64, 64)).translate(Point::new(64 + PADDING, 0));
 
-    let line = Line::new(Point::new(0, 0), Point::new(64, 64))
-        .translate(Point::new(128 + PADDING * 2, 0))
-        .stroke_color(Some(BinaryColor::On));
+    let line =
+        Line::new(Point::new(0, 0), Point::new(64, 64)).translate(Point::new(128 + PADDING * 2, 0));
 
-    let circ = Circle::new(Point::new(32, 32), 32)
-        .translate(Point::new(192 + PADDING * 3, 0))
-        .stroke_color(Some(BinaryColor::On));
+    let circ = Circle::new(Point::new(32, 32), 32).translate(Point::new(192 + PADDING * 3, 0));
 
-    circ.into_iter()
-        .chain(rect.into_iter())
-        .chain(line.into_iter())
-        .chain(triangle.into_iter())
-        .draw(&mut display);
+    // The same shapes are drawn three times with a different stroke width each time
+    for (row, stroke_width) in [1, 3, 10].iter().enumerate() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, *stroke_width);
+        let offset = Point::new(0, (64 + PADDING) * row as i32);
 
-    circ.translate(Point::new(0, 64 + PADDING))
-        .stroke_width(3)
-        .into_iter()
-        .chain(rect.translate(Point::new(0, 64 + PADDING)).stroke_width(3))
-        .chain(line.translate(Point::new(0, 64 + PADDING)).stroke_width(3))
-        .chain(
-            triangle
-                .translate(Point::new(0, 64 + PADDING))
-                .stroke_width(3),
-        )
-        .draw(&mut display);
-
-    circ.translate(Point::new(0, 128 + PADDING * 2))
-        .stroke_width(10)
-        .into_iter()
-        .chain(
-            rect.translate(Point::new(0, 128 + PADDING * 2))
-                .stroke_width(10),
-        )
-        .chain(
-            line.translate(Point::new(0, 128 + PADDING * 2))
-                .stroke_width(10),
-        )
-        .chain(
-            triangle
-                .translate(Point::new(0, 128 + PADDING * 2))
-                .stroke_width(10),
-        )
-        .draw(&mut display);
+        circ.translate(offset)
+            .into_styled(style)
+            .into_iter()
+            .chain(rect.translate(offset).into_styled(style))
+            .chain(line.translate(offset).into_styled(style))
+            .chain(triangle.translate(offset).into_styled(style))
+            .draw(&mut display);
+    }
 
     let mut window = WindowBuilder::new(&display).title("Strokes").build();
     window.show_static(&display);
@@ -11,42 +11,42 @@ fn main() {
     // no straight lines
     Triangle::new(Point::new(0, 0), Point::new(64, 10), Point::new(15, 64))
         .translate(Point::new(PAD, 0))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .draw(&mut display);
 
     // flat top
     Triangle::new(Point::new(5, 0), Point::new(30, 64), Point::new(64, 0))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .translate(Point::new(64 + PAD, 0))
         .draw(&mut display);
 
     // flat left
     Triangle::new(Point::new(0, 0), Point::new(0, 64), Point::new(64, 30))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .translate(Point::new((64 + PAD) * 2, 0))
         .draw(&mut display);
 
     // flat bottom
     Triangle::new(Point::new(22, 0), Point::new(0, 64), Point::new(64, 64))
         .translate(Point::new((64 + PAD) * 3, 0))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .draw(&mut display);
 
     // flat right
     Triangle::new(Point::new(0, 22), Point::new(64, 0), Point::new(64, 64))
         .translate(Point::new((64 + PAD) * 4, 0))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .draw(&mut display);
 
     // draw filled above stroke, should not be visible
     Triangle::new(Point::new(0, 22), Point::new(64, 0), Point::new(64, 64))
         .translate(Point::new((64 + PAD) * 5, 0))
-        .stroke_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
         .draw(&mut display);
 
     Triangle::new(Point::new(0, 22), Point::new(64, 0), Point::new(64, 64))
         .translate(Point::new((64 + PAD) * 5, 0))
-        .fill_color(Some(BinaryColor::On))
+        .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
         .draw(&mut display);
 
     let mut window = WindowBuilder::new(&display)
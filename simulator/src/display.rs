@@ -1,7 +1,7 @@
 use embedded_graphics::drawable::Pixel;
 use embedded_graphics::geometry::{Point, Size};
 use embedded_graphics::pixelcolor::{BinaryColor, PixelColor};
-use embedded_graphics::DrawTarget;
+use embedded_graphics::{DrawTarget, GetPixel};
 
 /// Display
 pub struct SimulatorDisplay<C> {
@@ -76,3 +76,12 @@ where
         self.size
     }
 }
+
+impl<C> GetPixel<C> for SimulatorDisplay<C>
+where
+    C: PixelColor,
+{
+    fn pixel(&self, point: Point) -> Option<C> {
+        self.point_to_index(point).map(|index| self.pixels[index])
+    }
+}